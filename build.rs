@@ -0,0 +1,26 @@
+//! Defines the `cfg` aliases that are used throughout the crate, so that long
+//! lists of features are written in one place only.
+
+use cfg_aliases::cfg_aliases;
+
+// -----------------------------------------------------------------------------
+
+fn main() {
+    cfg_aliases! {
+        // Request URLs are built, authenticated (signed) and redacted when
+        // requests can be sent, or when any API that returns a request URL is
+        // enabled:
+        request_urls: { any(
+            feature = "enable-reqwest",
+            feature = "autocomplete",
+            feature = "directions",
+            feature = "distance_matrix",
+            feature = "elevation",
+            feature = "geocoding",
+            feature = "geolocation",
+            feature = "places",
+            feature = "roads",
+            feature = "time_zone"
+        ) },
+    } // cfg_aliases!
+} // fn
\ No newline at end of file
@@ -0,0 +1,48 @@
+//! Contains the `Charge` struct and its associated traits. It describes what a
+//! request will be billed for.
+
+use crate::billing::sku::Sku;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// What a request will be billed for: a SKU and how many units of it. Most
+/// requests are billed for one unit. Distance Matrix API requests are billed
+/// for each element.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct Charge {
+
+    /// The billing SKU that the request falls under.
+    pub sku: Sku,
+
+    /// How many units of the SKU the request is billed for.
+    pub units: u64,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Charge {
+
+    /// Initializes a charge for the specified number of units of a SKU.
+    pub fn new(sku: Sku, units: u64) -> Charge {
+        Charge { sku, units }
+    } // fn
+
+    /// Returns the estimated cost of the charge, in US dollars.
+    pub fn cost(&self) -> Decimal {
+        self.sku.unit_price() * Decimal::from(self.units)
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<Sku> for Charge {
+    /// Converts a SKU into a charge for one unit of that SKU.
+    fn from(sku: Sku) -> Charge {
+        Charge::new(sku, 1)
+    } // fn
+} // impl
\ No newline at end of file
@@ -0,0 +1,255 @@
+//! Contains the `CostLedger` struct and its associated traits. It keeps a
+//! running tally of the estimated cost of the client's requests.
+
+use crate::billing::{charge::Charge, error::Error, reservation::Reservation};
+use crate::request_rate::api::Api;
+use rust_decimal::Decimal;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::{SystemTime, UNIX_EPOCH},
+}; // use std
+
+// -----------------------------------------------------------------------------
+
+/// The tally of estimated costs for each API, along with the month that it is
+/// for. The month is counted from January 1970. The cost of requests that are
+/// in-flight is held in `reserved` until they receive a response.
+#[derive(Debug, Default)]
+struct Tally {
+    month: u64,
+    costs: HashMap<Api, Decimal>,
+    reserved: Decimal,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// A running tally of the estimated cost of the client's requests, in US
+/// dollars, for each API. The tally covers the current calendar month (UTC)
+/// and starts again from zero when the month changes. It is usually read using
+/// the client's `costs` and `total_cost` methods.
+///
+/// A request's estimated cost is reserved against the budget before the
+/// request is rate limited and sent, so that concurrent requests can't
+/// overspend it together. The cost is only added to the tally once Google has
+/// answered the request successfully, and only once no matter how many times
+/// the request was retried. If Google returns an error status (such as
+/// `REQUEST_DENIED`), or no response is received because the request timed out
+/// or the network was unreachable, the reservation is released and the request
+/// costs nothing.
+///
+/// The tally is shared between clones of the client. It is kept in memory, so
+/// it starts again from zero when the program is restarted.
+
+#[derive(Clone, Debug, Default)]
+pub struct CostLedger {
+
+    /// The most that may be spent each month, in US dollars. Requests that
+    /// would take the month's spending over the budget are refused. When
+    /// `None`, requests are tallied but never refused.
+    pub budget: Option<Decimal>,
+
+    tally: Arc<Mutex<Tally>>,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl CostLedger {
+
+    /// Returns the estimated cost of this month's requests for each API.
+    pub fn costs(&self) -> HashMap<Api, Decimal> {
+        self.lock().costs.clone()
+    } // fn
+
+    /// Returns the estimated cost of this month's requests for all APIs.
+    pub fn total(&self) -> Decimal {
+        self.lock().costs.values().sum()
+    } // fn
+
+    /// Resets the tally to zero.
+    pub fn reset(&self) {
+        self.lock().costs.clear();
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// ## Description
+    ///
+    /// Reserves the estimated cost of a request against the budget, unless it
+    /// would take the month's spending (including the requests that are
+    /// in-flight) over the budget. In that case, nothing is reserved and an
+    /// error is returned.
+    ///
+    /// The cost is added to the tally when the returned `Reservation` is
+    /// committed, and is released if the reservation is dropped without being
+    /// committed.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `api` ‧ The API that the request is for.
+    ///
+    /// * `charge` ‧ What the request will be billed for.
+
+    pub(crate) fn reserve(&self, api: &Api, charge: &Charge) -> Result<Reservation, Error> {
+        let cost = charge.cost();
+        let mut tally = self.lock();
+        if let Some(budget) = self.budget {
+            let spent: Decimal = tally.costs.values().sum::<Decimal>() + tally.reserved;
+            if spent + cost > budget {
+                return Err(Error::BudgetExceeded { sku: charge.sku.clone(), cost, spent, budget })
+            } // if
+        } // if
+        tally.reserved += cost;
+        Ok(Reservation::new(self.clone(), api.clone(), charge.clone()))
+    } // fn
+
+    /// Settles a reservation that was made using `reserve`. Its cost is
+    /// removed from the reserved amount and, if `commit` is `true`, added to
+    /// the API's tally.
+    pub(crate) fn settle(&self, api: &Api, cost: Decimal, commit: bool) {
+        let mut tally = self.lock();
+        tally.reserved = (tally.reserved - cost).max(Decimal::ZERO);
+        if commit {
+            *tally.costs.entry(api.clone()).or_default() += cost;
+        } // if
+    } // fn
+
+    /// Locks the tally, clearing it first if the month has changed. A
+    /// poisoned lock is recovered, since the tally holds no invariants that a
+    /// panic could break.
+    fn lock(&self) -> MutexGuard<'_, Tally> {
+        let mut tally = self.tally.lock().unwrap_or_else(PoisonError::into_inner);
+        let month = current_month();
+        if tally.month != month {
+            tally.month = month;
+            tally.costs.clear();
+        } // if
+        tally
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the current calendar month (UTC), counted from January 1970.
+
+fn current_month() -> u64 {
+    month_of(SystemTime::now())
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the calendar month (UTC) of the `time`, counted from January 1970.
+/// The civil date is calculated from the number of days since the UNIX epoch
+/// using Howard Hinnant's `civil_from_days` algorithm.
+
+fn month_of(time: SystemTime) -> u64 {
+    let days = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() / 86_400;
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let (year, month) = if shifted_month < 10 {
+        (era * 400 + year_of_era, shifted_month + 2)
+    } else {
+        (era * 400 + year_of_era + 1, shifted_month - 10)
+    }; // if
+    (year - 1970) * 12 + month
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::billing::sku::Sku;
+    use rust_decimal_macros::dec;
+    use std::time::Duration;
+
+    /// Returns the time that is `seconds` after the UNIX epoch.
+    fn at(seconds: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(seconds)
+    } // fn
+
+    #[test]
+    fn month_of_counts_months_from_january_1970() {
+        assert_eq!(month_of(UNIX_EPOCH), 0);
+        // 1970-02-01T00:00:00Z
+        assert_eq!(month_of(at(2_678_400)), 1);
+        // 1971-01-01T00:00:00Z
+        assert_eq!(month_of(at(31_536_000)), 12);
+        // 2024-03-15T00:00:00Z
+        assert_eq!(month_of(at(1_710_460_800)), 54 * 12 + 2);
+    } // fn
+
+    #[test]
+    fn month_of_changes_at_midnight_on_the_first() {
+        // 2024-03-01T00:00:00Z
+        let march = 1_709_251_200;
+        assert_eq!(month_of(at(march - 1)), 54 * 12 + 1);
+        assert_eq!(month_of(at(march)), 54 * 12 + 2);
+    } // fn
+
+    #[test]
+    fn month_of_handles_leap_days_and_year_ends() {
+        // 2024-02-29T00:00:00Z
+        assert_eq!(month_of(at(1_709_164_800)), 54 * 12 + 1);
+        // 2000-02-29T00:00:00Z
+        assert_eq!(month_of(at(951_782_400)), 30 * 12 + 1);
+        // 1999-12-31T23:59:59Z and 2000-01-01T00:00:00Z
+        assert_eq!(month_of(at(946_684_799)), 29 * 12 + 11);
+        assert_eq!(month_of(at(946_684_800)), 30 * 12);
+    } // fn
+
+    #[test]
+    fn current_month_matches_the_system_clock() {
+        assert_eq!(current_month(), month_of(SystemTime::now()));
+    } // fn
+
+    #[test]
+    fn committed_reservation_is_charged_once() {
+        let ledger = CostLedger::default();
+        let reservation = ledger.reserve(&Api::Geocoding, &Charge::new(Sku::Geocoding, 2)).unwrap();
+        assert_eq!(ledger.total(), Decimal::ZERO);
+        reservation.commit();
+        reservation.commit();
+        drop(reservation);
+        assert_eq!(ledger.total(), dec!(0.01));
+        assert_eq!(ledger.costs().get(&Api::Geocoding), Some(&dec!(0.01)));
+    } // fn
+
+    #[test]
+    fn dropped_reservation_is_released() {
+        let ledger = CostLedger { budget: Some(dec!(0.01)), ..Default::default() };
+        let reservation = ledger.reserve(&Api::Geocoding, &Charge::new(Sku::Geocoding, 2)).unwrap();
+        drop(reservation);
+        assert_eq!(ledger.total(), Decimal::ZERO);
+        assert!(ledger.reserve(&Api::Geocoding, &Charge::new(Sku::Geocoding, 2)).is_ok());
+    } // fn
+
+    #[test]
+    fn in_flight_reservations_count_against_the_budget() {
+        let ledger = CostLedger { budget: Some(dec!(0.01)), ..Default::default() };
+        let _first = ledger.reserve(&Api::Geocoding, &Charge::new(Sku::Geocoding, 1)).unwrap();
+        let _second = ledger.reserve(&Api::Geocoding, &Charge::new(Sku::Geocoding, 1)).unwrap();
+        assert_eq!(
+            ledger.reserve(&Api::Geocoding, &Charge::new(Sku::Geocoding, 1)).unwrap_err(),
+            Error::BudgetExceeded {
+                sku: Sku::Geocoding,
+                cost: dec!(0.005),
+                spent: dec!(0.01),
+                budget: dec!(0.01),
+            }, // BudgetExceeded
+        );
+    } // fn
+
+} // mod
\ No newline at end of file
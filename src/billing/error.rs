@@ -0,0 +1,27 @@
+//! Billing error types and error messages.
+
+use crate::billing::sku::Sku;
+use miette::Diagnostic;
+use rust_decimal::Decimal;
+use thiserror::Error;
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced by the client's cost accounting.
+
+#[derive(Clone, Debug, Diagnostic, Eq, Error, PartialEq)]
+#[diagnostic(code(google_maps::billing::error), url(docsrs))]
+pub enum Error {
+    /// The request was not sent because its estimated cost would take the
+    /// month's spending over the client's budget. The estimated cost of the
+    /// request, the amount spent so far this month and the budget are in US
+    /// dollars.
+    #[error("a {sku} request costing ${cost} USD would exceed the monthly \
+        budget of ${budget} USD, ${spent} USD has been spent this month")]
+    BudgetExceeded {
+        sku: Sku,
+        cost: Decimal,
+        spent: Decimal,
+        budget: Decimal,
+    }, // BudgetExceeded
+} // enum
\ No newline at end of file
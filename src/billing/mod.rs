@@ -0,0 +1,35 @@
+//! Contains the `Sku` enum and the `CostLedger` struct. They're used to
+//! estimate what the Google Maps Platform will bill for the client's requests,
+//! and to refuse requests that would exceed a monthly budget.
+//!
+//! Each request is classified into its billing SKU (_stock keeping unit_)
+//! before it is sent. For example, a Directions API request with more than 10
+//! waypoints is billed as a `DirectionsAdvanced` request, and a Distance Matrix
+//! API request is billed for each _element_ (origins × destinations). The
+//! estimated cost is reserved against the budget, and is added to a running
+//! tally for the request's API once Google has answered the request
+//! successfully.
+//!
+//! **These are estimates using Google's list prices for the first 100,000
+//! monthly requests. They don't account for volume discounts, the monthly free
+//! credit, or Places data fields that are billed separately. To accurately
+//! control billing charges by Google, please use the [Google Cloud Platform
+//! Console](https://console.cloud.google.com/) _IAM & admin_ to set quotas for
+//! each API on the server's side.**
+
+pub mod charge;
+pub mod cost_ledger;
+pub mod error;
+mod reservation;
+pub mod sku;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::billing::{
+    charge::Charge,
+    cost_ledger::CostLedger,
+    error::Error,
+    sku::Sku,
+}; // crate::billing
+
+pub(crate) use crate::billing::reservation::Reservation;
\ No newline at end of file
@@ -0,0 +1,70 @@
+//! Contains the `Reservation` struct and its associated traits. It holds a
+//! request's estimated cost against the budget while the request is in-flight.
+
+use crate::billing::{charge::Charge, cost_ledger::CostLedger};
+use crate::request_rate::api::Api;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// -----------------------------------------------------------------------------
+//
+/// A request's estimated cost that has been reserved against the client's
+/// budget using `CostLedger::reserve`. The cost is added to the tally when the
+/// reservation is committed, which should happen once Google has answered the
+/// request successfully. A reservation that is dropped without being committed
+/// (for example, because Google returned an error) is released, and the
+/// request costs nothing.
+
+#[derive(Debug)]
+pub(crate) struct Reservation {
+
+    /// The ledger that the cost was reserved in.
+    ledger: CostLedger,
+
+    /// The API that the request is for.
+    api: Api,
+
+    /// What the request will be billed for.
+    charge: Charge,
+
+    /// Whether the reservation has been committed.
+    committed: AtomicBool,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Reservation {
+
+    /// Initializes a reservation. The cost must already have been reserved in
+    /// the `ledger`.
+    pub(crate) fn new(ledger: CostLedger, api: Api, charge: Charge) -> Reservation {
+        Reservation { ledger, api, charge, committed: AtomicBool::new(false) }
+    } // fn
+
+    /// Adds the reserved cost to the tally. Committing a reservation more than
+    /// once, for example when a request is retried, only charges it once.
+    pub(crate) fn commit(&self) {
+        if !self.committed.swap(true, Ordering::AcqRel) {
+            self.ledger.settle(&self.api, self.charge.cost(), true);
+            tracing::debug!(
+                "Charged {} × {} request to Google Maps {} API: ${} USD",
+                self.charge.units,
+                self.charge.sku,
+                String::from(&self.api),
+                self.charge.cost(),
+            ); // debug!
+        } // if
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Drop for Reservation {
+    /// Releases the reserved cost, unless the reservation has been committed.
+    fn drop(&mut self) {
+        if !*self.committed.get_mut() {
+            self.ledger.settle(&self.api, self.charge.cost(), false);
+        } // if
+    } // fn
+} // impl
\ No newline at end of file
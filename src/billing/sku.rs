@@ -0,0 +1,131 @@
+//! Contains the `Sku` enum and its associated traits. It specifies the Google
+//! Maps Platform billing SKU that a request falls under.
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// A Google Maps Platform billing SKU (_stock keeping unit_). Each request is
+/// billed under one SKU, at that SKU's price per unit. See [Google Maps
+/// Platform billing](https://developers.google.com/maps/billing/gmp-billing)
+/// for more information.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum Sku {
+    /// A Directions API request that doesn't use live traffic information,
+    /// has fewer than 11 waypoints and doesn't optimize waypoints.
+    Directions,
+    /// A Directions API request that uses live traffic information, has more
+    /// than 10 waypoints, or optimizes waypoints.
+    DirectionsAdvanced,
+    /// An element of a Distance Matrix API request that doesn't use live
+    /// traffic information.
+    DistanceMatrix,
+    /// An element of a Distance Matrix API request that uses live traffic
+    /// information.
+    DistanceMatrixAdvanced,
+    /// An Elevation API request.
+    Elevation,
+    /// A Geocoding API request, forward or reverse.
+    Geocoding,
+    /// A Geolocation API request.
+    Geolocation,
+    /// A Time Zone API request.
+    TimeZone,
+    /// A Place Autocomplete request that isn't part of a session.
+    AutocompletePerRequest,
+    /// A Query Autocomplete request.
+    QueryAutocomplete,
+    /// A Place Details request, including the _Basic Data_ fields.
+    PlaceDetails,
+    /// A Places API Place Photo request.
+    PlacePhoto,
+    /// A Places API Find Place request that requests fields other than
+    /// `place_id`.
+    FindPlace,
+    /// A Places API Find Place request that only returns each candidate's
+    /// `place_id`.
+    FindPlaceIdOnly,
+    /// A Places API Nearby Search request.
+    NearbySearch,
+    /// A Places API Text Search request.
+    TextSearch,
+    /// A Roads API Nearest Roads request.
+    NearestRoads,
+    /// A Roads API Snap to Roads request.
+    SnapToRoads,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl Sku {
+
+    /// Returns Google's list price, in US dollars, for one unit of the SKU.
+    /// This is the price for the first 100,000 monthly requests.
+    ///
+    /// **These prices are subject to change by Google. Please review the
+    /// current [Google Maps Platform billing
+    /// rates](https://developers.google.com/maps/billing/gmp-billing).**
+    pub fn unit_price(&self) -> Decimal {
+        match self {
+            Sku::Directions => dec!(0.005),
+            Sku::DirectionsAdvanced => dec!(0.01),
+            Sku::DistanceMatrix => dec!(0.005),
+            Sku::DistanceMatrixAdvanced => dec!(0.01),
+            Sku::Elevation => dec!(0.005),
+            Sku::Geocoding => dec!(0.005),
+            Sku::Geolocation => dec!(0.005),
+            Sku::TimeZone => dec!(0.005),
+            Sku::AutocompletePerRequest => dec!(0.00283),
+            Sku::QueryAutocomplete => dec!(0.00283),
+            Sku::PlaceDetails => dec!(0.017),
+            Sku::PlacePhoto => dec!(0.007),
+            Sku::FindPlace => dec!(0.017),
+            Sku::FindPlaceIdOnly => dec!(0),
+            Sku::NearbySearch => dec!(0.032),
+            Sku::TextSearch => dec!(0.032),
+            Sku::NearestRoads => dec!(0.01),
+            Sku::SnapToRoads => dec!(0.01),
+        } // match
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Sku> for String {
+    /// Converts a `Sku` enum to a `String` that contains a SKU name.
+    fn from(sku: &Sku) -> String {
+        match sku {
+            Sku::Directions => String::from("Directions"),
+            Sku::DirectionsAdvanced => String::from("Directions Advanced"),
+            Sku::DistanceMatrix => String::from("Distance Matrix"),
+            Sku::DistanceMatrixAdvanced => String::from("Distance Matrix Advanced"),
+            Sku::Elevation => String::from("Elevation"),
+            Sku::Geocoding => String::from("Geocoding"),
+            Sku::Geolocation => String::from("Geolocation"),
+            Sku::TimeZone => String::from("Time Zone"),
+            Sku::AutocompletePerRequest => String::from("Autocomplete - Per Request"),
+            Sku::QueryAutocomplete => String::from("Query Autocomplete - Per Request"),
+            Sku::PlaceDetails => String::from("Place Details"),
+            Sku::PlacePhoto => String::from("Places Photo"),
+            Sku::FindPlace => String::from("Find Place"),
+            Sku::FindPlaceIdOnly => String::from("Find Place - ID only"),
+            Sku::NearbySearch => String::from("Nearby Search"),
+            Sku::TextSearch => String::from("Text Search"),
+            Sku::NearestRoads => String::from("Roads - Nearest Road"),
+            Sku::SnapToRoads => String::from("Roads - Route Traveled"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Sku {
+    /// Formats a `Sku` enum into a string that is presentable to the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", String::from(self))
+    } // fn
+} // impl
\ No newline at end of file
@@ -0,0 +1,44 @@
+//! Contains the runtime that is used to drive requests to completion for the
+//! synchronous `execute_blocking` methods. These methods are only available
+//! when the `blocking` feature is enabled.
+
+use std::future::Future;
+use std::sync::OnceLock;
+use tokio::runtime::Runtime;
+
+// -----------------------------------------------------------------------------
+
+/// The runtime is created on first use and shared by all blocking requests for
+/// the lifetime of the process. It has a worker thread of its own so that any
+/// background tasks spawned by the HTTP transport (such as pooled connections)
+/// continue to be driven between blocking calls.
+static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+
+// -----------------------------------------------------------------------------
+//
+/// This function is not for public consumption. It is for internal use only.
+///
+/// ## Description
+///
+/// Runs the future to completion on the shared runtime and returns its output.
+/// Because the asynchronous request pipeline is used, blocking requests are
+/// subject to the exact same rate limiting and retry behaviour as asynchronous
+/// requests.
+///
+/// This function must not be called from within an asynchronous context,
+/// otherwise it will panic.
+///
+/// ## Arguments:
+///
+/// * `future` ‧ The future to run to completion.
+
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    RUNTIME
+        .get_or_init(|| tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("google-maps-blocking")
+            .enable_all()
+            .build()
+            .expect("the blocking Google Maps runtime could not be started"))
+        .block_on(future)
+} // fn
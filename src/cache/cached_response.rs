@@ -0,0 +1,134 @@
+//! Contains the `CachedResponse` struct and its associated functions. It is
+//! the form in which a response is stored in a cache backend.
+
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+use crate::transport::HttpResponse;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// A successful HTTP response, as stored in a cache backend. The headers are
+/// kept along with the body, so that a cache hit is rebuilt into the same
+/// `HttpResponse` that Google returned and is deserialized in the same way.
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub(crate) struct CachedResponse {
+
+    /// The HTTP status code of the response.
+    status: u16,
+
+    /// The headers of the response.
+    headers: Vec<(String, String)>,
+
+    /// The body of the response.
+    body: CachedBody,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// The body of a cached response. Text bodies (such as JSON) are stored as-is
+/// so that they remain readable in the backend, and are not re-formatted. Any
+/// other body (for example, an image) is stored as a base64 string.
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum CachedBody {
+    Text(String),
+    Base64(String),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl CachedResponse {
+
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// ## Description
+    ///
+    /// Serializes the HTTP response so that it may be stored in a cache
+    /// backend.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `response` ‧ Google's successful HTTP response.
+
+    pub(crate) fn encode(response: &HttpResponse) -> Vec<u8> {
+        let body = match std::str::from_utf8(&response.body) {
+            Ok(text) => CachedBody::Text(text.to_string()),
+            Err(_error) => CachedBody::Base64(BASE64.encode(&response.body)),
+        }; // match
+        let cached = CachedResponse {
+            status: response.status,
+            headers: response.headers.clone(),
+            body,
+        }; // CachedResponse
+        serde_json::to_vec(&cached).unwrap_or_default()
+    } // fn
+
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// ## Description
+    ///
+    /// Rebuilds the HTTP response from a value that was read from a cache
+    /// backend. Returns an error message if the value can't be decoded.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `value` ‧ The value that was stored by `encode`.
+
+    pub(crate) fn decode(value: &[u8]) -> Result<HttpResponse, String> {
+        let cached: CachedResponse = serde_json::from_slice(value)
+            .map_err(|error| error.to_string())?;
+        let body = match cached.body {
+            CachedBody::Text(text) => text.into_bytes(),
+            CachedBody::Base64(base64) => BASE64
+                .decode(base64)
+                .map_err(|error| error.to_string())?,
+        }; // match
+        Ok(HttpResponse { status: cached.status, headers: cached.headers, body })
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use crate::cache::cached_response::CachedResponse;
+    use crate::transport::HttpResponse;
+
+    #[test]
+    fn text_response_round_trip() {
+        let body = r#"{ "results": [], "status": "OK" }"#;
+        let response = HttpResponse {
+            status: 200,
+            headers: vec![("Content-Type".to_string(), "application/json".to_string())],
+            body: body.as_bytes().to_vec(),
+        }; // HttpResponse
+        let value = CachedResponse::encode(&response);
+        // The JSON body is stored verbatim, rather than re-formatted:
+        assert!(String::from_utf8_lossy(&value).contains(r#"{ \"results\": [], \"status\": \"OK\" }"#));
+        assert_eq!(CachedResponse::decode(&value), Ok(response));
+    } // fn
+
+    #[test]
+    fn binary_response_round_trip() {
+        let response = HttpResponse {
+            status: 200,
+            headers: vec![("Content-Type".to_string(), "image/jpeg".to_string())],
+            body: vec![0xff, 0xd8, 0xff, 0xe0, 0x00, 0x80, 0xff, 0xd9],
+        }; // HttpResponse
+        let value = CachedResponse::encode(&response);
+        assert!(String::from_utf8_lossy(&value).contains(r#""base64":"/9j/4ACA/9k=""#));
+        assert_eq!(CachedResponse::decode(&value), Ok(response));
+    } // fn
+
+    #[test]
+    fn invalid_value_is_an_error() {
+        assert!(CachedResponse::decode(b"not a cached response").is_err());
+        assert!(CachedResponse::decode(br#"{ "status": 200, "headers": [], "body": { "base64": "!" } }"#).is_err());
+    } // fn
+
+} // mod
\ No newline at end of file
@@ -0,0 +1,141 @@
+//! Contains the `MemoryCache` struct and its associated traits. It is an
+//! in-memory, least-recently-used cache backend.
+
+use crate::cache::CacheBackend;
+use futures::future::BoxFuture;
+use lru::LruCache;
+use std::{
+    num::NonZeroUsize,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+}; // use std
+
+// -----------------------------------------------------------------------------
+
+/// The cached responses, keyed by request, along with when they expire. An
+/// expiry of `None` means that the response never expires.
+type Entries = LruCache<String, (Vec<u8>, Option<Instant>)>;
+
+// -----------------------------------------------------------------------------
+//
+/// An in-memory cache backend. Once the cache is full, the least recently used
+/// response is evicted. Expired responses are evicted when they are next
+/// looked up.
+///
+/// The cache is shared between clones, so a clone may be kept to inspect or
+/// clear the cache after it has been passed to the client.
+
+#[derive(Clone, Debug)]
+pub struct MemoryCache {
+    entries: Arc<Mutex<Entries>>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl MemoryCache {
+
+    /// Initializes an in-memory cache that holds up to `capacity` responses. A
+    /// capacity of `0` is treated as `1`.
+    pub fn new(capacity: usize) -> MemoryCache {
+        let capacity = NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN);
+        MemoryCache { entries: Arc::new(Mutex::new(LruCache::new(capacity))) }
+    } // fn
+
+    /// Returns the number of responses in the cache, including any that have
+    /// expired but have not been evicted yet.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    } // fn
+
+    /// Returns `true` if the cache contains no responses.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    } // fn
+
+    /// Removes all responses from the cache.
+    pub fn clear(&self) {
+        self.lock().clear();
+    } // fn
+
+    /// Locks the cache. A poisoned lock is recovered, since the cache holds no
+    /// invariants that a panic could break.
+    fn lock(&self) -> MutexGuard<'_, Entries> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl CacheBackend for MemoryCache {
+
+    /// Returns the cached response for the key, if it has not expired.
+    fn get(&self, key: &str) -> BoxFuture<'_, Option<Vec<u8>>> {
+        let mut entries = self.lock();
+        let value = match entries.get(key) {
+            Some((value, expires_at)) if expires_at.is_none_or(|expires_at| expires_at > Instant::now()) =>
+                Some(value.clone()),
+            Some(_expired) => {
+                entries.pop(key);
+                None
+            }, // Some
+            None => None,
+        }; // match
+        Box::pin(async move { value })
+    } // fn
+
+    /// Stores the response for the key until the time-to-live elapses. A
+    /// time-to-live too long to be represented (such as `Duration::MAX`) never
+    /// expires.
+    fn set(&self, key: &str, value: Vec<u8>, ttl: Duration) -> BoxFuture<'_, ()> {
+        let expires_at = Instant::now().checked_add(ttl);
+        self.lock().put(key.to_string(), (value, expires_at));
+        Box::pin(async move { })
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use crate::cache::{CacheBackend, MemoryCache};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn returns_stored_value() {
+        let cache = MemoryCache::new(10);
+        cache.set("key", b"value".to_vec(), Duration::from_secs(60)).await;
+        assert_eq!(cache.get("key").await, Some(b"value".to_vec()));
+        assert_eq!(cache.get("other").await, None);
+    } // fn
+
+    #[tokio::test]
+    async fn expired_value_is_evicted() {
+        let cache = MemoryCache::new(10);
+        cache.set("key", b"value".to_vec(), Duration::ZERO).await;
+        assert_eq!(cache.get("key").await, None);
+        assert!(cache.is_empty());
+    } // fn
+
+    #[tokio::test]
+    async fn maximum_ttl_never_expires() {
+        let cache = MemoryCache::new(10);
+        cache.set("key", b"value".to_vec(), Duration::MAX).await;
+        assert_eq!(cache.get("key").await, Some(b"value".to_vec()));
+    } // fn
+
+    #[tokio::test]
+    async fn least_recently_used_value_is_evicted() {
+        let cache = MemoryCache::new(2);
+        cache.set("a", b"a".to_vec(), Duration::MAX).await;
+        cache.set("b", b"b".to_vec(), Duration::MAX).await;
+        cache.get("a").await;
+        cache.set("c", b"c".to_vec(), Duration::MAX).await;
+        assert_eq!(cache.get("b").await, None);
+        assert_eq!(cache.get("a").await, Some(b"a".to_vec()));
+        assert_eq!(cache.len(), 2);
+    } // fn
+
+} // mod
\ No newline at end of file
@@ -0,0 +1,79 @@
+//! Contains the `CacheBackend` trait and its associated types. An opt-in
+//! response cache may be placed in front of the `GoogleMapsClient`, so that
+//! repeated requests (for example, geocoding the same address or asking for
+//! the same time zone) are answered without calling, or paying for, the
+//! Google Maps Platform.
+//!
+//! Responses are cached using the request's URL (without the API key, client
+//! ID or signature) as the key. Only successful responses are cached. How long
+//! responses are kept for may be set for each API. Cache hits and misses are
+//! reported using `tracing` at the `debug` level.
+//!
+//! By default, the `MemoryCache` backend may be used. Any other storage (for
+//! example, files, SQLite or Redis) may be used instead by implementing the
+//! `CacheBackend` trait.
+//!
+//! Please make sure that caching is permitted by the Google Maps Platform
+//! Terms of Service for the APIs and durations that you configure.
+
+mod cached_response;
+pub mod memory_cache;
+pub mod response_cache;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::cache::{
+    memory_cache::MemoryCache,
+    response_cache::ResponseCache,
+}; // crate::cache
+
+pub(crate) use crate::cache::cached_response::CachedResponse;
+
+// -----------------------------------------------------------------------------
+
+use futures::future::BoxFuture;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+//
+/// Implement this trait to store cached responses in a backend of your choice.
+///
+/// Backends should not fail: if a value cannot be read, `get` should return
+/// `None` and the request will be sent to Google as usual. If a value cannot be
+/// written, `set` should discard it.
+///
+/// ## Example:
+///
+/// ```rust
+/// use futures::future::BoxFuture;
+/// use google_maps::cache::CacheBackend;
+/// use std::time::Duration;
+///
+/// #[derive(Debug)]
+/// struct RedisCache { /* ... */ }
+///
+/// impl CacheBackend for RedisCache {
+///     fn get(&self, key: &str) -> BoxFuture<'_, Option<Vec<u8>>> {
+///         let key = key.to_string();
+///         Box::pin(async move { /* GET key */ })
+///     }
+///     fn set(&self, key: &str, value: Vec<u8>, ttl: Duration) -> BoxFuture<'_, ()> {
+///         let key = key.to_string();
+///         Box::pin(async move { /* SET key value EX ttl */ })
+///     }
+/// }
+/// ```
+
+pub trait CacheBackend: std::fmt::Debug + Send + Sync {
+
+    /// Returns the cached response for the key, or `None` if there is no
+    /// cached response or it has expired. The value is opaque to the backend:
+    /// it is returned exactly as it was stored.
+    fn get(&self, key: &str) -> BoxFuture<'_, Option<Vec<u8>>>;
+
+    /// Stores the response for the key. The response (Google's response body
+    /// and headers, serialized as JSON) should be discarded once the
+    /// time-to-live has elapsed.
+    fn set(&self, key: &str, value: Vec<u8>, ttl: Duration) -> BoxFuture<'_, ()>;
+
+} // trait
\ No newline at end of file
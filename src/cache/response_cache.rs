@@ -0,0 +1,200 @@
+//! Contains the `ResponseCache` struct and its associated traits. It holds the
+//! client's cache backend and the time-to-live of each API's responses.
+
+use crate::cache::{CacheBackend, CachedResponse};
+use crate::request_rate::api::Api;
+use crate::traits::ServiceResponse;
+use crate::transport::HttpResponse;
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+// -----------------------------------------------------------------------------
+//
+/// The response cache settings of a `GoogleMapsClient`. It is usually
+/// configured using the client's `with_cache` and `with_cache_ttl` methods.
+
+#[derive(Clone, Debug)]
+pub struct ResponseCache {
+
+    /// Where cached responses are stored.
+    pub backend: Arc<dyn CacheBackend>,
+
+    /// How long responses are cached for, for each API. A time-to-live set
+    /// for a group, such as `Api::Places`, is used by every API in the group
+    /// that does not have its own. A time-to-live set for `Api::All` is used by
+    /// every API that does not have its own or its group's. An API with no
+    /// time-to-live, or a time-to-live of zero, is not cached.
+    pub ttls: HashMap<Api, Duration>,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl ResponseCache {
+
+    // -------------------------------------------------------------------------
+    //
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// ## Description
+    ///
+    /// Returns how long responses from the specified API should be cached for,
+    /// or `None` if they should not be cached.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `api` ‧ The API that the request is being made to.
+
+    pub(crate) fn ttl(&self, api: &Api) -> Option<Duration> {
+        api.scopes()
+            .iter()
+            .rev()
+            .find_map(|scope| self.ttls.get(scope))
+            .copied()
+            .filter(|ttl| !ttl.is_zero())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// ## Description
+    ///
+    /// Returns the cached response for the key, if the API's responses are
+    /// cached and a response is found. The cached HTTP response is
+    /// deserialized by `ServiceResponse::from_http_response`, exactly as a
+    /// response from Google is. Cache hits and misses are reported using
+    /// `tracing`. A cached response that can no longer be deserialized is
+    /// treated as a miss.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `api` ‧ The API that the request is being made to.
+    ///
+    /// * `title` ‧ The human-readable name of the API, used in log messages.
+    ///
+    /// * `key` ‧ The cache key. This is the request URL without the client's
+    ///   credentials.
+
+    pub(crate) async fn get<T: ServiceResponse>(
+        &self,
+        api: &Api,
+        title: &str,
+        key: &str,
+    ) -> Option<T> {
+
+        // Responses from this API are not cached:
+        self.ttl(api)?;
+
+        match self.backend.get(key).await {
+            Some(value) => match CachedResponse::decode(&value)
+                .and_then(|response| T::from_http_response(&response).map_err(|error| error.to_string())) {
+                Ok(response) => {
+                    tracing::debug!("Cache hit for Google Maps {title} API: `{key}`");
+                    Some(response)
+                }, // Ok
+                Err(error) => {
+                    tracing::warn!("Cached Google Maps {title} API response could not be parsed: {error}");
+                    None
+                }, // Err
+            }, // Some
+            None => {
+                tracing::debug!("Cache miss for Google Maps {title} API: `{key}`");
+                None
+            }, // None
+        } // match
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// ## Description
+    ///
+    /// Stores the HTTP response for the key, if the API's responses are
+    /// cached. Its headers are stored along with its body.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `api` ‧ The API that the request was made to.
+    ///
+    /// * `key` ‧ The cache key. This is the request URL without the client's
+    ///   credentials.
+    ///
+    /// * `response` ‧ Google's successful HTTP response.
+
+    pub(crate) async fn set(&self, api: &Api, key: &str, response: &HttpResponse) {
+        if let Some(ttl) = self.ttl(api) {
+            self.backend.set(key, CachedResponse::encode(response), ttl).await;
+        } // if
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use crate::cache::{MemoryCache, ResponseCache};
+    use crate::request_rate::api::Api;
+    use std::{collections::HashMap, sync::Arc, time::Duration};
+
+    fn cache(ttls: impl IntoIterator<Item = (Api, Duration)>) -> ResponseCache {
+        ResponseCache {
+            backend: Arc::new(MemoryCache::new(10)),
+            ttls: HashMap::from_iter(ttls),
+        } // ResponseCache
+    } // fn
+
+    #[test]
+    fn most_specific_ttl_is_used() {
+        let day = Duration::from_secs(86_400);
+        let cache = cache([
+            (Api::All, day),
+            (Api::Places, 2 * day),
+            (Api::TextSearch, 3 * day),
+        ]); // cache
+        assert_eq!(cache.ttl(&Api::Geocoding), Some(day));
+        assert_eq!(cache.ttl(&Api::NearbySearch), Some(2 * day));
+        assert_eq!(cache.ttl(&Api::TextSearch), Some(3 * day));
+    } // fn
+
+    #[test]
+    fn zero_or_missing_ttl_disables_caching() {
+        let cache = cache([(Api::Places, Duration::from_secs(60)), (Api::TextSearch, Duration::ZERO)]);
+        assert_eq!(cache.ttl(&Api::TextSearch), None);
+        assert_eq!(cache.ttl(&Api::Geocoding), None);
+    } // fn
+
+    #[cfg(feature = "places")]
+    #[tokio::test]
+    async fn hit_is_deserialized_from_the_http_response() {
+        use crate::places::photo::response::Response;
+        use crate::transport::HttpResponse;
+        let cache = cache([(Api::All, Duration::from_secs(60))]);
+        let response = HttpResponse {
+            status: 200,
+            headers: vec![("Content-Type".to_string(), "image/png".to_string())],
+            body: vec![0x89, 0x50, 0x4e, 0x47],
+        }; // HttpResponse
+        cache.set(&Api::PlacePhoto, "photo", &response).await;
+        let cached = cache.get::<Response>(&Api::PlacePhoto, "Place Photo", "photo").await;
+        assert_eq!(cached, Some(Response { bytes: response.body, content_type: "image/png".to_string() }));
+    } // fn
+
+    #[cfg(feature = "geocoding")]
+    #[tokio::test]
+    async fn unreadable_value_is_a_miss() {
+        use crate::cache::CacheBackend;
+        use crate::geocoding::response::Response;
+        let backend = MemoryCache::new(10);
+        backend.set("key", b"{ \"results\": [] }".to_vec(), Duration::from_secs(60)).await;
+        let cache = ResponseCache {
+            backend: Arc::new(backend),
+            ttls: HashMap::from([(Api::All, Duration::from_secs(60))]),
+        }; // ResponseCache
+        assert_eq!(cache.get::<Response>(&Api::Geocoding, "Geocoding", "key").await, None);
+    } // fn
+
+} // mod
\ No newline at end of file
@@ -0,0 +1,53 @@
+use crate::client::GoogleMapsClient;
+use crate::url_signing::{path_and_query, signature};
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// ## Description
+    ///
+    /// Appends the client's credentials to a request URL. The client ID is
+    /// appended if one has been set, otherwise the API key is appended (unless
+    /// it is empty). If a
+    /// URL signing secret has been set, the URL's path and query string are
+    /// then signed and the `signature` parameter is appended last, as required
+    /// by the Google Maps Platform.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `url` ‧ The full request URL, including the query string that was
+    ///   built by the request builder.
+
+    pub(crate) fn authenticated_url(&self, url: &str) -> String {
+
+        // End-points that are sent a request body have an empty query string,
+        // so their first parameter must not be preceded by an ampersand:
+        let separator = if url.ends_with('?') { "" } else { "&" };
+
+        let mut url = match &self.client_id {
+            Some(client_id) => format!("{url}{separator}client={client_id}"),
+            // An empty API key is omitted. This allows requests to be
+            // authenticated using only an OAuth 2.0 access token:
+            None if self.key.is_empty() => url.to_string(),
+            None => format!("{url}{separator}key={key}", key = self.key),
+        }; // match
+
+        if let Some(signing_secret) = &self.signing_secret {
+            let signature = signature(
+                path_and_query(&url).unwrap_or(&url),
+                signing_secret,
+            ); // signature
+            url.push_str("&signature=");
+            url.push_str(&signature);
+        } // if
+
+        url
+
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,35 @@
+use crate::client::GoogleMapsClient;
+use crate::oauth::Error;
+use crate::transport::HttpRequest;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// ## Description
+    ///
+    /// Adds an `Authorization: Bearer ...` header to the HTTP request if the
+    /// client has a token provider. Otherwise, the request is returned as-is.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `request` ‧ The HTTP request that is about to be sent.
+
+    pub(crate) async fn authorized_request(
+        &self,
+        request: HttpRequest,
+    ) -> Result<HttpRequest, Error> {
+        match &self.token_provider {
+            Some(token_provider) => {
+                let access_token = token_provider.access_token().await?;
+                Ok(request.with_header("Authorization", &format!("Bearer {access_token}")))
+            }, // Some
+            None => Ok(request),
+        } // match
+    } // fn
+
+} // impl
\ No newline at end of file
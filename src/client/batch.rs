@@ -0,0 +1,68 @@
+use crate::client::GoogleMapsClient;
+use crate::error::Error as GoogleMapsError;
+use crate::traits::RequestDescription;
+use futures::stream::StreamExt;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Executes a batch of requests of one kind using this client, running at
+    /// most `concurrency` of them at a time, and returns each request's result
+    /// in the same order as the input.
+    ///
+    /// The requests are passed as client-independent descriptions (for
+    /// example, a `GeocodingRequestDescription`), which are bound to this
+    /// client as they're executed. Every request passes through the client's
+    /// rate limits, cache, retry policy and budget, so a large batch is paced
+    /// according to the `RequestRate` that has been set with `with_rate`. A
+    /// failed request does not abort the batch: its error is returned in its
+    /// position and the remaining requests carry on.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `descriptions` ‧ The descriptions of the requests to execute. A
+    ///   description may be obtained from a request using its `describe()`
+    ///   method, or deserialized from a job queue.
+    ///
+    /// * `concurrency` ‧ The maximum number of requests that may be in flight
+    ///   at once. A value of `0` is treated as `1`.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::new(YOUR_API_KEY);
+    ///
+    /// let descriptions = addresses.iter().map(|address| {
+    ///     google_maps_client.geocoding().with_address(address).describe()
+    /// }); // map
+    ///
+    /// let results = google_maps_client.batch(descriptions, 10).await;
+    ///
+    /// for (address, result) in addresses.iter().zip(results) {
+    ///     match result {
+    ///         Ok(response) => println!("{address}: {:#?}", response.results),
+    ///         Err(error) => println!("{address}: {error}"),
+    ///     } // match
+    /// } // for
+    /// ```
+
+    pub async fn batch<I>(
+        &self,
+        descriptions: I,
+        concurrency: usize,
+    ) -> Vec<Result<<I::Item as RequestDescription>::Response, GoogleMapsError>>
+    where
+        I: IntoIterator,
+        I::Item: RequestDescription,
+    {
+
+        self.batch_stream(futures::stream::iter(descriptions), concurrency)
+            .collect()
+            .await
+
+    } // fn
+
+} // impl
\ No newline at end of file
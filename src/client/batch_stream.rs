@@ -0,0 +1,137 @@
+use crate::client::GoogleMapsClient;
+use crate::error::Error as GoogleMapsError;
+use crate::traits::RequestDescription;
+use futures::stream::{Stream, StreamExt};
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Executes a stream of requests of one kind using this client, running at
+    /// most `concurrency` of them at a time. Returns a stream of each request's
+    /// result, in the same order as the input.
+    ///
+    /// This is the streaming version of `batch`. It's useful for very large
+    /// request sets because the descriptions are pulled from the input stream
+    /// only as capacity frees up, and results may be handled (or persisted) as
+    /// they arrive rather than all at once at the end. A failed request does
+    /// not end the stream.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `descriptions` ‧ A stream of request descriptions to bind to this
+    ///   client and execute.
+    ///
+    /// * `concurrency` ‧ The maximum number of requests that may be in flight
+    ///   at once. A value of `0` is treated as `1`.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let (job_ids, descriptions): (Vec<_>, Vec<_>) = jobs.into_iter().unzip();
+    ///
+    /// let mut results = google_maps_client
+    ///     .batch_stream(futures::stream::iter(descriptions), 10)
+    ///     .zip(futures::stream::iter(job_ids));
+    ///
+    /// while let Some((result, job_id)) = results.next().await {
+    ///     save_result(job_id, result).await?;
+    /// } // while
+    /// ```
+
+    pub fn batch_stream<'a, S>(
+        &'a self,
+        descriptions: S,
+        concurrency: usize,
+    ) -> impl Stream<Item = Result<<S::Item as RequestDescription>::Response, GoogleMapsError>> + 'a
+    where
+        S: Stream + 'a,
+        S::Item: RequestDescription,
+    {
+
+        descriptions
+            .map(move |description| description.execute(self))
+            .buffered(concurrency.max(1))
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "geocoding"))]
+mod tests {
+
+    use crate::client::GoogleMapsClient;
+    use crate::transport::{Error, HttpRequest, HttpResponse, Transport};
+    use futures::future::BoxFuture;
+    use futures::stream::StreamExt;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    /// A transport that answers slowly for addresses containing `slow`, and
+    /// quickly for the others. Addresses containing `missing` have no results.
+    /// It remembers how many requests were in flight at once.
+    #[derive(Clone, Debug, Default)]
+    struct DelayedTransport {
+        in_flight: Arc<AtomicUsize>,
+        max_in_flight: Arc<AtomicUsize>,
+    } // struct
+
+    impl Transport for DelayedTransport {
+        fn execute(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, Error>> {
+            Box::pin(async move {
+                let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+                let delay = if request.url.contains("slow") { 3 } else { 1 };
+                tokio::time::sleep(Duration::from_secs(delay)).await;
+                self.in_flight.fetch_sub(1, Ordering::SeqCst);
+                let status = if request.url.contains("missing") { "ZERO_RESULTS" } else { "OK" };
+                Ok(HttpResponse::new(200, format!(r#"{{ "results": [], "status": "{status}" }}"#)))
+            }) // Box::pin
+        } // fn
+    } // impl
+
+    #[tokio::test(start_paused = true)]
+    async fn results_are_in_input_order() {
+        let transport = DelayedTransport::default();
+        let client = GoogleMapsClient::new("TEST_KEY")
+            .with_transport(transport.clone())
+            .build();
+        let addresses = ["slow missing", "fast", "fast", "slow missing", "fast"];
+
+        let descriptions = addresses
+            .iter()
+            .map(|address| client.geocoding().with_address(address).describe());
+        let results: Vec<bool> = client
+            .batch_stream(futures::stream::iter(descriptions), 2)
+            .map(|result| result.is_ok())
+            .collect()
+            .await;
+
+        // The slow requests finish last, but their results are returned in
+        // their positions, and their errors don't abort the others:
+        assert_eq!(results, vec![false, true, true, false, true]);
+        assert_eq!(transport.max_in_flight.load(Ordering::SeqCst), 2);
+    } // fn
+
+    #[tokio::test(start_paused = true)]
+    async fn zero_concurrency_runs_one_request_at_a_time() {
+        let transport = DelayedTransport::default();
+        let client = GoogleMapsClient::new("TEST_KEY")
+            .with_transport(transport.clone())
+            .build();
+        let descriptions = ["fast", "slow", "fast"]
+            .iter()
+            .map(|address| client.geocoding().with_address(address).describe());
+
+        let results = client.batch(descriptions, 0).await;
+
+        assert!(results.iter().all(Result::is_ok));
+        assert_eq!(transport.max_in_flight.load(Ordering::SeqCst), 1);
+    } // fn
+
+} // mod
\ No newline at end of file
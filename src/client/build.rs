@@ -29,6 +29,10 @@ impl GoogleMapsClient {
     pub fn build(&self) -> GoogleMapsClient {
         GoogleMapsClient {
             key: self.key.clone(),
+            reveal_secrets: self.reveal_secrets,
+            client_id: self.client_id.clone(),
+            signing_secret: self.signing_secret.clone(),
+            base_urls: self.base_urls.clone(),
         } // GoogleMapsClient
     } // fn
 
@@ -0,0 +1,81 @@
+//! Contains the `ClientRef` enum and its associated traits. It is how a request
+//! refers to the `GoogleMapsClient` that will execute it.
+
+use crate::client::GoogleMapsClient;
+use std::sync::Arc;
+
+// -----------------------------------------------------------------------------
+//
+/// How a request refers to the `GoogleMapsClient` that will execute it.
+///
+/// Requests created using the client's methods, such as
+/// `google_maps_client.geocoding()`, borrow the client. Requests created from
+/// an `Arc<GoogleMapsClient>` share ownership of the client instead. They are
+/// `'static`, so they may be moved onto another task, stored in a queue or
+/// returned from a function:
+///
+/// ```rust
+/// let google_maps_client = Arc::new(GoogleMapsClient::new("YOUR_API_KEY_HERE"));
+///
+/// let mut request = ForwardRequest::new(google_maps_client.clone());
+/// request.with_address("10 Downing Street London");
+///
+/// let handle = tokio::spawn(async move { request.await });
+/// ```
+
+#[derive(Clone, Debug)]
+pub enum ClientRef<'a> {
+    /// The request borrows the client.
+    Borrowed(&'a GoogleMapsClient),
+    /// The request shares ownership of the client.
+    Shared(Arc<GoogleMapsClient>),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::ops::Deref for ClientRef<'_> {
+    type Target = GoogleMapsClient;
+    /// Returns the client that the request refers to.
+    fn deref(&self) -> &GoogleMapsClient {
+        match self {
+            ClientRef::Borrowed(client) => client,
+            ClientRef::Shared(client) => client,
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<'a> std::convert::From<&'a GoogleMapsClient> for ClientRef<'a> {
+    /// Converts a borrowed client into a `ClientRef`.
+    fn from(client: &'a GoogleMapsClient) -> Self {
+        ClientRef::Borrowed(client)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<Arc<GoogleMapsClient>> for ClientRef<'_> {
+    /// Converts a shared client into a `ClientRef`.
+    fn from(client: Arc<GoogleMapsClient>) -> Self {
+        ClientRef::Shared(client)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Arc<GoogleMapsClient>> for ClientRef<'_> {
+    /// Converts a shared client into a `ClientRef`, cloning the `Arc`.
+    fn from(client: &Arc<GoogleMapsClient>) -> Self {
+        ClientRef::Shared(Arc::clone(client))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<GoogleMapsClient> for ClientRef<'_> {
+    /// Converts an owned client into a `ClientRef`.
+    fn from(client: GoogleMapsClient) -> Self {
+        ClientRef::Shared(Arc::new(client))
+    } // fn
+} // impl
\ No newline at end of file
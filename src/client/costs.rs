@@ -0,0 +1,46 @@
+use crate::client::GoogleMapsClient;
+use crate::request_rate::api::Api;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the estimated cost, in US dollars, of this calendar month's
+    /// requests for each API. Each request is classified into its billing SKU
+    /// before it is sent. For example, a Directions API request with more than
+    /// 10 waypoints is charged as a _Directions Advanced_ request.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// for (api, cost) in google_maps_client.costs() {
+    ///     println!("{api}: ${cost} USD");
+    /// }
+    /// ```
+
+    pub fn costs(&self) -> HashMap<Api, Decimal> {
+        self.cost_ledger.costs()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the estimated cost, in US dollars, of this calendar month's
+    /// requests for all APIs.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn total_cost(&self) -> Decimal {
+        self.cost_ledger.total()
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,42 @@
+use crate::client::{GoogleMapsClient, redact::REDACTED};
+use crate::url_signing::encode_signing_secret;
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for GoogleMapsClient {
+    /// Formats the client for debugging. The API key and URL signing secret are
+    /// replaced with `REDACTED` unless the client has opted-in to revealing secrets using
+    /// `with_reveal_secrets`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut debug_struct = f.debug_struct("GoogleMapsClient");
+        let signing_secret = self.signing_secret.as_ref().map(|signing_secret|
+            if self.reveal_secrets {
+                encode_signing_secret(signing_secret)
+            } else {
+                REDACTED.to_string()
+            } // if
+        ); // map
+        if self.reveal_secrets {
+            debug_struct.field("key", &self.key);
+        } else {
+            debug_struct.field("key", &REDACTED);
+        } // if
+        debug_struct
+            .field("reveal_secrets", &self.reveal_secrets)
+            .field("client_id", &self.client_id)
+            .field("signing_secret", &signing_secret)
+            .field("base_urls", &self.base_urls);
+        #[cfg(feature = "enable-reqwest")]
+        debug_struct
+            .field("rate_limit", &self.rate_limit)
+            .field("retry_policy", &self.retry_policy)
+            .field("timeout", &self.timeout)
+            .field("transport", &self.transport)
+            .field("token_provider", &self.token_provider)
+            .field("cache", &self.cache)
+            .field("cache_ttls", &self.cache_ttls)
+            .field("cost_ledger", &self.cost_ledger)
+            .field("metrics", &self.metrics);
+        debug_struct.finish()
+    } // fn
+} // impl
\ No newline at end of file
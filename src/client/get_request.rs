@@ -0,0 +1,384 @@
+use backoff::Error::{Permanent, Transient};
+use backoff::future::retry;
+use crate::billing::Reservation;
+use crate::client::GoogleMapsClient;
+use crate::error::Error as GoogleMapsError;
+use crate::metrics::RequestRecorder;
+use crate::oauth::Error as OAuthError;
+use crate::traits::{EndPoint, ServiceError, ServiceResponse};
+use crate::transport::{HttpRequest, HttpResponse};
+use std::time::{Duration, Instant};
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// ## Description
+    ///
+    /// Performs the HTTP get request for any Google Maps Platform API
+    /// end-point and returns the response to the caller. This is the shared
+    /// request pipeline: it consults the client's response cache, reserves the
+    /// request's estimated cost, observes the client's rate limits, sends the
+    /// request through the client's transport, retries transient errors
+    /// according to the client's retry policy, deserializes the response,
+    /// checks Google's response status and charges the reserved cost once
+    /// Google answers successfully. The rate limiter waits and retries are
+    /// bounded by the request's (or the client's) timeout, if any.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `request` ‧ The request to send. Its query string must have been
+    ///   built.
+
+    pub(crate) async fn get_request<R: EndPoint>(
+        &self,
+        request: &R,
+    ) -> Result<R::Response, GoogleMapsError> {
+
+        // Build the URL stem for the HTTP get request:
+        let service_url = self.service_url(&R::api(), R::service_url());
+        let mut url = match R::output_format() {
+            Some(output_format) => format!("{service_url}/{output_format}?"),
+            None => format!("{service_url}?"),
+        }; // match
+
+        match request.query_string() {
+            // If query string built, append it to the URL stem.
+            Some(query) => url.push_str(query),
+            // If query string not built, return an error.
+            None => return Err(<R::Response as ServiceResponse>::Error::query_not_built().into()),
+        } // match
+
+        // Collect the request's metrics for the client's metrics hook, if it
+        // has one:
+        let recorder = RequestRecorder::new(self.metrics.as_deref(), R::api());
+
+        // Answer the request from the response cache, if possible. The URL
+        // without the client's credentials is used as the cache key. Requests
+        // with a body are not cached since the URL doesn't identify them, nor
+        // are end-points that opt out:
+        let cache_key = url;
+        let cache = match request.cacheable() {
+            true => self.cache.as_ref(),
+            false => None,
+        }; // match
+        if let Some(cache) = cache {
+            if let Some(response) = cache.get(&R::api(), R::title(), &cache_key).await {
+                recorder.finish(Duration::ZERO, true, true);
+                return Ok(response)
+            } // if
+        } // if
+
+        // Reserve the estimated cost of the request against the client's
+        // budget. The request is refused if it would exceed the budget. The
+        // cost is only charged once Google answers the request successfully.
+        // The reservation is released if the request fails or is abandoned:
+        let reservation = match self.cost_ledger.reserve(&R::api(), &request.charge()) {
+            Ok(reservation) => reservation,
+            Err(error) => {
+                recorder.finish(Duration::ZERO, false, false);
+                return Err(error.into())
+            }, // Err
+        }; // match
+
+        // Append the client's credentials and sign the URL, if required. The
+        // credentials are not part of the query string so that they aren't
+        // leaked by the request's `Debug` output:
+        let url = self.authenticated_url(&cache_key);
+
+        // The rate limiter waits, the HTTP request and its retries are bounded
+        // by the request's deadline, or by the client's default deadline:
+        let timeout = request.timeout().or(self.timeout);
+        let pipeline = self.send_request::<R>(request, &url, &recorder, &reservation);
+        let (rate_limit_wait, result) = match timeout {
+            Some(timeout) => match tokio::time::timeout(timeout, pipeline).await {
+                Ok(output) => output,
+                Err(_elapsed) => {
+                    tracing::error!(
+                        "Google Maps {} API request did not complete within {:?}",
+                        R::title(),
+                        timeout,
+                    ); // error!
+                    // The time spent waiting on the rate limits is unknown
+                    // when the request is abandoned:
+                    recorder.finish(Duration::ZERO, false, false);
+                    return Err(GoogleMapsError::Timeout(timeout))
+                }, // Err
+            }, // Some
+            None => pipeline.await,
+        }; // match
+
+        recorder.finish(rate_limit_wait, false, result.is_ok());
+        let (response, http_response) = result?;
+
+        // Store the response in the response cache, if there is one:
+        if let Some(cache) = cache {
+            cache.set(&R::api(), &cache_key, &http_response).await;
+        } // if
+
+        // Return response to caller:
+        Ok(response)
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// ## Description
+    ///
+    /// Observes the client's rate limits and then sends the request, retrying
+    /// transient errors according to the client's retry policy. Returns the
+    /// time spent waiting on the rate limits, along with the deserialized
+    /// response and the HTTP response it was deserialized from.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `request` ‧ The request to send.
+    /// * `url` ‧ The authenticated URL of the request.
+    /// * `recorder` ‧ Collects the request's metrics.
+    /// * `reservation` ‧ The request's reserved cost, which is committed once
+    ///   Google answers the request successfully.
+
+    async fn send_request<R: EndPoint>(
+        &self,
+        request: &R,
+        url: &str,
+        recorder: &RequestRecorder<'_>,
+        reservation: &Reservation,
+    ) -> (Duration, Result<(R::Response, HttpResponse), GoogleMapsError>) {
+
+        // Observe any rate limiting before executing request:
+        let rate_limit_wait = self.rate_limit.limit_apis(&R::api().scopes(), request.weight()).await;
+
+        // Requests with a body are sent as an HTTP post request, all others
+        // as an HTTP get request:
+        let http_request = match request.request_body() {
+            Some(body) => HttpRequest::post(url, body.as_bytes().to_vec())
+                .with_header("Content-Type", "application/json"),
+            None => HttpRequest::get(url),
+        }; // match
+
+        // Emit debug message so client can monitor activity:
+        tracing::debug!(
+            "Making HTTP {} request to Google Maps {} API: `{}`",
+            http_request.method,
+            R::title(),
+            self.redact_url(url),
+        ); // debug!
+
+        // Retries the get request until successful, an error ineligible for
+        // retries is returned, or we have reached the maximum retries. Note:
+        // errors wrapped in `Transient()` will retried by the `backoff` crate
+        // while errors wrapped in `Permanent()` will exit the retry loop.
+        let backoff = self.retry_policy.backoff();
+        let server_delay = backoff.server_delay();
+        let result = retry(backoff, || async {
+
+            let attempt_start = Instant::now();
+
+            // Add the OAuth access token to the request, if the client has a
+            // token provider:
+            let request = match self.authorized_request(http_request.clone()).await {
+                Ok(request) => request,
+                // The access token could not be obtained. Failing to reach the
+                // token endpoint is eligible for retries, other errors are
+                // permanent:
+                Err(error @ OAuthError::Transport(_)) => {
+                    tracing::warn!("{}", error);
+                    return Err(Transient { err: error.into(), retry_after: None })
+                }, // Err
+                Err(error) => {
+                    tracing::error!("{}", error);
+                    return Err(Permanent(error.into()))
+                }, // Err
+            }; // match
+
+            // Query the Google Cloud Maps Platform using using an HTTP get
+            // request through the client's transport, and return result to
+            // caller:
+            let response = self.transport
+                .execute(request)
+                .await;
+
+            // The statuses that were returned, for the client's metrics hook:
+            let mut http_status = None;
+            let mut google_status = None;
+
+            // Check response from the HTTP client:
+            let result = match response {
+                Ok(response) => {
+                    http_status = Some(response.status);
+                    // HTTP client was successful getting a response from the
+                    // server. Check the HTTP status code:
+                    if response.is_success() {
+                        // If the HTTP GET request was successful, parse the
+                        // response body:
+                        match <R::Response as ServiceResponse>::from_http_response(&response) {
+                            // If the response was successfully parsed,
+                            // check the Google API status before returning it
+                            // to the caller:
+                            Ok(deserialized) => match deserialized.into_result() {
+                                // If Google's response was "Ok" return the
+                                // struct deserialized from JSON, along with
+                                // the HTTP response so that it may be cached:
+                                Ok(deserialized) => {
+                                    google_status = Some(String::from("OK"));
+                                    // Google answered the request, so it will
+                                    // be billed. Errors and retried attempts
+                                    // are not:
+                                    reservation.commit();
+                                    Ok((deserialized, response))
+                                }, // Ok
+                                // Google API returned an error. Only statuses
+                                // that the retry policy considers transient
+                                // (such as Google's "Unknown Error") are
+                                // eligible for retries:
+                                Err((status, error)) => {
+                                    google_status = Some(status.clone());
+                                    if self.retry_policy.is_transient_status(&status) {
+                                        tracing::warn!("{}", error);
+                                        Err(Transient { err: error.into(), retry_after: None })
+                                    } else {
+                                        // Not a transient status. The error is
+                                        // permanent, do not retry:
+                                        tracing::error!("{}", error);
+                                        Err(Permanent(error.into()))
+                                    } // if
+                                }, // Err
+                            }, // Ok(deserialized)
+                            Err(error) => {
+                                tracing::error!("JSON parsing error: {}", error);
+                                Err(Permanent(<R::Response as ServiceResponse>::Error::serde_json(error).into()))
+                            }, // Err
+                        } // match
+                    // We got a response from the server but it was not OK. Some
+                    // services describe the error in the response body, use
+                    // it if possible:
+                    } else {
+                        let (status, error) = match <R::Response as ServiceResponse>::error_from_body(&response.body) {
+                            Some((status, error)) => (Some(status), error),
+                            None => (None, <R::Response as ServiceResponse>::Error::http_unsuccessful(response.status.to_string())),
+                        }; // match
+                        // Only HTTP status codes that the retry policy
+                        // considers transient (such as "503 Service
+                        // Unavailable" and "429 Too Many Requests"), or errors
+                        // with a transient status, are eligible for retries:
+                        let transient = self.retry_policy.is_transient_http_status(response.status)
+                            || status.as_deref().is_some_and(|status| self.retry_policy.is_transient_status(status));
+                        google_status = status;
+                        if transient {
+                            tracing::warn!("HTTP client returned: {}: {}", response.status, error);
+                            // Honour the server's `Retry-After` header, if any:
+                            server_delay.set(response.retry_after());
+                            Err(Transient { err: error.into(), retry_after: None })
+                        // Not a transient error. The error is permanent, do
+                        // not retry:
+                        } else {
+                            tracing::error!("HTTP client returned: {}: {}", response.status, error);
+                            Err(Permanent(error.into()))
+                        } // if
+                    } // if
+                } // case
+                // HTTP client did not get a response from the server. The
+                // error's secrets are redacted before it is logged or
+                // returned. Retry, unless the transport reports that retrying
+                // would not help:
+                Err(error) => {
+                    let error = if self.reveal_secrets { error } else { error.redacted() };
+                    if error.is_transient() {
+                        tracing::warn!("HTTP client returned: {}", error);
+                        Err(Transient { err: GoogleMapsError::Transport(error), retry_after: None })
+                    } else {
+                        tracing::error!("HTTP client returned: {}", error);
+                        Err(Permanent(GoogleMapsError::Transport(error)))
+                    } // if
+                } // case
+            }; // match
+
+            recorder.attempt(attempt_start, http_status, google_status);
+            result
+
+        }).await;
+
+        (rate_limit_wait, result)
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "geocoding"))]
+mod tests {
+
+    use crate::client::GoogleMapsClient;
+    use crate::request_rate::api::Api;
+    use crate::transport::ScriptedTransport;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    const OK: &str = r#"{ "results": [], "status": "OK" }"#;
+
+    fn client(transport: &ScriptedTransport) -> GoogleMapsClient {
+        GoogleMapsClient::new("TEST_KEY")
+            .with_transport(transport.clone())
+            .build()
+    } // fn
+
+    #[tokio::test(start_paused = true)]
+    async fn failed_request_is_not_charged() {
+        let transport = ScriptedTransport::responses([
+            (200, r#"{ "results": [], "status": "REQUEST_DENIED" }"#),
+            (403, r#"{ "results": [], "status": "REQUEST_DENIED" }"#),
+        ]);
+        let client = client(&transport);
+
+        assert!(client.geocoding().with_address("Paris").execute().await.is_err());
+        assert!(client.geocoding().with_address("Paris").execute().await.is_err());
+
+        assert_eq!(transport.requests().len(), 2);
+        assert_eq!(client.total_cost(), Decimal::ZERO);
+    } // fn
+
+    #[tokio::test(start_paused = true)]
+    async fn retried_request_is_charged_once() {
+        let transport = ScriptedTransport::responses([
+            (503, ""),
+            (429, ""),
+            (200, r#"{ "results": [], "status": "OVER_QUERY_LIMIT" }"#),
+            (200, OK),
+        ]);
+        let client = client(&transport);
+
+        client.geocoding().with_address("Paris").execute().await.unwrap();
+
+        assert_eq!(transport.requests().len(), 4);
+        assert_eq!(client.costs().get(&Api::Geocoding), Some(&dec!(0.005)));
+        assert_eq!(client.total_cost(), dec!(0.005));
+    } // fn
+
+    #[tokio::test(start_paused = true)]
+    async fn failed_request_releases_its_reservation() {
+        let transport = ScriptedTransport::responses([
+            (200, r#"{ "results": [], "status": "INVALID_REQUEST" }"#),
+            (200, OK),
+        ]);
+        let client = GoogleMapsClient::new("TEST_KEY")
+            .with_transport(transport.clone())
+            .with_budget(dec!(0.005))
+            .build();
+
+        assert!(client.geocoding().with_address("Paris").execute().await.is_err());
+        // The budget only has room for one request, which would be refused if
+        // the failed request had been charged or were still reserved:
+        client.geocoding().with_address("Paris").execute().await.unwrap();
+
+        assert_eq!(client.total_cost(), dec!(0.005));
+    } // fn
+
+} // mod
\ No newline at end of file
@@ -1,11 +1,20 @@
 use crate::client::GoogleMapsClient;
 #[cfg(feature = "enable-reqwest")]
+use crate::billing::CostLedger;
+#[cfg(feature = "enable-reqwest")]
 use crate::request_rate::RequestRate;
+#[cfg(feature = "enable-reqwest")]
+use crate::retry_policy::RetryPolicy;
+#[cfg(feature = "enable-reqwest")]
+use crate::transport::ReqwestTransport;
+#[cfg(feature = "enable-reqwest")]
+use std::sync::Arc;
+use std::collections::HashMap;
 #[cfg(feature = "directions")]
 use crate::directions::request::location::Location;
 #[cfg(feature = "distance_matrix")]
 use crate::directions::request::waypoint::Waypoint;
-#[cfg(any(feature = "geocoding", feature = "time_zone", feature = "roads"))]
+#[cfg(any(feature = "geocoding", feature = "places", feature = "time_zone", feature = "roads"))]
 use crate::types::LatLng;
 #[cfg(feature = "time_zone")]
 use chrono::{DateTime, Utc};
@@ -24,16 +33,21 @@ impl GoogleMapsClient {
 
     #[cfg(feature = "enable-reqwest")]
     pub fn new(key: &str) -> GoogleMapsClient {
-
-        let reqwest_client = reqwest::Client::builder()
-            .user_agent(format!("google-maps-rust/{version}", version=env!("CARGO_PKG_VERSION")))
-            .build()
-            .unwrap();
-
         GoogleMapsClient {
             key: key.to_string(),
+            reveal_secrets: false,
+            client_id: None,
+            signing_secret: None,
             rate_limit: RequestRate::default(),
-            reqwest_client,
+            retry_policy: RetryPolicy::default(),
+            timeout: None,
+            transport: Arc::new(ReqwestTransport::new()),
+            base_urls: HashMap::new(),
+            token_provider: None,
+            cache: None,
+            cache_ttls: HashMap::new(),
+            cost_ledger: CostLedger::default(),
+            metrics: None,
         } // GoogleMapsClient
 
     } // fn
@@ -50,6 +64,10 @@ impl GoogleMapsClient {
     pub fn new(key: &str) -> GoogleMapsClient {
         GoogleMapsClient {
             key: key.to_string(),
+            reveal_secrets: false,
+            client_id: None,
+            signing_secret: None,
+            base_urls: HashMap::new(),
         } // GoogleMapsClient
     } // fn
 
@@ -171,6 +189,29 @@ impl GoogleMapsClient {
         crate::geocoding::reverse::ReverseRequest::new(self, latlng)
     } // fn
 
+    // -------------------------------------------------------------------------
+    //
+    /// The Geolocation API returns a location and accuracy radius based on
+    /// information about cell towers and WiFi nodes that the mobile client can
+    /// detect.
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// let location = google_maps_client.geolocation()
+    ///     .with_consider_ip(false)
+    ///     .with_wifi_access_points(&wifi_access_points)
+    ///     .execute()
+    ///     .await?;
+    /// ```
+
+    #[cfg(feature = "geolocation")]
+    pub fn geolocation(&self) -> crate::geolocation::request::Request<'_> {
+        crate::geolocation::request::Request::new(self)
+    } // fn
+
     // -------------------------------------------------------------------------
     //
     /// The **Time Zone API** provides time offset data for locations on the
@@ -310,6 +351,103 @@ impl GoogleMapsClient {
         crate::places::place_search::text_search::request::Request::new(self, query)
     } // fn
 
+    // -------------------------------------------------------------------------
+    //
+    /// The Places API **Nearby Search** service lets you search for places
+    /// within a specified area. You can refine your search request by
+    /// supplying keywords or specifying the type of place you are searching
+    /// for.
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// let search_results = google_maps_client.nearby_search(
+    ///     LatLng::try_from_dec(dec!(53.540_950), dec!(-113.493_041))?,
+    ///     1_500,
+    /// )
+    /// .with_keyword("coffee")
+    /// .with_type(PlaceType::Cafe)
+    /// .execute()
+    /// .await?;
+    ///
+    /// println!("{:#?}", search_results);
+    /// ```
+
+    #[cfg(feature = "places")]
+    pub fn nearby_search(
+        &self,
+        location: LatLng,
+        radius: u32,
+    ) -> crate::places::place_search::nearby_search::request::Request<'_> {
+        crate::places::place_search::nearby_search::request::Request::new(self, location, radius)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// The Places API **Nearby Search** service lets you search for places
+    /// near a specified location. This variant lists the results in ascending
+    /// order by their distance from the location, so it has no radius. One or
+    /// more of `keyword` or `type` is required.
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// let search_results = google_maps_client.nearby_search_ranked_by_distance(
+    ///     LatLng::try_from_dec(dec!(53.540_950), dec!(-113.493_041))?,
+    /// )
+    /// .with_type(PlaceType::Cafe)
+    /// .execute()
+    /// .await?;
+    ///
+    /// println!("{:#?}", search_results);
+    /// ```
+
+    #[cfg(feature = "places")]
+    pub fn nearby_search_ranked_by_distance(
+        &self,
+        location: LatLng,
+    ) -> crate::places::place_search::nearby_search::request::Request<'_> {
+        crate::places::place_search::nearby_search::request::Request::new_ranked_by_distance(self, location)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// The Places API **Find Place** service takes a text input and returns a
+    /// place. The input can be any kind of Places text data, such as a name,
+    /// address, or phone number. When no fields are requested, only the
+    /// `place_id` of each candidate is returned, which is billed at a lower
+    /// rate.
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// let candidates = google_maps_client.find_place(
+    ///     "+61293744000".to_string(),
+    ///     InputType::PhoneNumber,
+    /// )
+    /// .execute()
+    /// .await?;
+    ///
+    /// println!("{:#?}", candidates);
+    /// ```
+
+    #[cfg(feature = "places")]
+    pub fn find_place(
+        &self,
+        input: String,
+        input_type: crate::places::place_search::find_place::request::input_type::InputType,
+    ) -> crate::places::place_search::find_place::request::Request<'_> {
+        crate::places::place_search::find_place::request::Request::new(self, input, input_type)
+    } // fn
+
     // -------------------------------------------------------------------------
     //
     /// The Places API **Place Details** service returns more details about a
@@ -338,6 +476,36 @@ impl GoogleMapsClient {
         crate::places::place_details::request::Request::new(self, place_id)
     } // fn
 
+    // -------------------------------------------------------------------------
+    //
+    /// The Places API **Place Photo** service gives you access to the photos
+    /// stored in the Places database. Photo references are returned in the
+    /// `photos` field of a Place Search or Place Details response. The image's
+    /// bytes and content type are returned.
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// let photo = google_maps_client.place_photo(
+    ///     place_photo.photo_reference.clone(),
+    /// )
+    /// .with_max_width(400)
+    /// .execute()
+    /// .await?;
+    ///
+    /// std::fs::write("photo.jpg", &photo.bytes)?;
+    /// ```
+
+    #[cfg(feature = "places")]
+    pub fn place_photo(
+        &self,
+        photo_reference: String,
+    ) -> crate::places::photo::request::Request<'_> {
+        crate::places::photo::request::Request::new(self, photo_reference)
+    } // fn
+
     // -------------------------------------------------------------------------
     //
     /// The Roads API **Snap To Roads** service takes up to 100 GPS points
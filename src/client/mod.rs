@@ -4,17 +4,73 @@
 
 // -----------------------------------------------------------------------------
 
+#[cfg(request_urls)]
+mod authenticated_url;
+#[cfg(feature = "enable-reqwest")]
+mod authorized_request;
+#[cfg(feature = "enable-reqwest")]
+mod batch;
+#[cfg(feature = "enable-reqwest")]
+mod batch_stream;
 mod build;
+mod client_ref;
+#[cfg(feature = "enable-reqwest")]
+mod costs;
+mod debug;
+#[cfg(feature = "enable-reqwest")]
+mod get_request;
 mod impls;
+mod redact;
+#[cfg(request_urls)]
+mod service_url;
+mod with_base_url;
+#[cfg(feature = "enable-reqwest")]
+mod with_budget;
+#[cfg(feature = "enable-reqwest")]
+mod with_cache;
+#[cfg(feature = "enable-reqwest")]
+mod with_fixtures;
+#[cfg(feature = "enable-reqwest")]
+mod with_metrics;
 #[cfg(feature = "enable-reqwest")]
 mod with_rate;
 #[cfg(feature = "enable-reqwest")]
+mod with_rate_store;
+#[cfg(feature = "enable-reqwest")]
 mod with_reqwest_client;
+#[cfg(feature = "enable-reqwest")]
+mod with_retry_policy;
+mod with_reveal_secrets;
+#[cfg(feature = "enable-reqwest")]
+mod with_timeout;
+#[cfg(feature = "enable-reqwest")]
+mod with_token_provider;
+#[cfg(feature = "enable-reqwest")]
+mod with_transport;
+mod with_url_signing;
 
 // -----------------------------------------------------------------------------
 
+pub use crate::client::client_ref::ClientRef;
+pub(crate) use crate::client::redact::{redact_secrets, REDACTED};
+#[cfg(feature = "enable-reqwest")]
+use crate::billing::CostLedger;
+#[cfg(feature = "enable-reqwest")]
+use crate::cache::ResponseCache;
+#[cfg(feature = "enable-reqwest")]
+use crate::metrics::Metrics;
+#[cfg(feature = "enable-reqwest")]
+use crate::oauth::TokenProvider;
+use crate::request_rate::api::Api;
 #[cfg(feature = "enable-reqwest")]
 use crate::request_rate::RequestRate;
+#[cfg(feature = "enable-reqwest")]
+use crate::retry_policy::RetryPolicy;
+#[cfg(feature = "enable-reqwest")]
+use crate::transport::Transport;
+#[cfg(feature = "enable-reqwest")]
+use std::{sync::Arc, time::Duration};
+use std::collections::HashMap;
 
 // -----------------------------------------------------------------------------
 //
@@ -36,7 +92,7 @@ use crate::request_rate::RequestRate;
 ///     .build();
 /// ```
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct GoogleMapsClient {
 
     /// Your application's API key. This key identifies your application for
@@ -45,13 +101,76 @@ pub struct GoogleMapsClient {
     /// Contains the application's API key and other settings.
     pub key: String,
 
+    /// Whether the API key and URL signing secret are shown in log messages,
+    /// errors, `Debug` output and `query_url` strings. By default, this is
+    /// `false` and they are replaced with `REDACTED`. See `with_reveal_secrets` for more
+    /// information.
+    pub reveal_secrets: bool,
+
+    /// Your client ID, for Google Maps Platform customers that authenticate
+    /// using a client ID (for example, `gme-...`) rather than an API key. When
+    /// set, the client ID is sent instead of the API key and every request is
+    /// signed. See `with_client_id` for more information.
+    pub client_id: Option<String>,
+
+    /// The decoded URL signing secret. When set, every request is signed with
+    /// a HMAC-SHA1 digital signature. See `with_signing_secret` for more
+    /// information.
+    pub signing_secret: Option<Vec<u8>>,
+
     /// Rate limits for each of the Google Cloud Maps Platform APIs.
     #[cfg(feature = "enable-reqwest")]
     pub rate_limit: RequestRate,
 
-    /// Allows you to optionally provide your own pre-configured reqwest client
-    /// that will be used by the Google Maps client.
+    /// Specifies how failed requests are retried. See `RetryPolicy` for more
+    /// information.
+    #[cfg(feature = "enable-reqwest")]
+    pub retry_policy: RetryPolicy,
+
+    /// The default deadline for each request, including rate limiter waits
+    /// and retries. Requests may override it with their own `with_timeout`
+    /// method. See `with_timeout` for more information.
+    #[cfg(feature = "enable-reqwest")]
+    pub timeout: Option<Duration>,
+
+    /// The HTTP transport that is used to send requests to the Google Maps
+    /// Platform. By default, this is a `ReqwestTransport`. It may be replaced
+    /// using the `with_transport` or `with_reqwest_client` methods.
+    #[cfg(feature = "enable-reqwest")]
+    pub transport: Arc<dyn Transport>,
+
+    /// Optional base URL overrides for each of the Google Cloud Maps Platform
+    /// APIs. This allows requests to be directed to a local mock server or a
+    /// proxy instead of Google's servers.
+    pub base_urls: HashMap<Api, String>,
+
+    /// Supplies OAuth 2.0 access tokens, which are sent to the server in an
+    /// `Authorization` header. See `with_bearer_token` and
+    /// `with_token_provider` for more information.
+    #[cfg(feature = "enable-reqwest")]
+    pub token_provider: Option<Arc<dyn TokenProvider>>,
+
+    /// Optional response cache. When set, successful responses are cached and
+    /// repeated requests are answered from the cache. See `with_cache` for
+    /// more information.
+    #[cfg(feature = "enable-reqwest")]
+    pub cache: Option<ResponseCache>,
+
+    /// The time-to-lives that were set using `with_cache_ttl`. They are kept
+    /// here so that they are applied to the response cache whether they were
+    /// set before or after `with_cache` was called.
+    #[cfg(feature = "enable-reqwest")]
+    pub cache_ttls: HashMap<Api, Duration>,
+
+    /// A running tally of the estimated cost of the client's requests, and an
+    /// optional monthly budget. See `with_budget` and `costs` for more
+    /// information.
+    #[cfg(feature = "enable-reqwest")]
+    pub cost_ledger: CostLedger,
+
+    /// Optional metrics hook. When set, the client's request metrics are
+    /// reported to it. See `with_metrics` for more information.
     #[cfg(feature = "enable-reqwest")]
-    pub reqwest_client: reqwest::Client,
+    pub metrics: Option<Arc<dyn Metrics>>,
 
 } // struct
\ No newline at end of file
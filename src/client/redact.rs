@@ -0,0 +1,123 @@
+#[cfg(request_urls)]
+use crate::client::GoogleMapsClient;
+
+// -----------------------------------------------------------------------------
+
+/// The text that replaces a secret in log messages, errors and `Debug` output.
+pub(crate) const REDACTED: &str = "REDACTED";
+
+/// The query string parameters whose values are secret. They are replaced
+/// with `REDACTED` unless the client has opted-in to revealing secrets.
+const SECRET_PARAMETERS: [&str; 2] = ["key", "signature"];
+
+// =============================================================================
+
+#[cfg(request_urls)]
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// ## Description
+    ///
+    /// Returns the URL with its secrets (such as the API key) replaced with
+    /// `REDACTED`, so that it may be safely logged or displayed. If the client
+    /// has opted-in to revealing secrets using `with_reveal_secrets`, the URL
+    /// is returned as-is.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `url` ‧ The URL or query string to redact.
+
+    pub(crate) fn redact_url(&self, url: &str) -> String {
+        if self.reveal_secrets {
+            url.to_string()
+        } else {
+            redact_secrets(url)
+        } // if
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// This function is not for public consumption. It is for internal use only.
+///
+/// ## Description
+///
+/// Returns the URL with the values of its secret query string parameters
+/// (such as `key`) replaced with `REDACTED`, regardless of the client's
+/// settings.
+///
+/// ## Arguments:
+///
+/// * `url` ‧ The URL or query string to redact.
+
+pub(crate) fn redact_secrets(url: &str) -> String {
+
+    // Separate the query string from the rest of the URL. If there is no `?`
+    // the entire string is treated as a query string:
+    let (stem, query) = match url.split_once('?') {
+        Some((stem, query)) => (Some(stem), query),
+        None => (None, url),
+    }; // match
+
+    // Replace the value of each secret key/value pair:
+    let query = query
+        .split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((name, _value)) if SECRET_PARAMETERS.contains(&name) =>
+                format!("{name}={REDACTED}"),
+            _ => pair.to_string(),
+        }) // map
+        .collect::<Vec<String>>()
+        .join("&");
+
+    match stem {
+        Some(stem) => format!("{stem}?{query}"),
+        None => query,
+    } // match
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn redact_secrets_replaces_secret_parameters() {
+        assert_eq!(
+            redact_secrets("https://maps.googleapis.com/maps/api/geocode/json?address=Paris&key=AIzaSECRET&signature=abc123="),
+            "https://maps.googleapis.com/maps/api/geocode/json?address=Paris&key=REDACTED&signature=REDACTED",
+        );
+    } // fn
+
+    #[test]
+    fn redact_secrets_accepts_a_bare_query_string() {
+        assert_eq!(redact_secrets("key=AIzaSECRET&language=fr"), "key=REDACTED&language=fr");
+    } // fn
+
+    #[test]
+    fn redact_secrets_ignores_parameters_that_merely_contain_a_secret_name() {
+        let url = "https://maps.googleapis.com/maps/api/place/textsearch/json?query=key&monkey=1&signatures=2";
+        assert_eq!(redact_secrets(url), url);
+    } // fn
+
+    #[cfg(request_urls)]
+    #[test]
+    fn redact_url_observes_reveal_secrets() {
+        let url = "https://maps.googleapis.com/maps/api/elevation/json?locations=0,0&key=AIzaSECRET";
+        let client = GoogleMapsClient::new("AIzaSECRET").build();
+        assert_eq!(
+            client.redact_url(url),
+            "https://maps.googleapis.com/maps/api/elevation/json?locations=0,0&key=REDACTED",
+        );
+        let client = GoogleMapsClient::new("AIzaSECRET").with_reveal_secrets(true).build();
+        assert_eq!(client.redact_url(url), url);
+    } // fn
+
+} // mod
\ No newline at end of file
@@ -0,0 +1,102 @@
+use crate::{client::GoogleMapsClient, request_rate::api::Api};
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// ## Description
+    ///
+    /// Returns the service URL that should be used for the specified API. If a
+    /// base URL override has been set for the API (or for its group, or for
+    /// `Api::All`) using the `with_base_url` method, the scheme and host of the
+    /// Google service URL are replaced with the override. Otherwise, the Google
+    /// service URL is returned as-is.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `api` ‧ The API that the request is being made to.
+    ///
+    /// * `service_url` ‧ The default Google service URL for the request. For
+    ///   example, `https://maps.googleapis.com/maps/api/directions`.
+
+    pub(crate) fn service_url(&self, api: &Api, service_url: &str) -> String {
+
+        // Use the most precise override that has been set for the API, its
+        // group, or `Api::All`:
+        let scopes = api.scopes();
+        let base_url = match scopes.iter().rev().find_map(|scope| self.base_urls.get(scope)) {
+            Some(base_url) => base_url,
+            // No override has been set. Use Google's service URL:
+            None => return service_url.to_string(),
+        }; // match
+
+        // Separate the path (i.e. `/maps/api/directions`) from the scheme and
+        // host (i.e. `https://maps.googleapis.com`) of the service URL:
+        let authority_start = service_url.find("://").map_or(0, |index| index + 3);
+        let path = match service_url[authority_start..].find('/') {
+            Some(index) => &service_url[authority_start + index..],
+            None => "",
+        }; // match
+
+        format!("{base_url}{path}")
+
+    } // fn
+
+} // impl
+
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const SERVICE_URL: &str = "https://maps.googleapis.com/maps/api/place/textsearch";
+
+    #[test]
+    fn service_url_defaults_to_google() {
+        let client = GoogleMapsClient::new("").build();
+        assert_eq!(client.service_url(&Api::TextSearch, SERVICE_URL), SERVICE_URL);
+    } // fn
+
+    #[test]
+    fn service_url_replaces_the_scheme_and_host() {
+        let client = GoogleMapsClient::new("")
+            .with_base_url(Api::All, "http://localhost:8080/")
+            .build();
+        assert_eq!(
+            client.service_url(&Api::TextSearch, SERVICE_URL),
+            "http://localhost:8080/maps/api/place/textsearch",
+        );
+    } // fn
+
+    #[test]
+    fn service_url_prefers_the_most_precise_override() {
+        let client = GoogleMapsClient::new("")
+            .with_base_url(Api::All, "http://all")
+            .with_base_url(Api::Places, "http://places")
+            .build();
+        assert_eq!(
+            client.service_url(&Api::TextSearch, SERVICE_URL),
+            "http://places/maps/api/place/textsearch",
+        );
+        assert_eq!(
+            client.service_url(&Api::Geocoding, "https://maps.googleapis.com/maps/api/geocode"),
+            "http://all/maps/api/geocode",
+        );
+        let client = GoogleMapsClient::new("")
+            .with_base_url(Api::Places, "http://places")
+            .with_base_url(Api::TextSearch, "http://text-search")
+            .build();
+        assert_eq!(
+            client.service_url(&Api::TextSearch, SERVICE_URL),
+            "http://text-search/maps/api/place/textsearch",
+        );
+    } // fn
+
+} // mod
\ No newline at end of file
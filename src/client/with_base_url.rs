@@ -0,0 +1,48 @@
+use crate::{client::GoogleMapsClient, request_rate::api::Api};
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Overrides the base URL (the scheme, host and optional port) used to
+    /// reach the specified API. The path of each service, for example
+    /// `/maps/api/directions/json`, is preserved and appended to the base URL.
+    ///
+    /// This is useful for pointing the client at a local mock server in
+    /// integration tests, or at a corporate egress proxy.
+    ///
+    /// ## Arguments
+    ///
+    /// * `api` ‧ Which Google Maps API are you setting the base URL for? For
+    ///   example, `Api::Directions`, `Api::Places`, `Api::Roads`, and so on. A
+    ///   base URL set for a group, such as `Api::Places`, is used by every API
+    ///   in the group that does not have its own base URL. A base URL set for
+    ///   `Api::All` is used by every API that does not have its own or its
+    ///   group's base URL.
+    ///
+    /// * `base_url` ‧ The base URL to use instead of Google's. For example,
+    ///   `http://localhost:8080`.
+    ///
+    /// ## Examples:
+    ///
+    /// * Sends all requests to a local mock server except for Roads API
+    ///   requests, which are sent to a different mock server:
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_base_url(Api::All, "http://localhost:8080")
+    ///     .with_base_url(Api::Roads, "http://localhost:8081")
+    ///     .build();
+    /// ```
+
+    pub fn with_base_url(
+        &mut self,
+        api: Api,
+        base_url: &str,
+    ) -> &mut GoogleMapsClient {
+        self.base_urls.insert(api, base_url.trim_end_matches('/').to_string());
+        self
+    } // fn
+
+} // impl
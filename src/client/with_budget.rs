@@ -0,0 +1,43 @@
+use crate::client::GoogleMapsClient;
+use rust_decimal::Decimal;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets a monthly budget for the client. Before each request is sent, its
+    /// estimated cost is added to the month's tally. A request that would take
+    /// the month's spending over the budget is not sent, and a
+    /// `billing::Error::BudgetExceeded` error is returned instead.
+    ///
+    /// Requests answered from the response cache aren't charged. Retries of a
+    /// request aren't charged again.
+    ///
+    /// **The tally is an estimate and is kept in memory, so it starts again
+    /// from zero when the program is restarted. To accurately control billing
+    /// charges by Google, please use the [Google Cloud Platform
+    /// Console](https://console.cloud.google.com/) _IAM & admin_ to set quotas
+    /// for each API on the server's side.**
+    ///
+    /// ## Arguments
+    ///
+    /// * `budget` ‧ The most that may be spent each calendar month (UTC), in
+    ///   US dollars.
+    ///
+    /// ## Examples:
+    ///
+    /// * Stops sending requests once $200.00 USD has been spent this month:
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_budget(dec!(200.00))
+    ///     .build();
+    /// ```
+
+    pub fn with_budget(&mut self, budget: Decimal) -> &mut GoogleMapsClient {
+        self.cost_ledger.budget = Some(budget);
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
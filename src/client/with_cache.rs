@@ -0,0 +1,234 @@
+use crate::cache::{CacheBackend, ResponseCache};
+use crate::client::GoogleMapsClient;
+use crate::request_rate::api::Api;
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Places a response cache in front of the client. Successful responses
+    /// are cached using the request's URL (without the API key, client ID or
+    /// signature) as the key, and repeated requests are answered from the
+    /// cache without calling Google. Cache hits and misses are reported using
+    /// `tracing` at the `debug` level.
+    ///
+    /// ## Arguments
+    ///
+    /// * `backend` ‧ Where cached responses are stored. For example,
+    ///   `MemoryCache::new(10_000)`, or any type that implements the
+    ///   `google_maps::cache::CacheBackend` trait.
+    ///
+    /// * `ttl` ‧ How long responses from every API are cached for. Use
+    ///   `with_cache_ttl` to set a different time-to-live for an API, either
+    ///   before or after this method is called.
+    ///
+    /// ## Examples:
+    ///
+    /// * Caches geocoding results for 30 days, time zones for a day, and
+    ///   doesn't cache directions at all:
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_cache(MemoryCache::new(10_000), Duration::from_secs(86_400))
+    ///     .with_cache_ttl(Api::Geocoding, Duration::from_secs(30 * 86_400))
+    ///     .with_cache_ttl(Api::Directions, Duration::ZERO)
+    ///     .build();
+    /// ```
+
+    pub fn with_cache(
+        &mut self,
+        backend: impl CacheBackend + 'static,
+        ttl: Duration,
+    ) -> &mut GoogleMapsClient {
+        let mut ttls = HashMap::from([(Api::All, ttl)]);
+        ttls.extend(self.cache_ttls.clone());
+        self.cache = Some(ResponseCache {
+            backend: Arc::new(backend),
+            ttls,
+        }); // ResponseCache
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets how long responses from the specified API are cached for. This
+    /// overrides the time-to-live that was passed to `with_cache`. A
+    /// time-to-live of zero disables caching for the API. It may be called
+    /// before or after the cache is set using `with_cache`: the time-to-live
+    /// is kept by the client and applied to any cache that it uses.
+    ///
+    /// ## Arguments
+    ///
+    /// * `api` ‧ Which Google Maps API are you setting the time-to-live for?
+    ///   For example, `Api::Geocoding`, `Api::TimeZone`, and so on.
+    ///
+    /// * `ttl` ‧ How long responses from the API are cached for.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_cache(MemoryCache::new(10_000), Duration::from_secs(86_400))
+    ///     .with_cache_ttl(Api::Geocoding, Duration::from_secs(30 * 86_400))
+    ///     .build();
+    /// ```
+
+    pub fn with_cache_ttl(
+        &mut self,
+        api: Api,
+        ttl: Duration,
+    ) -> &mut GoogleMapsClient {
+        self.cache_ttls.insert(api.clone(), ttl);
+        if let Some(cache) = &mut self.cache {
+            cache.ttls.insert(api, ttl);
+        } // if
+        self
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "geocoding"))]
+mod tests {
+
+    use crate::cache::MemoryCache;
+    use crate::client::GoogleMapsClient;
+    use crate::request_rate::api::Api;
+    use crate::transport::ScriptedTransport;
+    use std::time::Duration;
+
+    const PARIS: &str = r#"{
+        "results": [ {
+            "address_components": [],
+            "formatted_address": "Paris, France",
+            "geometry": {
+                "location": { "lat": 48.8566, "lng": 2.3522 },
+                "location_type": "APPROXIMATE",
+                "viewport": {
+                    "northeast": { "lat": 48.9, "lng": 2.4 },
+                    "southwest": { "lat": 48.8, "lng": 2.2 }
+                }
+            },
+            "place_id": "ChIJD7fiBh9u5kcRYJSMaMOCCwQ",
+            "types": [ "locality", "political" ]
+        } ],
+        "status": "OK"
+    }"#;
+
+    const MINUTE: Duration = Duration::from_secs(60);
+
+    /// Geocodes Paris twice, and returns the number of requests that were sent
+    /// to the transport.
+    async fn requests_sent(client: impl FnOnce(ScriptedTransport) -> GoogleMapsClient) -> usize {
+        let transport = ScriptedTransport::responses([(200, PARIS), (200, PARIS)]);
+        let client = client(transport.clone());
+        let first = client.geocoding().with_address("Paris").execute().await.unwrap();
+        let second = client.geocoding().with_address("Paris").execute().await.unwrap();
+        assert_eq!(first, second);
+        transport.requests().len()
+    } // fn
+
+    #[tokio::test]
+    async fn repeated_request_is_a_hit() {
+        let requests = requests_sent(|transport| GoogleMapsClient::new("TEST_KEY")
+            .with_transport(transport)
+            .with_cache(MemoryCache::new(10), MINUTE)
+            .build()).await;
+        assert_eq!(requests, 1);
+    } // fn
+
+    #[tokio::test]
+    async fn different_request_is_a_miss() {
+        let transport = ScriptedTransport::responses([(200, PARIS), (200, PARIS)]);
+        let cache = MemoryCache::new(10);
+        let client = GoogleMapsClient::new("TEST_KEY")
+            .with_transport(transport.clone())
+            .with_cache(cache.clone(), MINUTE)
+            .build();
+        client.geocoding().with_address("Paris").execute().await.unwrap();
+        client.geocoding().with_address("Paris, France").execute().await.unwrap();
+        assert_eq!(transport.requests().len(), 2);
+        assert_eq!(cache.len(), 2);
+    } // fn
+
+    #[tokio::test]
+    async fn credentials_are_not_part_of_the_key() {
+        let transport = ScriptedTransport::responses([(200, PARIS)]);
+        let cache = MemoryCache::new(10);
+        let client = GoogleMapsClient::new("TEST_KEY")
+            .with_transport(transport.clone())
+            .with_cache(cache.clone(), MINUTE)
+            .build();
+        client.geocoding().with_address("Paris").execute().await.unwrap();
+        let other_key = GoogleMapsClient::new("OTHER_KEY")
+            .with_transport(transport.clone())
+            .with_cache(cache, MINUTE)
+            .build();
+        other_key.geocoding().with_address("Paris").execute().await.unwrap();
+        assert_eq!(transport.requests().len(), 1);
+    } // fn
+
+    #[tokio::test]
+    async fn expired_response_is_a_miss() {
+        let transport = ScriptedTransport::responses([(200, PARIS), (200, PARIS)]);
+        let client = GoogleMapsClient::new("TEST_KEY")
+            .with_transport(transport.clone())
+            .with_cache(MemoryCache::new(10), Duration::from_millis(50))
+            .build();
+        client.geocoding().with_address("Paris").execute().await.unwrap();
+        client.geocoding().with_address("Paris").execute().await.unwrap();
+        assert_eq!(transport.requests().len(), 1);
+        // The memory cache uses the system clock, which can't be paused:
+        std::thread::sleep(Duration::from_millis(100));
+        client.geocoding().with_address("Paris").execute().await.unwrap();
+        assert_eq!(transport.requests().len(), 2);
+    } // fn
+
+    #[tokio::test]
+    async fn zero_ttl_disables_the_cache() {
+        let requests = requests_sent(|transport| GoogleMapsClient::new("TEST_KEY")
+            .with_transport(transport)
+            .with_cache(MemoryCache::new(10), Duration::ZERO)
+            .build()).await;
+        assert_eq!(requests, 2);
+    } // fn
+
+    #[tokio::test]
+    async fn zero_api_ttl_disables_the_cache_for_the_api() {
+        let requests = requests_sent(|transport| GoogleMapsClient::new("TEST_KEY")
+            .with_transport(transport)
+            .with_cache(MemoryCache::new(10), MINUTE)
+            .with_cache_ttl(Api::Geocoding, Duration::ZERO)
+            .build()).await;
+        assert_eq!(requests, 2);
+    } // fn
+
+    #[tokio::test]
+    async fn api_ttl_overrides_the_default() {
+        let requests = requests_sent(|transport| GoogleMapsClient::new("TEST_KEY")
+            .with_transport(transport)
+            .with_cache(MemoryCache::new(10), Duration::ZERO)
+            .with_cache_ttl(Api::Geocoding, MINUTE)
+            .build()).await;
+        assert_eq!(requests, 1);
+    } // fn
+
+    #[tokio::test]
+    async fn api_ttl_may_be_set_before_the_cache() {
+        let requests = requests_sent(|transport| GoogleMapsClient::new("TEST_KEY")
+            .with_transport(transport)
+            .with_cache_ttl(Api::Geocoding, Duration::ZERO)
+            .with_cache(MemoryCache::new(10), MINUTE)
+            .build()).await;
+        assert_eq!(requests, 2);
+        let client = GoogleMapsClient::new("TEST_KEY")
+            .with_cache_ttl(Api::Geocoding, MINUTE)
+            .with_cache(MemoryCache::new(10), Duration::ZERO)
+            .build();
+        assert_eq!(client.cache.unwrap().ttl(&Api::Geocoding), Some(MINUTE));
+    } // fn
+
+} // mod
\ No newline at end of file
@@ -0,0 +1,72 @@
+use crate::client::GoogleMapsClient;
+use crate::transport::{RecordingTransport, ReplayTransport};
+use std::{path::PathBuf, sync::Arc};
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Records every request and the server's raw response to a fixture
+    /// directory, so that they may later be replayed using `with_replay`.
+    /// Requests are still sent through the client's current transport, so this
+    /// method should be called after `with_transport` or `with_reqwest_client`,
+    /// if they are used.
+    ///
+    /// Secrets (such as the API key and signature) are replaced with `REDACTED`
+    /// in the recorded URLs, and request headers (such as `Authorization`) are
+    /// not recorded, so fixtures may be committed to source control.
+    ///
+    /// ## Arguments
+    ///
+    /// * `directory` ‧ The directory that fixtures are written to. It is
+    ///   created if it does not exist.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_recording("tests/fixtures/geocoding")
+    ///     .build();
+    /// ```
+
+    pub fn with_recording(
+        &mut self,
+        directory: impl Into<PathBuf>,
+    ) -> &mut GoogleMapsClient {
+        self.transport = Arc::new(RecordingTransport::new(self.transport.clone(), directory));
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Serves responses from a fixture directory that was recorded using
+    /// `with_recording`, instead of going to the network. A request that has no
+    /// recorded fixture fails immediately with a `transport::Error::Fixture`
+    /// error and is not retried.
+    ///
+    /// Requests are matched on their method, URL and body, with secrets (such
+    /// as the API key) ignored, so tests may be run without a real API key.
+    ///
+    /// ## Arguments
+    ///
+    /// * `directory` ‧ The directory that fixtures are read from.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("TEST_KEY")
+    ///     .with_replay("tests/fixtures/geocoding")
+    ///     .build();
+    /// ```
+
+    pub fn with_replay(
+        &mut self,
+        directory: impl Into<PathBuf>,
+    ) -> &mut GoogleMapsClient {
+        self.transport = Arc::new(ReplayTransport::new(directory));
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
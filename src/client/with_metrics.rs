@@ -0,0 +1,191 @@
+use crate::client::GoogleMapsClient;
+use crate::metrics::Metrics;
+use std::sync::Arc;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets a metrics hook for the client. The hook is told about each HTTP
+    /// request to Google (including retries) and about each completed
+    /// request: which API it was for, how long it took, how many times it was
+    /// retried, how long it waited under the rate limiter, and which HTTP and
+    /// Google statuses were returned.
+    ///
+    /// ## Arguments
+    ///
+    /// * `metrics` ‧ The metrics hook. For example, `MetricsFacade` (with the
+    ///   `metrics` feature), or any type that implements the
+    ///   `google_maps::metrics::Metrics` trait.
+    ///
+    /// ## Examples:
+    ///
+    /// * Records request metrics using the `metrics` crate:
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_metrics(MetricsFacade)
+    ///     .build();
+    /// ```
+
+    pub fn with_metrics(
+        &mut self,
+        metrics: impl Metrics + 'static,
+    ) -> &mut GoogleMapsClient {
+        self.metrics = Some(Arc::new(metrics));
+        self
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "geocoding"))]
+mod tests {
+
+    use crate::cache::MemoryCache;
+    use crate::client::GoogleMapsClient;
+    use crate::metrics::{AttemptMetrics, Metrics, RequestMetrics};
+    use crate::request_rate::api::Api;
+    use crate::retry_policy::RetryPolicy;
+    use crate::transport::ScriptedTransport;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    const OK: &str = r#"{ "results": [], "status": "OK" }"#;
+
+    /// A metrics hook that remembers everything that it was told.
+    #[derive(Clone, Debug, Default)]
+    struct RecordingMetrics {
+        attempts: Arc<Mutex<Vec<AttemptMetrics>>>,
+        requests: Arc<Mutex<Vec<RequestMetrics>>>,
+    } // struct
+
+    impl Metrics for RecordingMetrics {
+        fn record_attempt(&self, attempt: &AttemptMetrics) {
+            self.attempts.lock().unwrap().push(attempt.clone());
+        } // fn
+        fn record_request(&self, request: &RequestMetrics) {
+            self.requests.lock().unwrap().push(request.clone());
+        } // fn
+    } // impl
+
+    impl RecordingMetrics {
+        /// Returns the `(api, attempt, http_status, google_status)` of each
+        /// recorded attempt.
+        fn attempts(&self) -> Vec<(Api, u32, Option<u16>, Option<String>)> {
+            self.attempts
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|attempt| (
+                    attempt.api.clone(),
+                    attempt.attempt,
+                    attempt.http_status,
+                    attempt.google_status.clone(),
+                )) // map
+                .collect()
+        } // fn
+        fn requests(&self) -> Vec<RequestMetrics> {
+            self.requests.lock().unwrap().clone()
+        } // fn
+    } // impl
+
+    fn client(transport: &ScriptedTransport, metrics: &RecordingMetrics) -> GoogleMapsClient {
+        GoogleMapsClient::new("TEST_KEY")
+            .with_transport(transport.clone())
+            .with_metrics(metrics.clone())
+            .build()
+    } // fn
+
+    #[tokio::test(start_paused = true)]
+    async fn each_attempt_is_recorded() {
+        let transport = ScriptedTransport::responses([
+            (503, ""),
+            (200, r#"{ "results": [], "status": "OVER_QUERY_LIMIT" }"#),
+            (200, OK),
+        ]);
+        let metrics = RecordingMetrics::default();
+        let client = client(&transport, &metrics);
+
+        client.geocoding().with_address("Paris").execute().await.unwrap();
+
+        assert_eq!(metrics.attempts(), vec![
+            (Api::Geocoding, 1, Some(503), None),
+            (Api::Geocoding, 2, Some(200), Some(String::from("OVER_QUERY_LIMIT"))),
+            (Api::Geocoding, 3, Some(200), Some(String::from("OK"))),
+        ]); // assert_eq!
+        let requests = metrics.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].api, Api::Geocoding);
+        assert_eq!(requests[0].retries, 2);
+        assert!(requests[0].success);
+        assert!(!requests[0].cache_hit);
+        assert_eq!(requests[0].http_status, Some(200));
+        assert_eq!(requests[0].google_status.as_deref(), Some("OK"));
+    } // fn
+
+    #[tokio::test(start_paused = true)]
+    async fn failed_request_is_recorded() {
+        let transport = ScriptedTransport::responses([
+            (200, r#"{ "results": [], "status": "REQUEST_DENIED" }"#),
+        ]);
+        let metrics = RecordingMetrics::default();
+        let client = client(&transport, &metrics);
+
+        assert!(client.geocoding().with_address("Paris").execute().await.is_err());
+
+        assert_eq!(metrics.attempts(), vec![
+            (Api::Geocoding, 1, Some(200), Some(String::from("REQUEST_DENIED"))),
+        ]); // assert_eq!
+        let requests = metrics.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].retries, 0);
+        assert!(!requests[0].success);
+        assert_eq!(requests[0].google_status.as_deref(), Some("REQUEST_DENIED"));
+    } // fn
+
+    #[tokio::test(start_paused = true)]
+    async fn request_that_runs_out_of_retries_is_recorded() {
+        let transport = ScriptedTransport::responses([(503, ""), (503, "")]);
+        let metrics = RecordingMetrics::default();
+        let client = GoogleMapsClient::new("TEST_KEY")
+            .with_transport(transport.clone())
+            .with_metrics(metrics.clone())
+            .with_retry_policy(RetryPolicy { max_retries: Some(1), ..RetryPolicy::default() })
+            .build();
+
+        assert!(client.geocoding().with_address("Paris").execute().await.is_err());
+
+        assert_eq!(metrics.attempts().len(), 2);
+        let requests = metrics.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].retries, 1);
+        assert!(!requests[0].success);
+        assert_eq!(requests[0].http_status, Some(503));
+    } // fn
+
+    #[tokio::test(start_paused = true)]
+    async fn cache_hit_is_recorded_without_an_attempt() {
+        let transport = ScriptedTransport::responses([(200, OK)]);
+        let metrics = RecordingMetrics::default();
+        let client = GoogleMapsClient::new("TEST_KEY")
+            .with_transport(transport.clone())
+            .with_metrics(metrics.clone())
+            .with_cache(MemoryCache::new(10), Duration::from_secs(60))
+            .build();
+
+        client.geocoding().with_address("Paris").execute().await.unwrap();
+        client.geocoding().with_address("Paris").execute().await.unwrap();
+
+        assert_eq!(metrics.attempts().len(), 1);
+        let requests = metrics.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(!requests[0].cache_hit);
+        assert!(requests[1].cache_hit);
+        assert!(requests[1].success);
+        assert_eq!(requests[1].retries, 0);
+    } // fn
+
+} // mod
\ No newline at end of file
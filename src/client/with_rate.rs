@@ -12,10 +12,12 @@ impl GoogleMapsClient {
     /// ## Arguments
     ///
     /// * `api` ‧ Which Google Maps API are you setting the rate limit for? For
-    /// example, `Api::Directions`, `Api::DistanceMatrix`, `Api::Elevation`,
-    /// `Api::Geocoding`, `Api::TimeZone`, and so on. The `Api::All` rate limit
-    /// is applied to all Google Maps API requests _in addition_ to the per-API
-    /// rate limits.
+    ///   example, `Api::Directions`, `Api::DistanceMatrix`, `Api::Elevation`,
+    ///   `Api::Geocoding`, `Api::PlaceDetails`, `Api::TimeZone`, and so on. The
+    ///   `Api::All` rate limit is applied to all Google Maps API requests _in
+    ///   addition_ to the per-API rate limits. Likewise, group rate limits such
+    ///   as `Api::Places` and `Api::Roads` are applied to every end-point in
+    ///   the group, such as `Api::TextSearch` or `Api::SnapToRoads`.
     ///
     /// * `requests` ‧ The number of requests the client library is attempting
     /// to target. For example, _2 requests_ per 1 hour.
@@ -24,6 +26,11 @@ impl GoogleMapsClient {
     /// 1 request _per 1 minute_. This can be defined using the
     /// `std::time::Duration` methods.
     ///
+    /// Each duration is a separate rate limit, so an API may have both a
+    /// per-second and a per-day rate limit, for example. Setting a rate limit
+    /// for a duration that already has one replaces it. A rate limit of `0`
+    /// requests removes it.
+    ///
     /// ## Examples:
     ///
     /// The following examples show how one might try to limit the request
@@ -33,9 +40,10 @@ impl GoogleMapsClient {
     /// the current [Google Maps Platform billing
     /// rates](https://developers.google.com/maps/billing/gmp-billing).**
     ///
-    /// **This Google client library's rate limiting is not persistent. If your
-    /// program is often restarted, it is easily possible to exceed Google's
-    /// monthly free credit. These are approximations and examples.**
+    /// **By default, this Google client library's rate limiting is not
+    /// persistent. If your program is often restarted, it is easily possible to
+    /// exceed Google's monthly free credit unless a rate store is set using
+    /// `with_rate_store`. These are approximations and examples.**
     ///
     /// **To accurately minimize billing charges by Google, please use the
     /// [Google Cloud Platform Console](https://console.cloud.google.com/)
@@ -68,26 +76,23 @@ impl GoogleMapsClient {
     /// ```
     ///
     /// * [Distance Matrix](https://developers.google.com/maps/billing/gmp-billing#distance-matrix)
-    /// API. You are billed for this SKU when your requests _do not_ require
-    /// live traffic information, $0.005 per _element_. **The below rate assumes
-    /// an average of 10 elements per request.**
+    ///   API. You are billed for this SKU when your requests _do not_ require
+    ///   live traffic information, $0.005 per _element_.
     /// ```rust
-    /// with_rate(
+    /// with_weighted_rate(
     ///     Api::DistanceMatrix,
-    ///     (GOOGLE_CREDIT / (0.005 * 10.0)) as u16,
+    ///     (GOOGLE_CREDIT / 0.005) as u32,
     ///     Duration::from_secs(SECONDS_PER_MONTH)
     /// )
     /// ```
     ///
-    /// * [Distance Matrix
-    /// Advanced](https://developers.google.com/maps/billing/gmp-billing#distance-matrix-advanced)
-    /// API. You are billed for this SKU when your requests require live traffic
-    /// information, $0.01 USD per _element_. **The below rate assumes an average
-    /// of 10 elements per request.**
+    /// * [Distance Matrix Advanced](https://developers.google.com/maps/billing/gmp-billing#distance-matrix-advanced)
+    ///   API. You are billed for this SKU when your requests require live
+    ///   traffic information, $0.01 USD per _element_.
     /// ```rust
-    /// with_rate(
+    /// with_weighted_rate(
     ///     Api::DistanceMatrix,
-    ///     (GOOGLE_CREDIT / (0.01 * 10.0)) as u16,
+    ///     (GOOGLE_CREDIT / 0.01) as u32,
     ///     Duration::from_secs(SECONDS_PER_MONTH)
     /// )
     /// ```
@@ -121,4 +126,55 @@ impl GoogleMapsClient {
         self
     } // fn
 
+    // -------------------------------------------------------------------------
+    //
+    /// Sets a weighted rate limit for the specified API. Rather than one
+    /// request, each request consumes its weight from a weighted rate limit:
+    ///
+    /// * Distance Matrix API requests weigh one unit per _element_ (origins ×
+    ///   destinations).
+    ///
+    /// * Elevation API requests weigh one unit per location or sample.
+    ///
+    /// * Roads API requests weigh one unit per point.
+    ///
+    /// * Requests to other APIs weigh one unit.
+    ///
+    /// This matches how Google counts quota and billing for these APIs, so
+    /// that a 25×25 matrix is limited 625 times as much as a 1×1 matrix.
+    /// Weighted and unweighted rate limits may be combined.
+    ///
+    /// ## Arguments
+    ///
+    /// * `api` ‧ Which Google Maps API are you setting the rate limit for?
+    ///
+    /// * `units` ‧ The number of units the client library is attempting to
+    ///   target. For example, _1,000 elements_ per 1 minute. A rate limit of
+    ///   `0` units removes it.
+    ///
+    /// * `per_duration` ‧ The duration for the targeted rate. Each duration is
+    ///   a separate rate limit.
+    ///
+    /// ## Examples:
+    ///
+    /// * Limits Distance Matrix API requests to 1,000 elements per second and
+    ///   100,000 elements per day, and to 10 requests per second:
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_weighted_rate(Api::DistanceMatrix, 1_000, Duration::from_secs(1))
+    ///     .with_weighted_rate(Api::DistanceMatrix, 100_000, Duration::from_secs(86_400))
+    ///     .with_rate(Api::DistanceMatrix, 10, Duration::from_secs(1))
+    ///     .build();
+    /// ```
+
+    pub fn with_weighted_rate(
+        &mut self,
+        api: Api,
+        units: u32,
+        per_duration: Duration,
+    ) -> &mut GoogleMapsClient {
+        self.rate_limit.with_weighted_rate(api, units, per_duration);
+        self
+    } // fn
+
 } // impl
\ No newline at end of file
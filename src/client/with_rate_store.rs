@@ -0,0 +1,46 @@
+use crate::client::GoogleMapsClient;
+use crate::rate_store::RateStore;
+use std::sync::Arc;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Keeps the rate limiter state in a persistent store instead of in
+    /// memory. This lets several processes (or several instances of the same
+    /// program) share one set of rate limits, and keeps the rate limits in
+    /// effect across restarts.
+    ///
+    /// Each rate limit's state is stored under the API's name and the rate
+    /// limit's duration, for example `Geocoding:requests:1000ms`. If the store
+    /// fails, the error is logged and the in-memory rate limiter is used for
+    /// that request.
+    ///
+    /// ## Arguments
+    ///
+    /// * `store` ‧ Where the rate limiter state is kept. For example,
+    ///   `FileRateStore::new("/var/tmp/google_maps_rates.json")`, or any type
+    ///   that implements the `google_maps::rate_store::RateStore` trait.
+    ///
+    /// ## Examples:
+    ///
+    /// * Shares a limit of 10 requests per second between every process that
+    ///   uses the same state file:
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_rate(Api::All, 10, std::time::Duration::from_secs(1))
+    ///     .with_rate_store(FileRateStore::new("/var/tmp/google_maps_rates.json"))
+    ///     .build();
+    /// ```
+
+    pub fn with_rate_store(
+        &mut self,
+        store: impl RateStore + 'static,
+    ) -> &mut GoogleMapsClient {
+        self.rate_limit.store = Some(Arc::new(store));
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -1,4 +1,6 @@
 use crate::client::GoogleMapsClient;
+use crate::transport::ReqwestTransport;
+use std::sync::Arc;
 
 // =============================================================================
 
@@ -13,6 +15,9 @@ impl GoogleMapsClient {
     /// [Mause](https://github.com/Mause) mentioned that this feature could be
     /// useful for writing tests. Thanks for the suggestion!
     ///
+    /// This replaces any transport that was previously set with
+    /// `with_transport`.
+    ///
     /// ## Arguments
     ///
     /// * `reqwest_client` ‧ A reqwest client built using the
@@ -34,7 +39,7 @@ impl GoogleMapsClient {
         &mut self,
         reqwest_client: reqwest::Client,
     ) -> &mut GoogleMapsClient {
-        self.reqwest_client = reqwest_client;
+        self.transport = Arc::new(ReqwestTransport::from(reqwest_client));
         self
     } // fn
 
@@ -0,0 +1,90 @@
+use crate::client::GoogleMapsClient;
+use crate::retry_policy::RetryPolicy;
+use std::time::Duration;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the retry policy that is used for all requests made with this
+    /// client. The retry policy controls how many times, for how long, and for
+    /// which errors a failed request is retried.
+    ///
+    /// ## Arguments
+    ///
+    /// * `retry_policy` ‧ The retry policy. See `RetryPolicy` for more
+    ///   information.
+    ///
+    /// ## Examples:
+    ///
+    /// * Disables retries entirely for latency-sensitive code. A separate
+    ///   client (which may be cloned from the original) can be used for these
+    ///   requests:
+    /// ```rust
+    /// let mut latency_sensitive_client = google_maps_client.clone()
+    ///     .with_retry_policy(RetryPolicy::disabled())
+    ///     .build();
+    /// ```
+
+    pub fn with_retry_policy(
+        &mut self,
+        retry_policy: RetryPolicy,
+    ) -> &mut GoogleMapsClient {
+        self.retry_policy = retry_policy;
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the maximum number of times a failed request will be retried. `0`
+    /// disables retries.
+    ///
+    /// ## Arguments
+    ///
+    /// * `max_retries` ‧ The maximum number of retries after the initial
+    ///   attempt.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_max_retries(10)
+    ///     .build();
+    /// ```
+
+    pub fn with_max_retries(
+        &mut self,
+        max_retries: u32,
+    ) -> &mut GoogleMapsClient {
+        self.retry_policy.max_retries = Some(max_retries);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the maximum delay between two retries. The delay between retries
+    /// grows exponentially until it reaches this value.
+    ///
+    /// ## Arguments
+    ///
+    /// * `max_delay` ‧ The maximum delay between retries.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_max_delay(std::time::Duration::from_secs(32))
+    ///     .build();
+    /// ```
+
+    pub fn with_max_delay(
+        &mut self,
+        max_delay: Duration,
+    ) -> &mut GoogleMapsClient {
+        self.retry_policy.max_interval = max_delay;
+        self
+    } // fn
+
+} // impl
@@ -0,0 +1,40 @@
+use crate::client::GoogleMapsClient;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets whether the API key, URL signing secret and request signatures are
+    /// revealed in log messages, errors, `Debug` output and `query_url`
+    /// strings.
+    ///
+    /// By default, these secrets are replaced with `REDACTED` everywhere the
+    /// client logs, formats errors or prints `Debug` output, so that they are
+    /// not leaked to log aggregators or crash reporters. Opting-in to revealing
+    /// secrets may be useful for local debugging, or if you are passing the
+    /// output of `query_url` to your own HTTP client.
+    ///
+    /// ## Arguments
+    ///
+    /// * `reveal_secrets` ‧ Set to `true` to reveal secrets. Defaults to
+    ///   `false`.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_reveal_secrets(true)
+    ///     .build();
+    /// ```
+
+    pub fn with_reveal_secrets(
+        &mut self,
+        reveal_secrets: bool,
+    ) -> &mut GoogleMapsClient {
+        self.reveal_secrets = reveal_secrets;
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,117 @@
+use crate::client::GoogleMapsClient;
+use std::time::Duration;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the default deadline for each request made with this client. The
+    /// deadline bounds the total time spent on a request, including any time
+    /// spent waiting on the client's rate limits and all retries. A request
+    /// that does not complete in time returns `Error::Timeout`.
+    ///
+    /// By default, requests have no deadline. A request's own `with_timeout`
+    /// method overrides the client's default.
+    ///
+    /// Requests may also be cancelled at any time by dropping their future.
+    ///
+    /// ## Arguments
+    ///
+    /// * `timeout` ‧ The maximum time that a request may take.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_timeout(std::time::Duration::from_secs(30))
+    ///     .build();
+    /// ```
+
+    pub fn with_timeout(
+        &mut self,
+        timeout: Duration,
+    ) -> &mut GoogleMapsClient {
+        self.timeout = Some(timeout);
+        self
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "geocoding"))]
+mod tests {
+
+    use crate::client::GoogleMapsClient;
+    use crate::error::Error as GoogleMapsError;
+    use crate::transport::ScriptedTransport;
+    use rust_decimal::Decimal;
+    use std::time::Duration;
+    use tokio::time::Instant;
+
+    #[tokio::test(start_paused = true)]
+    async fn request_that_never_completes_times_out() {
+        // The script is empty, so the request never completes:
+        let transport = ScriptedTransport::default();
+        let client = GoogleMapsClient::new("TEST_KEY")
+            .with_transport(transport.clone())
+            .with_timeout(Duration::from_secs(5))
+            .build();
+
+        let start = Instant::now();
+        let error = client.geocoding().with_address("Paris").execute().await.unwrap_err();
+
+        assert!(
+            matches!(error, GoogleMapsError::Timeout(timeout) if timeout == Duration::from_secs(5)),
+            "unexpected error: {error:?}",
+        );
+        assert_eq!(start.elapsed(), Duration::from_secs(5));
+        assert_eq!(transport.requests().len(), 1);
+        // The abandoned request is not charged:
+        assert_eq!(client.total_cost(), Decimal::ZERO);
+    } // fn
+
+    #[tokio::test(start_paused = true)]
+    async fn request_timeout_overrides_the_client_timeout() {
+        let client = GoogleMapsClient::new("TEST_KEY")
+            .with_transport(ScriptedTransport::default())
+            .with_timeout(Duration::from_secs(30))
+            .build();
+
+        let start = Instant::now();
+        let error = client
+            .geocoding()
+            .with_address("Paris")
+            .with_timeout(Duration::from_secs(2))
+            .execute()
+            .await
+            .unwrap_err();
+
+        assert!(
+            matches!(error, GoogleMapsError::Timeout(timeout) if timeout == Duration::from_secs(2)),
+            "unexpected error: {error:?}",
+        );
+        assert_eq!(start.elapsed(), Duration::from_secs(2));
+    } // fn
+
+    #[tokio::test(start_paused = true)]
+    async fn timeout_bounds_retries() {
+        // Each attempt fails quickly, but the retries would go on for longer
+        // than the deadline:
+        let transport = ScriptedTransport::responses([(503, ""); 100]);
+        let client = GoogleMapsClient::new("TEST_KEY")
+            .with_transport(transport.clone())
+            .with_timeout(Duration::from_secs(3))
+            .build();
+
+        let start = Instant::now();
+        let error = client.geocoding().with_address("Paris").execute().await.unwrap_err();
+
+        assert!(matches!(error, GoogleMapsError::Timeout(_)), "unexpected error: {error:?}");
+        assert_eq!(start.elapsed(), Duration::from_secs(3));
+        assert!(transport.requests().len() > 1);
+    } // fn
+
+} // mod
\ No newline at end of file
@@ -0,0 +1,72 @@
+use crate::client::GoogleMapsClient;
+use crate::oauth::{StaticToken, TokenProvider};
+use std::sync::Arc;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Authenticates requests using an OAuth 2.0 access token that was
+    /// obtained elsewhere, for example from `gcloud auth
+    /// print-access-token`. The token is sent to the server in an
+    /// `Authorization: Bearer ...` header and is never refreshed.
+    ///
+    /// If the client was initialized with an empty API key, no `key` parameter
+    /// is sent.
+    ///
+    /// ## Arguments
+    ///
+    /// * `access_token` ‧ The OAuth 2.0 access token.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("")
+    ///     .with_bearer_token("YOUR_ACCESS_TOKEN_HERE")
+    ///     .build();
+    /// ```
+
+    pub fn with_bearer_token(
+        &mut self,
+        access_token: &str,
+    ) -> &mut GoogleMapsClient {
+        self.with_token_provider(StaticToken::new(access_token))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Authenticates requests using OAuth 2.0 access tokens from a token
+    /// provider. A token is requested from the provider before each HTTP
+    /// request is sent, and is sent to the server in an `Authorization: Bearer
+    /// ...` header.
+    ///
+    /// If the client was initialized with an empty API key, no `key` parameter
+    /// is sent.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token_provider` ‧ Any type that implements the
+    ///   `google_maps::oauth::TokenProvider` trait. For example, a
+    ///   `ServiceAccount` (requires the `service-account` feature).
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let service_account = ServiceAccount::from_json(&key_json)?.build();
+    ///
+    /// let mut google_maps_client = GoogleMapsClient::new("")
+    ///     .with_token_provider(service_account)
+    ///     .build();
+    /// ```
+
+    pub fn with_token_provider(
+        &mut self,
+        token_provider: impl TokenProvider + 'static,
+    ) -> &mut GoogleMapsClient {
+        self.token_provider = Some(Arc::new(token_provider));
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
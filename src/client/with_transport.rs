@@ -0,0 +1,40 @@
+use crate::client::GoogleMapsClient;
+use crate::transport::Transport;
+use std::sync::Arc;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Passes a user supplied HTTP transport for the Google Maps client to use.
+    /// This allows you to send requests through an HTTP stack other than
+    /// reqwest, such as `hyper`, `ureq`, an instrumented in-house client, or a
+    /// test double that returns canned JSON.
+    ///
+    /// Rate limiting, retries, deserialization and status handling are still
+    /// performed by the Google Maps client.
+    ///
+    /// ## Arguments
+    ///
+    /// * `transport` ‧ Any type that implements the
+    ///   `google_maps::transport::Transport` trait.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_transport(MyHyperTransport::new())
+    ///     .build();
+    /// ```
+
+    pub fn with_transport(
+        &mut self,
+        transport: impl Transport + 'static,
+    ) -> &mut GoogleMapsClient {
+        self.transport = Arc::new(transport);
+        self
+    } // fn
+
+} // impl
@@ -0,0 +1,71 @@
+use crate::client::GoogleMapsClient;
+use crate::url_signing::{decode_signing_secret, Error};
+
+// =============================================================================
+
+impl GoogleMapsClient {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Authenticates requests using a client ID and URL signing secret, rather
+    /// than an API key. This is required by some Google Maps Platform premium
+    /// plan and enterprise customers.
+    ///
+    /// The `client` parameter is sent instead of the `key` parameter, and every
+    /// request is signed with a HMAC-SHA1 digital signature after its query
+    /// string is built.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client_id` ‧ Your client ID. For example, `gme-yourcompany`.
+    ///
+    /// * `signing_secret` ‧ Your URL signing secret, as provided by the Google
+    ///   Cloud Console. It is URL-safe base64 encoded.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("")
+    ///     .with_client_id("gme-yourcompany", "YOUR_SIGNING_SECRET_HERE")?
+    ///     .build();
+    /// ```
+
+    pub fn with_client_id(
+        &mut self,
+        client_id: &str,
+        signing_secret: &str,
+    ) -> Result<&mut GoogleMapsClient, Error> {
+        self.signing_secret = Some(decode_signing_secret(signing_secret)?);
+        self.client_id = Some(client_id.to_string());
+        Ok(self)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Signs every request that is authenticated with the client's API key
+    /// using a HMAC-SHA1 digital signature. Signing requests makes a stolen API
+    /// key less useful, and is required for some services when the API key's
+    /// quota is configured to require signatures.
+    ///
+    /// ## Arguments
+    ///
+    /// * `signing_secret` ‧ Your URL signing secret, as provided by the Google
+    ///   Cloud Console. It is URL-safe base64 encoded.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_signing_secret("YOUR_SIGNING_SECRET_HERE")?
+    ///     .build();
+    /// ```
+
+    pub fn with_signing_secret(
+        &mut self,
+        signing_secret: &str,
+    ) -> Result<&mut GoogleMapsClient, Error> {
+        self.signing_secret = Some(decode_signing_secret(signing_secret)?);
+        Ok(self)
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -98,6 +98,7 @@ pub use crate::directions::{
     request::{
         avoid::Avoid,
         departure_time::DepartureTime,
+        description::Description as DirectionsRequestDescription,
         location::Location,
         Request as DirectionsRequest,
         traffic_model::TrafficModel,
@@ -14,15 +14,14 @@ impl<'a> Request<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&'a mut self) -> Result<&'a mut Request, Error> {
+    pub fn build(&mut self) -> Result<&mut Self, Error> {
 
         // Ensure request has been validated before building the query string:
         if !self.validated { return Err(Error::RequestNotValidated) }
 
         // Builds the "required parameters" portion of the query string:
         let mut query = format!(
-            "key={}&origin={}&destination={}",
-            self.client.key,
+            "origin={}&destination={}",
             String::from(&self.origin), // URL-encoding performed by From trait
             String::from(&self.destination), // URL-encoding performed by From trait
         ); // format!
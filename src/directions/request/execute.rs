@@ -23,7 +23,7 @@ impl<'a> DirectionsRequest<'a> {
     /// This method accepts no arguments.
 
     pub async fn execute(
-        &'a mut self
+        &mut self
     ) -> Result<DirectionsResponse, GoogleMapsError> {
 
         self.validate()?.build()?.get().await
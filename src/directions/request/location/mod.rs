@@ -64,7 +64,7 @@ impl std::convert::From<&Location> for String {
 /// and destination.
 
 #[cfg(feature = "geo")]
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum Location {
     /// If you pass an address, the Directions service geocodes the string and
     /// converts it to a latitude/longitude coordinate to calculate directions.
@@ -4,10 +4,15 @@
 //! for building your Google Maps Platform request.
 
 mod build;
+mod describe;
 #[cfg(feature = "enable-reqwest")]
 mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
 #[cfg(feature = "enable-reqwest")]
 mod get;
+#[cfg(feature = "enable-reqwest")]
+mod into_future;
 mod new;
 mod query_url;
 mod validate;
@@ -17,6 +22,8 @@ mod with_departure_time;
 mod with_language;
 mod with_region;
 mod with_restrictions;
+#[cfg(feature = "enable-reqwest")]
+mod with_timeout;
 mod with_traffic_model;
 mod with_transit_modes;
 mod with_transit_route_preference;
@@ -26,6 +33,7 @@ mod with_waypoint_optimization;
 mod with_waypoints;
 pub mod avoid;
 pub mod departure_time;
+pub mod description;
 pub mod location;
 pub mod traffic_model;
 pub mod transit_mode;
@@ -46,7 +54,7 @@ use crate::directions::request::{
     unit_system::UnitSystem,
     waypoint::Waypoint,
 }; // crate::directions::request
-use crate::client::GoogleMapsClient;
+use crate::client::ClientRef;
 use crate::directions::travel_mode::TravelMode;
 use crate::types::{Language, Region};
 
@@ -64,7 +72,7 @@ pub struct Request<'a> {
 
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
-    client: &'a GoogleMapsClient,
+    client: ClientRef<'a>,
 
     /// The address, latitude/longitude, or place ID to which you wish to
     /// calculate directions.
@@ -130,6 +138,12 @@ pub struct Request<'a> {
     /// `waypoint.rs` and method `with_waypoints()` for more information.
     waypoints: Option<Vec<Waypoint>>,
 
+    /// The deadline for the request, including rate limiter waits and
+    /// retries. It overrides the client's default timeout. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "enable-reqwest")]
+    timeout: Option<std::time::Duration>,
+
     // Internal use only:
     // ------------------
 
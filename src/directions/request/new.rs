@@ -1,5 +1,5 @@
 use crate::{
-    client::GoogleMapsClient,
+    client::ClientRef,
     directions::request::{location::Location, Request},
 }; // use crate
 
@@ -16,14 +16,14 @@ impl<'a> Request<'a> {
     /// This method accepts no arguments.
 
     pub fn new(
-        client: &'a GoogleMapsClient,
+        client: impl Into<ClientRef<'a>>,
         origin: Location,
         destination: Location,
-    ) -> Request<'a> {
+    ) -> Self {
 
         Request {
             // Required parameters:
-            client,
+            client: client.into(),
             destination,
             origin,
             // Optional parameters:
@@ -40,6 +40,8 @@ impl<'a> Request<'a> {
             unit_system: None,
             waypoint_optimization: false,
             waypoints: None,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
             // Internal use only:
             query: None,
             validated: false,
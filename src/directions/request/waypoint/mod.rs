@@ -64,7 +64,7 @@ impl std::convert::From<&Waypoint> for String {
 /// Used to specify pass throughs or stopovers at intermediate locations.
 
 #[cfg(feature = "geo")]
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum Waypoint {
     /// If you pass an address, the Directions service geocodes the string and
     /// converts it to latitude & longitude coordinates to calculate directions.
@@ -25,9 +25,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_alternatives(
-        &'a mut self,
+        &mut self,
         alternatives: bool
-    ) -> &'a mut Request {
+    ) -> &mut Self {
         self.alternatives = Some(alternatives);
         self
     } // fn
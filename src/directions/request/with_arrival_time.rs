@@ -24,9 +24,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_arrival_time(
-        &'a mut self,
+        &mut self,
         arrival_time: NaiveDateTime
-    ) -> &'a mut Request {
+    ) -> &mut Self {
         self.arrival_time = Some(arrival_time);
         self
     } // fn
@@ -58,9 +58,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_departure_time(
-        &'a mut self,
+        &mut self,
         departure_time: DepartureTime
-    ) -> &'a mut Request {
+    ) -> &mut Self {
         self.departure_time = Some(departure_time);
         self
     } // fn
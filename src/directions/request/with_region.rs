@@ -32,7 +32,7 @@ impl<'a> Request<'a> {
     /// .with_region(Region::Canada)
     /// ```
 
-    pub fn with_region(&'a mut self, region: Region) -> &'a mut Request {
+    pub fn with_region(&mut self, region: Region) -> &mut Self {
         self.region = Some(region);
         self
     } // fn
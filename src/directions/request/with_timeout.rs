@@ -0,0 +1,36 @@
+use crate::directions::request::Request;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Sets a deadline for this Directions API request. If the route has not
+    /// been returned in time, `Error::Timeout` is returned instead. The
+    /// deadline covers the whole request: waiting on the client's rate limits,
+    /// the HTTP request and any retries.
+    ///
+    /// It overrides the client's default timeout, if one was set using
+    /// `GoogleMapsClient::with_timeout`. Routes with many waypoints, or that
+    /// use live traffic, take Google longer to calculate, so allow for this
+    /// when choosing a deadline.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `timeout` ‧ The maximum time that the request may take.
+    ///
+    /// ## Example:
+    ///
+    /// * Give up on the route after 15 seconds:
+    /// ```rust
+    /// .with_timeout(std::time::Duration::from_secs(15))
+    /// ```
+
+    pub fn with_timeout(&mut self, timeout: Duration) -> &mut Self {
+        // Set timeout in Request struct.
+        self.timeout = Some(timeout);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -50,9 +50,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_traffic_model(
-        &'a mut self,
+        &mut self,
         traffic_model: TrafficModel
-    ) -> &'a mut Request {
+    ) -> &mut Self {
         self.traffic_model = Some(traffic_model);
         self
     } // fn
@@ -49,9 +49,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_transit_mode(
-        &'a mut self,
+        &mut self,
         transit_mode: TransitMode
-    ) -> &'a mut Request {
+    ) -> &mut Self {
         // Add restiction to Request struct.
         match &mut self.transit_modes {
             // If there are no transit modes in the request struct, initialize:
@@ -79,9 +79,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_transit_modes(
-        &'a mut self,
+        &mut self,
         transit_modes_slice: &[TransitMode],
-    ) -> &'a mut Request {
+    ) -> &mut Self {
         // Add transit_modes to Request struct.
         match &mut self.transit_modes {
             // If there are no transit modes in the request struct, initialize:
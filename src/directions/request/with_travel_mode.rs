@@ -47,9 +47,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_travel_mode(
-        &'a mut self,
+        &mut self,
         travel_mode: TravelMode
-    ) -> &'a mut Request {
+    ) -> &mut Self {
         self.travel_mode = Some(travel_mode);
         self
     } // fn
@@ -42,9 +42,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_unit_system(
-        &'a mut self,
+        &mut self,
         unit_system: UnitSystem
-    ) -> &mut Request {
+    ) -> &mut Self {
         self.unit_system = Some(unit_system);
         self
     } // fn
@@ -43,9 +43,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_waypoint_optimization(
-        &'a mut self,
+        &mut self,
         waypoint_optimization: bool,
-    ) -> &'a mut Request {
+    ) -> &mut Self {
         self.waypoint_optimization = waypoint_optimization;
         self
     } // fn
@@ -91,7 +91,7 @@ impl<'a> Request<'a> {
     /// .with_waypoint(Waypoint::PlaceId(String::from("ChIJi5fWgmcSzkwRePJ_I9-xCRg")))
     /// ```
 
-    pub fn with_waypoint(&'a mut self, waypoint: Waypoint) -> &'a mut Request {
+    pub fn with_waypoint(&mut self, waypoint: Waypoint) -> &mut Self {
         // Add waypoint to Request struct.
         match &mut self.waypoints {
             // If there are no waypoints in the request struct, initialize:
@@ -124,9 +124,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_waypoints(
-        &'a mut self,
+        &mut self,
         waypoints_slice: &[Waypoint]
-    ) -> &'a mut Request {
+    ) -> &mut Self {
         // Add waypoints to Request struct.
         match &mut self.waypoints {
             // If there are no waypoints in the request struct, initialize:
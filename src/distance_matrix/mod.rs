@@ -120,6 +120,7 @@ pub use crate::directions::{
 }; // crate::directions
 
 pub use crate::distance_matrix::{
+    request::description::Description as DistanceMatrixRequestDescription,
     request::Request as DistanceMatrixRequest,
     response::Response as DistanceMatrixResponse,
     response::status::Status as DistanceMatrixStatus,
@@ -10,7 +10,7 @@ impl<'a> Request<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> Result<&'a mut Request, Error> {
+    pub fn build(&mut self) -> Result<&mut Self, Error> {
 
         // Ensure request has been validated before building the query string:
 
@@ -21,9 +21,7 @@ impl<'a> Request<'a> {
         // Builds the "required parameters" portion of the query string:
 
         let mut query = format!(
-            "key={}&origins={}&destinations={}",
-            // Key:
-            self.client.key,
+            "origins={}&destinations={}",
             // Origins:
             utf8_percent_encode(
                 &self.origins
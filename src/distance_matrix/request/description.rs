@@ -0,0 +1,140 @@
+//! Contains the `Description` struct. It is a client-independent, serializable
+//! description of a _Distance Matrix API_ request that may be persisted (for
+//! example, in a job queue) and later bound to a client for execution.
+
+use chrono::NaiveDateTime;
+use crate::client::ClientRef;
+use crate::directions::request::{
+    avoid::Avoid,
+    departure_time::DepartureTime,
+    traffic_model::TrafficModel,
+    transit_mode::TransitMode,
+    transit_route_preference::TransitRoutePreference,
+    unit_system::UnitSystem,
+    waypoint::Waypoint,
+}; // crate::directions::request
+use crate::directions::travel_mode::TravelMode;
+use crate::distance_matrix::request::Request;
+use crate::types::{Language, Region};
+
+// -----------------------------------------------------------------------------
+//
+/// A client-independent description of a _Distance Matrix API_ request. Use
+/// `Request::describe()` to obtain one, store it wherever you like using
+/// `serde`, and then call `bind()` to turn it back into a `Request` that may
+/// be executed.
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Description {
+    /// One or more locations to use as the finishing point for calculating
+    /// travel distance and time.
+    #[serde(default)]
+    pub destinations: Vec<Waypoint>,
+    /// The starting point for calculating travel distance and time.
+    #[serde(default)]
+    pub origins: Vec<Waypoint>,
+    /// Desired arrival time.
+    pub arrival_time: Option<NaiveDateTime>,
+    /// Desired departure time.
+    pub departure_time: Option<DepartureTime>,
+    /// Language in which to return results.
+    pub language: Option<Language>,
+    /// Region bias.
+    pub region: Option<Region>,
+    /// Features that routes should avoid.
+    pub restrictions: Option<Vec<Avoid>>,
+    /// Assumptions to use when calculating time in traffic.
+    pub traffic_model: Option<TrafficModel>,
+    /// Preferred modes of transit.
+    pub transit_modes: Option<Vec<TransitMode>>,
+    /// Preferences for transit routes.
+    pub transit_route_preference: Option<TransitRoutePreference>,
+    /// Mode of transportation.
+    pub travel_mode: Option<TravelMode>,
+    /// Unit system to use when displaying results.
+    pub unit_system: Option<UnitSystem>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Request<'_>> for Description {
+    /// Describes a _Distance Matrix API_ request, leaving its client behind.
+    fn from(request: &Request<'_>) -> Self {
+        Description {
+            destinations: request.destinations.clone(),
+            origins: request.origins.clone(),
+            arrival_time: request.arrival_time,
+            departure_time: request.departure_time.clone(),
+            language: request.language.clone(),
+            region: request.region.clone(),
+            restrictions: request.restrictions.clone(),
+            traffic_model: request.traffic_model.clone(),
+            transit_modes: request.transit_modes.clone(),
+            transit_route_preference: request.transit_route_preference.clone(),
+            travel_mode: request.travel_mode.clone(),
+            unit_system: request.unit_system.clone(),
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Description {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Binds this description to a client, producing a `Request` that is ready
+    /// to be executed.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct. This may
+    ///   be a reference to a `GoogleMapsClient` or an `Arc<GoogleMapsClient>`.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let description: DistanceMatrixRequestDescription = serde_json::from_str(&job)?;
+    /// let response = description.bind(&google_maps_client).execute().await?;
+    /// ```
+
+    pub fn bind<'a>(self, client: impl Into<ClientRef<'a>>) -> Request<'a> {
+        Request {
+            client: client.into(),
+            destinations: self.destinations,
+            origins: self.origins,
+            arrival_time: self.arrival_time,
+            departure_time: self.departure_time,
+            language: self.language,
+            region: self.region,
+            restrictions: self.restrictions,
+            traffic_model: self.traffic_model,
+            transit_modes: self.transit_modes,
+            transit_route_preference: self.transit_route_preference,
+            travel_mode: self.travel_mode,
+            unit_system: self.unit_system,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
+            query: None,
+            validated: false,
+        } // struct
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::traits::RequestDescription for Description {
+
+    type Response = crate::distance_matrix::response::Response;
+
+    /// Binds this description to the client and executes the request.
+    fn execute(
+        self,
+        client: &crate::client::GoogleMapsClient,
+    ) -> futures::future::BoxFuture<'_, Result<Self::Response, crate::error::Error>> {
+        Box::pin(async move { self.bind(client).execute().await })
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -23,7 +23,7 @@ impl<'a> DistanceMatrixRequest<'a> {
     /// This method accepts no arguments.
 
     pub async fn execute(
-        &'a mut self
+        &mut self
     ) -> Result<DistanceMatrixResponse, GoogleMapsError> {
 
         self.validate()?.build()?.get().await
@@ -4,10 +4,15 @@
 //! for building your Google Maps Platform request.
 
 mod build;
+mod describe;
 #[cfg(feature = "enable-reqwest")]
 mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
 #[cfg(feature = "enable-reqwest")]
 mod get;
+#[cfg(feature = "enable-reqwest")]
+mod into_future;
 mod new;
 mod query_url;
 mod validate;
@@ -16,11 +21,14 @@ mod with_departure_time;
 mod with_language;
 mod with_region;
 mod with_restrictions;
+#[cfg(feature = "enable-reqwest")]
+mod with_timeout;
 mod with_traffic_model;
 mod with_transit_modes;
 mod with_transit_route_preference;
 mod with_travel_mode;
 mod with_unit_system;
+pub mod description;
 
 // -----------------------------------------------------------------------------
 
@@ -34,7 +42,7 @@ use crate::directions::request::{
     unit_system::UnitSystem,
     waypoint::Waypoint,
 }; // crate::directions::request
-use crate::client::GoogleMapsClient;
+use crate::client::ClientRef;
 use crate::directions::travel_mode::TravelMode;
 use crate::types::{Language, Region};
 
@@ -52,7 +60,7 @@ pub struct Request<'a> {
 
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
-    client: &'a GoogleMapsClient,
+    client: ClientRef<'a>,
 
     /// One or more locations to use as the finishing point for calculating
     /// travel distance and time. You can pass an address, latitude/longitude,
@@ -107,6 +115,12 @@ pub struct Request<'a> {
     /// and method `with_unit_system()` for more information.
     unit_system: Option<UnitSystem>,
 
+    /// The deadline for the request, including rate limiter waits and
+    /// retries. It overrides the client's default timeout. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "enable-reqwest")]
+    timeout: Option<std::time::Duration>,
+
     // Internal use only:
     // ------------------
 
@@ -1,5 +1,5 @@
 use crate::{
-    client::GoogleMapsClient,
+    client::ClientRef,
     directions::request::waypoint::Waypoint,
     distance_matrix::request::Request,
 }; // use
@@ -18,14 +18,14 @@ impl<'a> Request<'a> {
     /// * `client` ‧ Your application's Google Maps API client struct.
 
     pub fn new(
-        client: &GoogleMapsClient,
+        client: impl Into<ClientRef<'a>>,
         origins: Vec<Waypoint>,
         destinations: Vec<Waypoint>,
-    ) -> Request {
+    ) -> Self {
 
         Request {
             // Required parameters:
-            client,
+            client: client.into(),
             destinations,
             origins,
             // Optional parameters:
@@ -39,6 +39,8 @@ impl<'a> Request<'a> {
             transit_route_preference: None,
             travel_mode: None,
             unit_system: None,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
             // Internal use only:
             query: None,
             validated: false,
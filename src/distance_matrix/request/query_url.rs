@@ -4,6 +4,7 @@ use crate::distance_matrix::{
     error::Error,
     request::Request,
 }; // crate::distance_matrix
+use crate::request_rate::api::Api;
 
 impl<'a> Request<'a> {
 
@@ -16,18 +17,27 @@ impl<'a> Request<'a> {
     /// records or logging. It could also be used for passing to your HTTP
     /// client of choice and executing the HTTP GET request yourself.
     ///
+    /// The API key (or client ID and signature) is appended to the URL. The
+    /// API key and signature are replaced with `REDACTED` unless the client
+    /// has opted-in to revealing secrets using
+    /// `GoogleMapsClient::with_reveal_secrets`. If a base URL override has
+    /// been set using `GoogleMapsClient::with_base_url`, it is used in place
+    /// of Google's.
+    ///
     /// ## Arguments:
     ///
     /// This method accepts no arguments.
 
-    pub fn query_url(&'a mut self) -> Result<String, Error> {
+    pub fn query_url(&mut self) -> Result<String, Error> {
+        let client = self.client.clone();
         let query_string = match &self.query {
             // If query string has already been built, return it:
             Some(query_string) => query_string,
             // If it hasn't been built, build it:
             None => self.validate()?.build()?.query.as_ref().unwrap(),
         }; // match
-        Ok(format!("{SERVICE_URL}/{OUTPUT_FORMAT}?{query_string}"))
+        let service_url = client.service_url(&Api::DistanceMatrix, SERVICE_URL);
+        Ok(client.redact_url(&client.authenticated_url(&format!("{service_url}/{OUTPUT_FORMAT}?{query_string}"))))
     } // fn
 
 } // impl
\ No newline at end of file
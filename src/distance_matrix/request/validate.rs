@@ -14,7 +14,7 @@ impl<'a> Request<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn validate(&mut self) -> Result<&'a mut Request, Error> {
+    pub fn validate(&mut self) -> Result<&mut Self, Error> {
 
         if let Some(travel_mode) = &self.travel_mode {
 
@@ -60,9 +60,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_restriction(
-        &'a mut self,
+        &mut self,
         restriction: Avoid
-    ) -> &'a mut Request {
+    ) -> &mut Self {
         // Add restriction to Request struct.
         match &mut self.restrictions {
             // If there are no restrictions in the request struct, initialize:
@@ -89,9 +89,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_restrictions(
-        &'a mut self,
+        &mut self,
         restrictions_slice: &[Avoid]
-    ) -> &'a mut Request {
+    ) -> &mut Self {
         // Add restrictions to Request struct.
         match &mut self.restrictions {
             // If there are no filters in the request struct, initialize field:
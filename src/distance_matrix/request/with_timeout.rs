@@ -0,0 +1,36 @@
+use crate::distance_matrix::request::Request;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Sets a deadline for this Distance Matrix API request. If the matrix has
+    /// not been returned in time, `Error::Timeout` is returned instead. The
+    /// deadline covers waiting on the client's rate limits, the HTTP request
+    /// and any retries.
+    ///
+    /// It overrides the client's default timeout, if one was set using
+    /// `GoogleMapsClient::with_timeout`. A large matrix counts as many elements
+    /// against weighted rate limits, so it may wait under the rate limiter for
+    /// longer than a small one.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `timeout` ‧ The maximum time that the request may take.
+    ///
+    /// ## Example:
+    ///
+    /// * Give up on the matrix after 20 seconds:
+    /// ```rust
+    /// .with_timeout(std::time::Duration::from_secs(20))
+    /// ```
+
+    pub fn with_timeout(&mut self, timeout: Duration) -> &mut Self {
+        // Set timeout in Request struct.
+        self.timeout = Some(timeout);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -34,9 +34,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_transit_route_preference(
-        &'a mut self,
+        &mut self,
         transit_route_preference: TransitRoutePreference,
-    ) -> &'a mut Request {
+    ) -> &mut Self {
         self.transit_route_preference = Some(transit_route_preference);
         self
     } // fn
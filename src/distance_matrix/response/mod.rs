@@ -52,4 +52,23 @@ impl std::str::FromStr for Response {
     fn from_str(s: &str) -> Result<Self, serde_json::error::Error> {
         serde_json::from_str(s)
     }
-}
\ No newline at end of file
+}
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::traits::ServiceResponse for Response {
+    type Error = crate::distance_matrix::error::Error;
+    /// Returns the response if the Google Maps Distance Matrix API status is `OK`.
+    /// Otherwise, returns the status code and the corresponding error.
+    fn into_result(self) -> Result<Self, (String, Self::Error)> {
+        if self.status == Status::Ok {
+            Ok(self)
+        } else {
+            Err((
+                String::from(&self.status),
+                crate::distance_matrix::error::Error::GoogleMapsService(self.status, self.error_message),
+            ))
+        } // if
+    } // fn
+} // impl
\ No newline at end of file
@@ -87,6 +87,7 @@ const OUTPUT_FORMAT: &str = "json"; // json or xml
 pub use crate::elevation::{
     error::Error as ElevationError,
     request::{
+        description::Description as ElevationRequestDescription,
         locations::Locations as ElevationLocations,
         Request as ElevationRequest,
     }, // request
@@ -12,17 +12,13 @@ impl<'a> Request<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> Result<&'a mut Request, Error> {
+    pub fn build(&mut self) -> Result<&mut Self, Error> {
 
         // Ensure request has been validated before building the query string:
 
         if !self.validated { return Err(Error::RequestNotValidated) }
 
-        // This section builds the "required parameters" portion of the query
-        // string:
-
-        let mut query = String::from("key=");
-        query.push_str(&self.client.key);
+        let mut query = String::new();
 
         // This section builds the "positional request" portion of the query
         // string:
@@ -48,6 +44,9 @@ impl<'a> Request<'a> {
             query.push_str(&samples.to_string());
         } // if
 
+        // Remove the leading ampersand from the first key/value pair:
+        if query.starts_with('&') { query.remove(0); }
+
         // Set query string in Request struct.
         self.query = Some(query);
 
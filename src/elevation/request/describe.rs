@@ -0,0 +1,27 @@
+use crate::elevation::request::{description::Description, Request};
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a client-independent, serializable description of this request.
+    /// The description may be persisted and later turned back into a request
+    /// with `Description::bind()`.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let job = serde_json::to_string(&elevation_request.describe())?;
+    /// ```
+
+    pub fn describe(&self) -> Description {
+        Description::from(self)
+    } // fn
+
+} // impl
\ No newline at end of file
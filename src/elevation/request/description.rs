@@ -0,0 +1,89 @@
+//! Contains the `Description` struct. It is a client-independent, serializable
+//! description of a _Elevation API_ request that may be persisted (for
+//! example, in a job queue) and later bound to a client for execution.
+
+use crate::client::ClientRef;
+use crate::elevation::request::{locations::Locations, Request};
+
+// -----------------------------------------------------------------------------
+//
+/// A client-independent description of a _Elevation API_ request. Use
+/// `Request::describe()` to obtain one, store it wherever you like using
+/// `serde`, and then call `bind()` to turn it back into a `Request` that may
+/// be executed.
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Description {
+    /// Locations on the earth from which to return elevation data.
+    pub locations: Option<Locations>,
+    /// The path along which to return elevation data.
+    pub path: Option<Locations>,
+    /// The number of sample points along the path.
+    pub samples: Option<u8>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Request<'_>> for Description {
+    /// Describes a _Elevation API_ request, leaving its client behind.
+    fn from(request: &Request<'_>) -> Self {
+        Description {
+            locations: request.locations.clone(),
+            path: request.path.clone(),
+            samples: request.samples,
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Description {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Binds this description to a client, producing a `Request` that is ready
+    /// to be executed.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct. This may
+    ///   be a reference to a `GoogleMapsClient` or an `Arc<GoogleMapsClient>`.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let description: ElevationRequestDescription = serde_json::from_str(&job)?;
+    /// let response = description.bind(&google_maps_client).execute().await?;
+    /// ```
+
+    pub fn bind<'a>(self, client: impl Into<ClientRef<'a>>) -> Request<'a> {
+        Request {
+            client: client.into(),
+            locations: self.locations,
+            path: self.path,
+            samples: self.samples,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
+            query: None,
+            validated: false,
+        } // struct
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::traits::RequestDescription for Description {
+
+    type Response = crate::elevation::response::Response;
+
+    /// Binds this description to the client and executes the request.
+    fn execute(
+        self,
+        client: &crate::client::GoogleMapsClient,
+    ) -> futures::future::BoxFuture<'_, Result<Self::Response, crate::error::Error>> {
+        Box::pin(async move { self.bind(client).execute().await })
+    } // fn
+
+} // impl
\ No newline at end of file
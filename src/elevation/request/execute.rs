@@ -23,7 +23,7 @@ impl<'a> ElevationRequest<'a> {
     /// This method accepts no arguments.
 
     pub async fn execute(
-        &'a mut self
+        &mut self
     ) -> Result<ElevationResponse, GoogleMapsError> {
 
         self.validate()?.build()?.get().await
@@ -23,9 +23,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn for_positional_request(
-        &'a mut self,
+        &mut self,
         location: LatLng,
-    ) -> &'a mut Request {
+    ) -> &mut Self {
         // Set the path in Request struct.
         self.locations = Some(Locations::LatLngs(vec![location]));
         // Return modified Request struct to caller.
@@ -56,9 +56,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn for_positional_requests(
-        &'a mut self,
+        &mut self,
         locations: Locations,
-    ) -> &'a mut Request {
+    ) -> &mut Self {
         // Set the path in Request struct.
         self.locations = Some(locations);
         // Return modified Request struct to caller.
@@ -80,9 +80,9 @@ impl<'a> Request<'a> {
 
     #[cfg(feature = "geo")]
     pub fn for_coordinate_request(
-        &'a mut self,
+        &mut self,
         coordinate: &geo_types::Coord,
-    ) -> Result<&'a mut Request, crate::error::Error> {
+    ) -> Result<&mut Self, crate::error::Error> {
         // Set the path in Request struct.
         self.locations = Some(Locations::LatLngs(vec![LatLng::try_from(coordinate)?]));
         // Return modified Request struct to caller.
@@ -104,9 +104,9 @@ impl<'a> Request<'a> {
 
     #[cfg(feature = "geo")]
     pub fn try_point_request(
-        &'a mut self,
+        &mut self,
         point: &geo_types::Point,
-    ) -> Result<&'a mut Request, crate::error::Error> {
+    ) -> Result<&mut Self, crate::error::Error> {
         // Set the path in Request struct.
         self.locations = Some(Locations::LatLngs(vec![LatLng::try_from(point)?]));
         // Return modified Request struct to caller.
@@ -46,10 +46,10 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn for_sampled_path_request(
-        &'a mut self,
+        &mut self,
         path: Locations,
         samples: u8
-    ) -> &'a mut Request {
+    ) -> &mut Self {
         // Set the path in Request struct.
         self.path = Some(path);
         // Set the sample number in Request struct.
@@ -75,9 +75,9 @@ impl<'a> Request<'a> {
 
     #[cfg(feature = "geo")]
     pub fn for_line_string_request(
-        &'a mut self,
+        &mut self,
         line_string: geo_types::LineString,
-    ) -> Result<&'a mut Request, crate::error::Error> {
+    ) -> Result<&mut Self, crate::error::Error> {
         // Set the path in Request struct.
         self.locations = Some(Locations::LineString(line_string));
         // Return modified Request struct to caller.
@@ -64,7 +64,7 @@ impl std::convert::From<&Locations> for String {
 /// pair, multiple latitude/longitude pairs, or an encoded polyline.
 
 #[cfg(feature = "geo")]
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum Locations {
     /// A single or multiple
     /// [latitude/longitude](https://developers.google.com/maps/documentation/elevation/intro#Locations)
@@ -127,6 +127,34 @@ impl std::convert::From<&Locations> for String {
 
 // -----------------------------------------------------------------------------
 
+impl Locations {
+
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// ## Description
+    ///
+    /// Returns the number of locations. The points in an encoded polyline are
+    /// counted without decoding it: each point is two values, and each value
+    /// ends with a character that doesn't have the continuation bit set.
+    #[cfg(feature = "enable-reqwest")]
+    pub(crate) fn count(&self) -> usize {
+        match self {
+            Locations::LatLngs(latlngs) => latlngs.len(),
+            Locations::Polyline(polyline) => polyline
+                .bytes()
+                .filter(|byte| byte.wrapping_sub(63) < 0x20)
+                .count() / 2,
+            #[cfg(feature = "geo")]
+            Locations::Line(_line) => 2,
+            #[cfg(feature = "geo")]
+            Locations::LineString(line_string) => line_string.0.len(),
+        } // match
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
 impl std::default::Default for Locations {
     /// Returns a reasonable default variant for the `Locations` enum type.
     fn default() -> Self {
@@ -4,20 +4,28 @@
 //! for building your Google Maps Platform request.
 
 mod build;
+mod describe;
 #[cfg(feature = "enable-reqwest")]
 mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
 mod for_positional_request;
 mod for_sampled_path_request;
 #[cfg(feature = "enable-reqwest")]
 mod get;
+#[cfg(feature = "enable-reqwest")]
+mod into_future;
 mod new;
 mod query_url;
 mod validate;
+#[cfg(feature = "enable-reqwest")]
+mod with_timeout;
+pub mod description;
 pub mod locations;
 
 // -----------------------------------------------------------------------------
 
-use crate::{client::GoogleMapsClient, elevation::request::locations::Locations};
+use crate::{client::ClientRef, elevation::request::locations::Locations};
 
 // -----------------------------------------------------------------------------
 //
@@ -33,7 +41,7 @@ pub struct Request<'a> {
 
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
-    client: &'a GoogleMapsClient,
+    client: ClientRef<'a>,
 
     // Positional Requests:
     // --------------------
@@ -59,6 +67,12 @@ pub struct Request<'a> {
     /// ordered set of equidistant points along the path.
     samples: Option<u8>,
 
+    /// The deadline for the request, including rate limiter waits and
+    /// retries. It overrides the client's default timeout. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "enable-reqwest")]
+    timeout: Option<std::time::Duration>,
+
     // Internal use only:
     // ------------------
 
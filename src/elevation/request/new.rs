@@ -1,4 +1,4 @@
-use crate::client::GoogleMapsClient;
+use crate::client::ClientRef;
 use crate::elevation::request::Request;
 
 // =============================================================================
@@ -14,17 +14,19 @@ impl<'a> Request<'a> {
     ///
     /// * `client` ‧ Your application's Google Maps API client struct.
 
-    pub fn new(client: &GoogleMapsClient) -> Request {
+    pub fn new(client: impl Into<ClientRef<'a>>) -> Self {
 
         // Instantiate struct and return it to caller:
         Request {
             // Required parameters:
-            client,
+            client: client.into(),
             // Positional requests:
             locations: None,
             // Sampled path requests:
             path: None,
             samples: None,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
             // Internal use only:
             query: None,
             validated: false,
@@ -13,7 +13,7 @@ impl<'a> Request<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn validate(&mut self) -> Result<&'a mut Request, Error> {
+    pub fn validate(&mut self) -> Result<&mut Self, Error> {
 
         // If a "for positional request" has been set...
         if let Some(_locations) = &self.locations {
@@ -0,0 +1,33 @@
+use crate::elevation::request::Request;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Sets a deadline for this Elevation API request. If the elevations have
+    /// not been returned in time, `Error::Timeout` is returned instead. The
+    /// deadline includes waiting on the client's rate limits and any retries.
+    ///
+    /// It overrides the client's default timeout, if one was set using
+    /// `GoogleMapsClient::with_timeout`.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `timeout` ‧ The maximum time that the request may take.
+    ///
+    /// ## Example:
+    ///
+    /// * Give up on the elevations after 10 seconds:
+    /// ```rust
+    /// .with_timeout(std::time::Duration::from_secs(10))
+    /// ```
+
+    pub fn with_timeout(&mut self, timeout: Duration) -> &mut Self {
+        // Set timeout in Request struct.
+        self.timeout = Some(timeout);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
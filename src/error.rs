@@ -47,6 +47,13 @@ pub enum Error {
     #[diagnostic(code(google_maps::geocoding))]
     Geocoding(#[from] crate::geocoding::error::Error),
 
+    /// Error originating from the `geolocation` module in the `google_maps`
+    /// crate.
+    #[cfg(feature = "geolocation")]
+    #[error(transparent)]
+    #[diagnostic(code(google_maps::geolocation))]
+    Geolocation(#[from] crate::geolocation::error::Error),
+
     /// Error originating from the `places` module in the `google_maps` crate.
     #[cfg(feature = "places")]
     #[error(transparent)]
@@ -73,4 +80,37 @@ pub enum Error {
     #[diagnostic(code(google_maps::time_zone))]
     TimeZone(#[from] crate::time_zone::error::Error),
 
+    /// Error originating from the HTTP transport that is used by the
+    /// `google_maps` crate to send requests.
+    #[cfg(feature = "enable-reqwest")]
+    #[error(transparent)]
+    #[diagnostic(code(google_maps::transport))]
+    Transport(#[from] crate::transport::Error),
+
+    /// The request did not complete within its deadline. The deadline covers
+    /// the whole request, including rate limiter waits and retries. It is set
+    /// using the client's or the request's `with_timeout` method.
+    #[cfg(feature = "enable-reqwest")]
+    #[error("the request did not complete within its {0:?} timeout")]
+    #[diagnostic(code(google_maps::timeout))]
+    Timeout(std::time::Duration),
+
+    /// Error originating from the `billing` module in the `google_maps` crate.
+    #[cfg(feature = "enable-reqwest")]
+    #[error(transparent)]
+    #[diagnostic(code(google_maps::billing))]
+    Billing(#[from] crate::billing::Error),
+
+    /// Error originating from the `oauth` module in the `google_maps` crate.
+    #[cfg(feature = "enable-reqwest")]
+    #[error(transparent)]
+    #[diagnostic(code(google_maps::oauth))]
+    OAuth(#[from] crate::oauth::Error),
+
+    /// Error originating from the `url_signing` module in the `google_maps`
+    /// crate.
+    #[error(transparent)]
+    #[diagnostic(code(google_maps::url_signing))]
+    UrlSigning(#[from] crate::url_signing::Error),
+
 } // enum Error
\ No newline at end of file
@@ -130,3 +130,21 @@ impl From<serde_json::error::Error> for Error {
         Error::SerdeJson(error)
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::traits::ServiceError for Error {
+    /// Returns the `QueryNotBuilt` error of the Google Maps Geocoding API.
+    fn query_not_built() -> Self {
+        Error::QueryNotBuilt
+    } // fn
+    /// Returns the `HttpUnsuccessful` error of the Google Maps Geocoding API.
+    fn http_unsuccessful(status: String) -> Self {
+        Error::HttpUnsuccessful(status)
+    } // fn
+    /// Returns the `SerdeJson` error of the Google Maps Geocoding API.
+    fn serde_json(error: serde_json::Error) -> Self {
+        Error::SerdeJson(error)
+    } // fn
+} // impl
\ No newline at end of file
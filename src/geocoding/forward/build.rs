@@ -10,16 +10,13 @@ impl<'a> ForwardRequest<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> Result<&'a mut ForwardRequest, Error> {
+    pub fn build(&mut self) -> Result<&mut Self, Error> {
 
         // Ensure request has been validated before building the query string:
 
         if !self.validated { return Err(Error::RequestNotValidated) }
 
-        // This section builds the "required parameters" portion of the query
-        // string:
-
-        let mut query = format!("key={}", self.client.key);
+        let mut query = String::new();
 
         // This section builds the "optional parameters" portion of the query
         // string:
@@ -75,6 +72,9 @@ impl<'a> ForwardRequest<'a> {
             query.push_str(&String::from(region))
         }
 
+        // Remove the leading ampersand from the first key/value pair:
+        if query.starts_with('&') { query.remove(0); }
+
         // Set query string in ForwardRequest struct.
         self.query = Some(query);
 
@@ -0,0 +1,103 @@
+//! Contains the `Description` struct. It is a client-independent, serializable
+//! description of a _Geocoding API_ forward request that may be persisted (for
+//! example, in a job queue) and later bound to a client for execution.
+
+use crate::client::ClientRef;
+use crate::geocoding::forward::{component::Component, ForwardRequest};
+use crate::types::{Bounds, Language, Region};
+
+// -----------------------------------------------------------------------------
+//
+/// A client-independent description of a _Geocoding API_ forward request. Use
+/// `ForwardRequest::describe()` to obtain one, store it wherever you like using
+/// `serde`, and then call `bind()` to turn it back into a `ForwardRequest` that may
+/// be executed.
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Description {
+    /// The street address that you want to geocode.
+    pub address: Option<String>,
+    /// The place ID of the place for which you wish to obtain the
+    /// human-readable address.
+    pub place_id: Option<String>,
+    /// The bounding box of the viewport within which to bias geocode results.
+    pub bounds: Option<Bounds>,
+    /// A components filter with elements separated by a pipe (|).
+    pub components: Option<Vec<Component>>,
+    /// The language in which to return results.
+    pub language: Option<Language>,
+    /// The region code, specified as a ccTLD two-character value.
+    pub region: Option<Region>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&ForwardRequest<'_>> for Description {
+    /// Describes a _Geocoding API_ forward request, leaving its client behind.
+    fn from(request: &ForwardRequest<'_>) -> Self {
+        Description {
+            address: request.address.clone(),
+            place_id: request.place_id.clone(),
+            bounds: request.bounds.clone(),
+            components: request.components.clone(),
+            language: request.language.clone(),
+            region: request.region.clone(),
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Description {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Binds this description to a client, producing a `ForwardRequest` that is ready
+    /// to be executed.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct. This may
+    ///   be a reference to a `GoogleMapsClient` or an `Arc<GoogleMapsClient>`.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let description: GeocodingRequestDescription = serde_json::from_str(&job)?;
+    /// let response = description.bind(&google_maps_client).execute().await?;
+    /// ```
+
+    pub fn bind<'a>(self, client: impl Into<ClientRef<'a>>) -> ForwardRequest<'a> {
+        ForwardRequest {
+            client: client.into(),
+            address: self.address,
+            place_id: self.place_id,
+            bounds: self.bounds,
+            components: self.components,
+            language: self.language,
+            region: self.region,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
+            query: None,
+            validated: false,
+        } // struct
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::traits::RequestDescription for Description {
+
+    type Response = crate::geocoding::response::Response;
+
+    /// Binds this description to the client and executes the request.
+    fn execute(
+        self,
+        client: &crate::client::GoogleMapsClient,
+    ) -> futures::future::BoxFuture<'_, Result<Self::Response, crate::error::Error>> {
+        Box::pin(async move { self.bind(client).execute().await })
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -23,7 +23,7 @@ impl<'a> GeocodingForwardRequest<'a> {
     /// This method accepts no arguments.
 
     pub async fn execute(
-        &'a mut self
+        &mut self
     ) -> Result<GeocodingResponse, GoogleMapsError> {
 
         self.validate()?.build()?.get().await
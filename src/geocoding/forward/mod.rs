@@ -5,10 +5,15 @@
 //! geocoding converts a street address to latitude & longitude coordinates.
 
 mod build;
+mod describe;
 #[cfg(feature = "enable-reqwest")]
 mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
 #[cfg(feature = "enable-reqwest")]
 mod get;
+#[cfg(feature = "enable-reqwest")]
+mod into_future;
 mod new;
 mod query_url;
 mod validate;
@@ -18,11 +23,14 @@ mod with_bounds;
 mod with_components;
 mod with_language;
 mod with_region;
+#[cfg(feature = "enable-reqwest")]
+mod with_timeout;
 pub mod component;
+pub mod description;
 
 // -----------------------------------------------------------------------------
 
-use crate::client::GoogleMapsClient;
+use crate::client::ClientRef;
 use crate::geocoding::forward::component::Component;
 use crate::types::{Bounds, Language, Region};
 
@@ -41,7 +49,7 @@ pub struct ForwardRequest<'a> {
 
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
-    client: &'a GoogleMapsClient,
+    client: ClientRef<'a>,
 
     // Optional parameters:
     // --------------------
@@ -87,6 +95,12 @@ pub struct ForwardRequest<'a> {
     /// below.)
     region: Option<Region>,
 
+    /// The deadline for the request, including rate limiter waits and
+    /// retries. It overrides the client's default timeout. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "enable-reqwest")]
+    timeout: Option<std::time::Duration>,
+
     // Internal use only:
     // ------------------
 
@@ -1,4 +1,4 @@
-use crate::{client::GoogleMapsClient, geocoding::forward::ForwardRequest};
+use crate::{client::ClientRef, geocoding::forward::ForwardRequest};
 
 // =============================================================================
 
@@ -13,12 +13,12 @@ impl<'a> ForwardRequest<'a> {
     ///
     /// * `client` ‧ Your application's Google Maps API client struct.
 
-    pub fn new(client: &GoogleMapsClient) -> ForwardRequest {
+    pub fn new(client: impl Into<ClientRef<'a>>) -> Self {
 
         // Instantiate struct and return it to caller:
         ForwardRequest {
             // Required parameters:
-            client,
+            client: client.into(),
             // Optional parameters:
             address: None,
             place_id: None,
@@ -28,6 +28,8 @@ impl<'a> ForwardRequest<'a> {
             region: None,
             // Internal use only:
             validated: false,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
             query: None,
         } // struct
 
@@ -13,7 +13,7 @@ impl<'a> ForwardRequest<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn validate(&mut self) -> Result<&'a mut ForwardRequest, Error> {
+    pub fn validate(&mut self) -> Result<&mut Self, Error> {
         // If a positional request has been set...
         if self.address.is_none() && self.place_id.is_none() && self.components.is_none() {
             return Err(Error::AddressOrComponentsRequired);
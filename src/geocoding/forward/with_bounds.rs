@@ -43,9 +43,9 @@ impl<'a> ForwardRequest<'a> {
     /// ```
 
     pub fn with_bounds(
-        &'a mut self,
+        &mut self,
         bounds: Bounds
-    ) -> &'a mut ForwardRequest {
+    ) -> &mut Self {
         // Set bounds in ForwardRequest struct.
         self.bounds = Some(bounds);
         // Return modified ForwardRequest struct to caller.
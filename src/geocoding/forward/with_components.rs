@@ -64,9 +64,9 @@ impl<'a> ForwardRequest<'a> {
     /// ```
 
     pub fn with_component(
-        &'a mut self,
+        &mut self,
         component: Component
-    ) -> &'a mut ForwardRequest {
+    ) -> &mut Self {
         // Add component to ForwardRequest struct.
         match &mut self.components {
             // If there are no components in the request struct, initialize:
@@ -95,9 +95,9 @@ impl<'a> ForwardRequest<'a> {
     /// ```
 
     pub fn with_components(
-        &'a mut self,
+        &mut self,
         components_slice: &[Component]
-    ) -> &'a mut ForwardRequest {
+    ) -> &mut Self {
         // Add components to ForwardRequest struct.
         match &mut self.components {
             // If there are no filters in the request struct, initialize field:
@@ -45,9 +45,9 @@ impl<'a> ForwardRequest<'a> {
     /// ```
 
     pub fn with_language(
-        &'a mut self,
+        &mut self,
         language: Language
-    ) -> &'a mut ForwardRequest {
+    ) -> &mut Self {
         // Set language in ForwardRequest struct.
         self.language = Some(language);
         // Return modified ForwardRequest struct to caller.
@@ -23,7 +23,7 @@ impl<'a> ForwardRequest<'a> {
     /// )
     /// ```
 
-    pub fn with_place_id(&'a mut self, place_id: &str) -> &'a mut ForwardRequest {
+    pub fn with_place_id(&mut self, place_id: &str) -> &mut Self {
         // Set address in ForwardRequest struct.
         self.place_id = Some(place_id.to_string());
         // Return modified ForwardRequest struct to caller.
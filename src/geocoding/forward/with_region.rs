@@ -37,9 +37,9 @@ impl<'a> ForwardRequest<'a> {
     /// ```
 
     pub fn with_region(
-        &'a mut self,
+        &mut self,
         region: Region
-    ) -> &'a mut ForwardRequest {
+    ) -> &mut Self {
         // Set region in ForwardRequest struct.
         self.region = Some(region);
         // Return modified ForwardRequest struct to caller.
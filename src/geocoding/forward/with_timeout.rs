@@ -0,0 +1,35 @@
+use crate::geocoding::forward::ForwardRequest;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> ForwardRequest<'a> {
+
+    /// Sets a deadline for this geocoding request. If the address has not been
+    /// geocoded in time, `Error::Timeout` is returned instead. The deadline
+    /// includes waiting on the client's rate limits and any retries.
+    ///
+    /// It overrides the client's default timeout, if one was set using
+    /// `GoogleMapsClient::with_timeout`. This is useful when geocoding an
+    /// address that a user is waiting on, so that a slow response can be
+    /// abandoned.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `timeout` ‧ The maximum time that the request may take.
+    ///
+    /// ## Example:
+    ///
+    /// * Give up on the address after 5 seconds:
+    /// ```rust
+    /// .with_timeout(std::time::Duration::from_secs(5))
+    /// ```
+
+    pub fn with_timeout(&mut self, timeout: Duration) -> &mut Self {
+        // Set timeout in ForwardRequest struct.
+        self.timeout = Some(timeout);
+        // Return modified ForwardRequest struct to caller.
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -96,6 +96,7 @@ pub use crate::geocoding::{
     error::Error as GeocodingError,
     forward::{
         component::Component as GeocodingComponent,
+        description::Description as GeocodingRequestDescription,
         ForwardRequest as GeocodingRequest,
     }, // forward
     response::{
@@ -104,7 +105,10 @@ pub use crate::geocoding::{
         Response as GeocodingResponse,
         status::Status as GeocodingStatus,
     }, // response
-    reverse::ReverseRequest as GeocodingReverseRequest,
+    reverse::{
+        description::Description as GeocodingReverseRequestDescription,
+        ReverseRequest as GeocodingReverseRequest,
+    }, // reverse
 }; // geocoding
 
 pub use crate::types::{LocationType, AddressComponent, Geometry};
\ No newline at end of file
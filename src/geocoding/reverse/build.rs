@@ -10,14 +10,13 @@ impl<'a> ReverseRequest<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> &'a mut ReverseRequest {
+    pub fn build(&mut self) -> &mut Self {
 
         // This section builds the "required parameters" portion of the query
         // string:
 
         let mut query = format!(
-            "key={}&latlng={}",
-            self.client.key,
+            "latlng={}",
             String::from(&self.latlng),
         ); // format!
 
@@ -0,0 +1,94 @@
+//! Contains the `Description` struct. It is a client-independent, serializable
+//! description of a _Geocoding API_ reverse request that may be persisted (for
+//! example, in a job queue) and later bound to a client for execution.
+
+use crate::client::ClientRef;
+use crate::geocoding::reverse::ReverseRequest;
+use crate::types::{Language, LatLng, LocationType, PlaceType};
+
+// -----------------------------------------------------------------------------
+//
+/// A client-independent description of a _Geocoding API_ reverse request. Use
+/// `ReverseRequest::describe()` to obtain one, store it wherever you like using
+/// `serde`, and then call `bind()` to turn it back into a `ReverseRequest` that may
+/// be executed.
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Description {
+    /// The latitude and longitude values specifying the location for which
+    /// you wish to obtain the closest, human-readable address.
+    pub latlng: LatLng,
+    /// The language in which to return results.
+    pub language: Option<Language>,
+    /// One or more location types used to filter the results.
+    pub location_types: Option<Vec<LocationType>>,
+    /// One or more address types used to filter the results.
+    pub result_types: Option<Vec<PlaceType>>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&ReverseRequest<'_>> for Description {
+    /// Describes a _Geocoding API_ reverse request, leaving its client behind.
+    fn from(request: &ReverseRequest<'_>) -> Self {
+        Description {
+            latlng: request.latlng.clone(),
+            language: request.language.clone(),
+            location_types: request.location_types.clone(),
+            result_types: request.result_types.clone(),
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Description {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Binds this description to a client, producing a `ReverseRequest` that is ready
+    /// to be executed.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct. This may
+    ///   be a reference to a `GoogleMapsClient` or an `Arc<GoogleMapsClient>`.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let description: GeocodingReverseRequestDescription = serde_json::from_str(&job)?;
+    /// let response = description.bind(&google_maps_client).execute().await?;
+    /// ```
+
+    pub fn bind<'a>(self, client: impl Into<ClientRef<'a>>) -> ReverseRequest<'a> {
+        ReverseRequest {
+            client: client.into(),
+            latlng: self.latlng,
+            language: self.language,
+            location_types: self.location_types,
+            result_types: self.result_types,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
+            query: None,
+        } // struct
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::traits::RequestDescription for Description {
+
+    type Response = crate::geocoding::response::Response;
+
+    /// Binds this description to the client and executes the request.
+    fn execute(
+        self,
+        client: &crate::client::GoogleMapsClient,
+    ) -> futures::future::BoxFuture<'_, Result<Self::Response, crate::error::Error>> {
+        Box::pin(async move { self.bind(client).execute().await })
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -23,7 +23,7 @@ impl<'a> GeocodingReverseRequest<'a> {
     /// This method accepts no arguments.
 
     pub async fn execute(
-        &'a mut self
+        &mut self
     ) -> Result<GeocodingResponse, GoogleMapsError> {
 
         self.build().get().await
@@ -5,19 +5,27 @@
 //! geocoding converts latitude & longitude coordinates to a street address.
 
 mod build;
+mod describe;
 #[cfg(feature = "enable-reqwest")]
 mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
 #[cfg(feature = "enable-reqwest")]
 mod get;
+#[cfg(feature = "enable-reqwest")]
+mod into_future;
 mod new;
 mod query_url;
 mod with_language;
 mod with_location_types;
 mod with_result_types;
+#[cfg(feature = "enable-reqwest")]
+mod with_timeout;
+pub mod description;
 
 // -----------------------------------------------------------------------------
 
-use crate::client::GoogleMapsClient;
+use crate::client::ClientRef;
 use crate::types::{Language, LatLng, LocationType, PlaceType};
 
 // -----------------------------------------------------------------------------
@@ -35,7 +43,7 @@ pub struct ReverseRequest<'a> {
 
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
-    client: &'a GoogleMapsClient,
+    client: ClientRef<'a>,
 
     /// The latitude and longitude values specifying the location for which you
     /// wish to obtain the closest, human-readable address.
@@ -65,6 +73,12 @@ pub struct ReverseRequest<'a> {
     /// the specified address type(s).
     result_types: Option<Vec<PlaceType>>,
 
+    /// The deadline for the request, including rate limiter waits and
+    /// retries. It overrides the client's default timeout. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "enable-reqwest")]
+    timeout: Option<std::time::Duration>,
+
     // Internal use only:
     // ------------------
 
@@ -1,4 +1,4 @@
-use crate::client::GoogleMapsClient;
+use crate::client::ClientRef;
 use crate::geocoding::reverse::ReverseRequest;
 use crate::types::LatLng;
 
@@ -18,19 +18,21 @@ impl<'a> ReverseRequest<'a> {
     /// for which you wish to obtain the closest, human-readable address.
 
     pub fn new(
-        client: &'a GoogleMapsClient,
+        client: impl Into<ClientRef<'a>>,
         latlng: LatLng
-    ) -> ReverseRequest<'a> {
+    ) -> Self {
 
         // Instantiate struct and return it to caller:
         ReverseRequest {
             // Required parameters:
-            client,
+            client: client.into(),
             latlng,
             // Optional parameters:
             language: None,
             location_types: None,
             result_types: None,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
             // Internal use only:
             query: None,
         } // struct
@@ -54,19 +56,21 @@ impl<'a> ReverseRequest<'a> {
 
     #[cfg(feature = "geo")]
     pub fn try_new_coordinate(
-        client: &'a GoogleMapsClient,
+        client: impl Into<ClientRef<'a>>,
         coordinate: &geo_types::Coord,
     ) -> Result<ReverseRequest<'a>, crate::error::Error> {
 
         // Instantiate struct and return it to caller:
         Ok(ReverseRequest {
             // Required parameters:
-            client,
+            client: client.into(),
             latlng: LatLng::try_from(coordinate)?,
             // Optional parameters:
             language: None,
             location_types: None,
             result_types: None,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
             // Internal use only:
             query: None,
         }) // struct
@@ -90,19 +94,21 @@ impl<'a> ReverseRequest<'a> {
 
     #[cfg(feature = "geo")]
     pub fn try_new_point(
-        client: &'a GoogleMapsClient,
+        client: impl Into<ClientRef<'a>>,
         point: &geo_types::Point,
     ) -> Result<ReverseRequest<'a>, crate::error::Error> {
 
         // Instantiate struct and return it to caller:
         Ok(ReverseRequest {
             // Required parameters:
-            client,
+            client: client.into(),
             latlng: LatLng::try_from(point)?,
             // Optional parameters:
             language: None,
             location_types: None,
             result_types: None,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
             // Internal use only:
             query: None,
         }) // struct
@@ -45,9 +45,9 @@ impl<'a> ReverseRequest<'a> {
     /// ```
 
     pub fn with_language(
-        &'a mut self,
+        &mut self,
         language: Language
-    ) -> &'a mut ReverseRequest {
+    ) -> &mut Self {
         // Set language in ReverseRequest struct.
         self.language = Some(language);
         // Return modified ReverseRequest struct to caller.
@@ -59,9 +59,9 @@ impl<'a> ReverseRequest<'a> {
     /// ```
 
     pub fn with_location_type(
-        &'a mut self,
+        &mut self,
         location_type_element: LocationType,
-    ) -> &'a mut ReverseRequest {
+    ) -> &mut Self {
         // Add location type to ReverseRequest struct.
         match &mut self.location_types {
             // If there are no filters in the request struct, initialize field:
@@ -101,9 +101,9 @@ impl<'a> ReverseRequest<'a> {
     /// ```
 
     pub fn with_location_types(
-        &'a mut self,
+        &mut self,
         location_type_slice: &[LocationType],
-    ) -> &'a mut ReverseRequest {
+    ) -> &mut Self {
         // Add location types to ReverseRequest struct.
         match &mut self.location_types {
             // If there are no filters in the request struct, initialize field:
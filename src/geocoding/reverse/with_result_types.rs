@@ -115,9 +115,9 @@ impl<'a> ReverseRequest<'a> {
     /// ```
 
     pub fn with_result_type(
-        &'a mut self,
+        &mut self,
         result_type_element: PlaceType,
-    ) -> &'a mut ReverseRequest {
+    ) -> &mut Self {
         // Add result type to ReverseRequest struct.
         match &mut self.result_types {
             // If there are no filters in the request struct, initialize field:
@@ -156,9 +156,9 @@ impl<'a> ReverseRequest<'a> {
     /// ```
 
     pub fn with_result_types(
-        &'a mut self,
+        &mut self,
         result_type_slice: &[PlaceType],
-    ) -> &'a mut ReverseRequest {
+    ) -> &mut Self {
         // Add location types to ReverseRequest struct.
         match &mut self.result_types {
             // If there are no filters in the request struct, initialize field:
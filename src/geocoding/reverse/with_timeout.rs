@@ -0,0 +1,34 @@
+use crate::geocoding::reverse::ReverseRequest;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> ReverseRequest<'a> {
+
+    /// Sets a deadline for this reverse geocoding request. If the addresses for
+    /// the location have not been returned in time, `Error::Timeout` is
+    /// returned instead. The deadline includes waiting on the client's rate
+    /// limits and any retries.
+    ///
+    /// It overrides the client's default timeout, if one was set using
+    /// `GoogleMapsClient::with_timeout`.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `timeout` ‧ The maximum time that the request may take.
+    ///
+    /// ## Example:
+    ///
+    /// * Give up on the location after 5 seconds:
+    /// ```rust
+    /// .with_timeout(std::time::Duration::from_secs(5))
+    /// ```
+
+    pub fn with_timeout(&mut self, timeout: Duration) -> &mut Self {
+        // Set timeout in ReverseRequest struct.
+        self.timeout = Some(timeout);
+        // Return modified ReverseRequest struct to caller.
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,115 @@
+//! Geolocation API error types and error messages.
+
+// -----------------------------------------------------------------------------
+
+use crate::geolocation::response::reason::Reason;
+use miette::Diagnostic;
+use thiserror::Error;
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced by the Google Maps Geolocation API client.
+
+#[derive(Debug, Diagnostic, Error)]
+#[diagnostic(code(google_maps::geolocation::error), url(docsrs))]
+pub enum Error {
+
+    /// Google Maps Geolocation API server generated an error. See the `Reason`
+    /// enum for more information.
+    GoogleMapsService(Reason, Option<String>),
+
+    /// The HTTP request was unsuccessful.
+    HttpUnsuccessful(String),
+
+    /// API client library attempted to parse a string that contained an invalid
+    /// reason code. See `google_maps\src\geolocation\response\reason.rs` for
+    /// more information.
+    InvalidReason(String),
+
+    /// The request body must be built before the request may be sent to the
+    /// Google Maps Geolocation API server.
+    QueryNotBuilt,
+
+    /// The dependency library Serde JSON generated an error.
+    SerdeJson(serde_json::error::Error),
+
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Error {
+    /// This trait converts the error code into a format that may be presented
+    /// to the user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::GoogleMapsService(reason, error_message) => match error_message {
+                // If the Google Maps Geolocation API server generated an error
+                // message, return that:
+                Some(error_message) => write!(f, "Google Maps Geolocation API service: {error_message}"),
+                // If the Google Maps Geolocation API server did not generate an
+                // error message, return a generic message derived from the
+                // response reason:
+                None => match reason {
+                    Reason::DailyLimitExceeded => write!(f, "Google Maps Geolocation API service: \
+                        Daily limit exceeded. \
+                        You have exceeded your daily limit."),
+                    Reason::KeyInvalid => write!(f, "Google Maps Geolocation API service: \
+                        Key invalid. \
+                        Your API key is not valid for the Geolocation API."),
+                    Reason::UserRateLimitExceeded => write!(f, "Google Maps Geolocation API service: \
+                        User rate limit exceeded. \
+                        You have exceeded the requests per second per user limit \
+                        that you configured in the Google Cloud Console."),
+                    Reason::NotFound => write!(f, "Google Maps Geolocation API service: \
+                        Not found. \
+                        The request was valid, but no results were returned."),
+                    Reason::ParseError => write!(f, "Google Maps Geolocation API service: \
+                        Parse error. \
+                        The request body is not valid JSON."),
+                } // match
+            }, // match
+            Error::HttpUnsuccessful(status) => write!(f,
+                "Google Maps Geolocation API client: \
+                Could not successfully query the Google Cloud Platform service. \
+                The service last responded with a `{status}` status."),
+            Error::InvalidReason(reason_code) => write!(f, "Google Maps Geolocation API client: \
+                `{reason_code}` is not a valid reason code. \
+                Valid codes are `dailyLimitExceeded`, `keyInvalid`, \
+                `userRateLimitExceeded`, `notFound`, and `parseError`."),
+            Error::QueryNotBuilt => write!(f, "Google Maps Geolocation API client library: \
+                The request body must be built before the request may be sent to the Google Cloud Maps Platform. \
+                Ensure the build() method is called before run()."),
+            Error::SerdeJson(error) => write!(f, "Google Maps Geolocation API client in the Serde JSON library: {error}"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<serde_json::error::Error> for Error {
+    /// This trait converts from an Serde JSON (`serde_json::error::Error`)
+    /// error type into a Google Maps Geolocation API error type
+    /// (`google_maps::geolocation::error::Error`) by wrapping it inside. This
+    /// function is required to use the `?` operator.
+    fn from(error: serde_json::error::Error) -> Error {
+        Error::SerdeJson(error)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::traits::ServiceError for Error {
+    /// Returns the `QueryNotBuilt` error of the Google Maps Geolocation API.
+    fn query_not_built() -> Self {
+        Error::QueryNotBuilt
+    } // fn
+    /// Returns the `HttpUnsuccessful` error of the Google Maps Geolocation API.
+    fn http_unsuccessful(status: String) -> Self {
+        Error::HttpUnsuccessful(status)
+    } // fn
+    /// Returns the `SerdeJson` error of the Google Maps Geolocation API.
+    fn serde_json(error: serde_json::Error) -> Self {
+        Error::SerdeJson(error)
+    } // fn
+} // impl
\ No newline at end of file
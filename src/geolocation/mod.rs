@@ -29,5 +29,26 @@
 //! request. If the response is a `404`, you've confirmed that your
 //! `wifiAccessPoints` and `cellTowers` objects could not be geolocated.
 
+pub mod error;
 pub mod request;
-pub mod response;
\ No newline at end of file
+pub mod response;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://www.googleapis.com/geolocation/v1/geolocate";
+
+// -----------------------------------------------------------------------------
+
+pub use crate::geolocation::{
+    error::Error as GeolocationError,
+    request::cell_tower::CellTower,
+    request::description::Description as GeolocationRequestDescription,
+    request::radio_type::RadioType,
+    request::Request as GeolocationRequest,
+    request::wifi_access_point::WiFiAccessPoint,
+    response::error_detail::ErrorDetail as GeolocationErrorDetail,
+    response::error_object::ErrorObject as GeolocationErrorObject,
+    response::error_response::ErrorResponse as GeolocationErrorResponse,
+    response::reason::Reason as GeolocationReason,
+    response::Response as GeolocationResponse,
+}; // crate::geolocation
\ No newline at end of file
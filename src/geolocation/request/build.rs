@@ -0,0 +1,66 @@
+use crate::geolocation::error::Error;
+use crate::geolocation::request::{
+    cell_tower::CellTower,
+    radio_type::RadioType,
+    Request,
+    wifi_access_point::WiFiAccessPoint,
+}; // crate::geolocation::request
+use serde::Serialize;
+
+// -----------------------------------------------------------------------------
+//
+/// The JSON body of a Geolocation API request, as it is sent to Google.
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Body<'b> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    carrier: Option<&'b str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cell_towers: Option<&'b [CellTower]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    consider_ip: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    home_mobile_country_code: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    home_mobile_network_code: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    radio_type: Option<&'b RadioType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    wifi_access_points: Option<&'b [WiFiAccessPoint]>,
+} // struct
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Builds the JSON request body for the Google Maps Geolocation API based
+    /// on the input provided by the client.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn build(&mut self) -> Result<&mut Self, Error> {
+
+        let body = Body {
+            carrier: self.carrier.as_deref(),
+            cell_towers: self.cell_towers.as_deref(),
+            consider_ip: self.consider_ip,
+            home_mobile_country_code: self.home_mobile_country_code,
+            home_mobile_network_code: self.home_mobile_network_code,
+            radio_type: self.radio_type.as_ref(),
+            wifi_access_points: self.wifi_access_points.as_deref(),
+        }; // Body
+
+        // Set request body in Request struct.
+        self.body = Some(serde_json::to_string(&body)?);
+
+        // Return modified Request struct to caller.
+        Ok(self)
+
+    } // fn
+
+} // impl
\ No newline at end of file
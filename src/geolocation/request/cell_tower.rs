@@ -1,6 +1,16 @@
+//! Contains the `CellTower` struct. It describes a cell tower that the device
+//! can detect.
+
 use serde::{Deserialize, Serialize};
 
+// -----------------------------------------------------------------------------
+//
+/// A cell tower that the device can detect. See the [Cell Tower
+/// Objects](https://developers.google.com/maps/documentation/geolocation/intro#cell_tower_object)
+/// section.
+
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct CellTower {
     // Required parameters:
     // --------------------
@@ -10,21 +20,17 @@ pub struct CellTower {
     /// UTRAN/GERAN Cell Identity (UC-Id), which is a 32-bit value concatenating
     /// the Radio Network Controller (RNC) and Cell ID. Specifying only the
     /// 16-bit Cell ID value in WCDMA networks may return inaccurate results.
-    #[serde(alias = "cellId")]
     pub cell_id: u32,
 
     /// The Location Area Code (LAC) for GSM and WCDMA networks. The Network ID
     /// (NID) for CDMA networks.
-    #[serde(alias = "locationAreaCode")]
     pub location_area_code: u16,
 
     /// The cell tower's Mobile Country Code (MCC).
-    #[serde(alias = "mobileCountryCode")]
     pub mobile_country_code: u16,
 
     /// The cell tower's Mobile Network Code. This is the MNC for GSM and WCDMA;
     /// CDMA uses the System ID (SID).
-    #[serde(alias = "mobileNetworkCode")]
     pub mobile_network_code: u16,
 
     // Optional parameters:
@@ -32,14 +38,15 @@ pub struct CellTower {
 
     /// The number of milliseconds since this cell was primary. If age is 0, the
     /// `cellId` represents a current measurement.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub age: Option<u16>,
 
     /// Radio signal strength measured in dBm.
-    #[serde(alias = "signalStrength")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub signal_strength: Option<i16>,
 
     /// The [timing advance](https://en.wikipedia.org/wiki/Timing_advance)
     /// value.
-    #[serde(alias = "timingAdvance")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub timing_advance: Option<u16>,
 } // struct
\ No newline at end of file
@@ -0,0 +1,109 @@
+//! Contains the `Description` struct. It is a client-independent, serializable
+//! description of a _Geolocation API_ request that may be persisted (for
+//! example, in a job queue) and later bound to a client for execution.
+
+use crate::client::ClientRef;
+use crate::geolocation::request::{
+    cell_tower::CellTower,
+    radio_type::RadioType,
+    Request,
+    wifi_access_point::WiFiAccessPoint,
+}; // crate::geolocation::request
+
+// -----------------------------------------------------------------------------
+//
+/// A client-independent description of a _Geolocation API_ request. Use
+/// `Request::describe()` to obtain one, store it wherever you like using
+/// `serde`, and then call `bind()` to turn it back into a `Request` that may
+/// be executed.
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Description {
+    /// The carrier name.
+    pub carrier: Option<String>,
+    /// The cell towers that the device can detect.
+    pub cell_towers: Option<Vec<CellTower>>,
+    /// Whether to fall back to IP geolocation.
+    pub consider_ip: Option<bool>,
+    /// The mobile country code (MCC) for the device's home network.
+    pub home_mobile_country_code: Option<u16>,
+    /// The mobile network code (MNC) for the device's home network.
+    pub home_mobile_network_code: Option<u16>,
+    /// The mobile radio type.
+    pub radio_type: Option<RadioType>,
+    /// The WiFi access points that the device can detect.
+    pub wifi_access_points: Option<Vec<WiFiAccessPoint>>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Request<'_>> for Description {
+    /// Describes a _Geolocation API_ request, leaving its client behind.
+    fn from(request: &Request<'_>) -> Self {
+        Description {
+            carrier: request.carrier.clone(),
+            cell_towers: request.cell_towers.clone(),
+            consider_ip: request.consider_ip,
+            home_mobile_country_code: request.home_mobile_country_code,
+            home_mobile_network_code: request.home_mobile_network_code,
+            radio_type: request.radio_type.clone(),
+            wifi_access_points: request.wifi_access_points.clone(),
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Description {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Binds this description to a client, producing a `Request` that is ready
+    /// to be executed.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct. This may
+    ///   be a reference to a `GoogleMapsClient` or an `Arc<GoogleMapsClient>`.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let description: GeolocationRequestDescription = serde_json::from_str(&job)?;
+    /// let response = description.bind(&google_maps_client).execute().await?;
+    /// ```
+
+    pub fn bind<'a>(self, client: impl Into<ClientRef<'a>>) -> Request<'a> {
+        Request {
+            client: client.into(),
+            carrier: self.carrier,
+            cell_towers: self.cell_towers,
+            consider_ip: self.consider_ip,
+            home_mobile_country_code: self.home_mobile_country_code,
+            home_mobile_network_code: self.home_mobile_network_code,
+            radio_type: self.radio_type,
+            wifi_access_points: self.wifi_access_points,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
+            body: None,
+        } // struct
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::traits::RequestDescription for Description {
+
+    type Response = crate::geolocation::response::Response;
+
+    /// Binds this description to the client and executes the request.
+    fn execute(
+        self,
+        client: &crate::client::GoogleMapsClient,
+    ) -> futures::future::BoxFuture<'_, Result<Self::Response, crate::error::Error>> {
+        Box::pin(async move { self.bind(client).execute().await })
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,53 @@
+use crate::billing::{Charge, Sku};
+use crate::error::Error as GoogleMapsError;
+use crate::geolocation::{
+    SERVICE_URL,
+    request::Request as GeolocationRequest,
+    response::Response as GeolocationResponse,
+}; // crate::geolocation
+use crate::request_rate::api::Api;
+use crate::traits::EndPoint;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+
+impl EndPoint for GeolocationRequest<'_> {
+
+    type Response = GeolocationResponse;
+
+    fn service_url() -> &'static str { SERVICE_URL }
+
+    fn output_format() -> Option<&'static str> { None }
+
+    fn title() -> &'static str { "Geolocation" }
+
+    fn api() -> Api { Api::Geolocation }
+
+    /// The Geolocation API has no query string. The request is ready to be
+    /// sent once its body has been built.
+    fn query_string(&self) -> Option<&str> { self.body.as_ref().map(|_body| "") }
+
+    fn request_body(&self) -> Option<&str> { self.body.as_deref() }
+
+    fn timeout(&self) -> Option<Duration> { self.timeout }
+
+    fn charge(&self) -> Charge { Sku::Geolocation.into() }
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<'a> GeolocationRequest<'a> {
+
+    /// Performs the HTTP post request and returns the response to the caller.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    #[tracing::instrument(level = "debug", name = "Google Maps Geolocation", skip(self))]
+    pub async fn get(&mut self) -> Result<GeolocationResponse, GoogleMapsError> {
+        self.client.get_request(self).await
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -1,80 +1,103 @@
-mod cell_tower;
-mod radio_type;
-mod wifi_access_point;
+//! **Look in this module for documentation on building your _Geolocation API_
+//! query**. In particular, look at the _Request_ struct for examples of the
+//! builder pattern. This module contains the tools (enums, structs, methods)
+//! for building your Google Maps Platform request.
+
+mod build;
+mod describe;
+#[cfg(feature = "enable-reqwest")]
+mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
+#[cfg(feature = "enable-reqwest")]
+mod get;
+#[cfg(feature = "enable-reqwest")]
+mod into_future;
+mod new;
+mod query_url;
+mod with_carrier;
+mod with_cell_towers;
+mod with_consider_ip;
+mod with_home_mobile_network;
+mod with_radio_type;
+#[cfg(feature = "enable-reqwest")]
+mod with_timeout;
+mod with_wifi_access_points;
+pub mod cell_tower;
+pub mod description;
+pub mod radio_type;
+pub mod wifi_access_point;
+
+// -----------------------------------------------------------------------------
+
+use crate::client::ClientRef;
+use crate::geolocation::request::{
+    cell_tower::CellTower,
+    radio_type::RadioType,
+    wifi_access_point::WiFiAccessPoint,
+}; // crate::geolocation::request
+
+// -----------------------------------------------------------------------------
+//
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Geolocation API_ query**. The methods implemented for this struct are
+/// what's used to build your request.
+///
+/// The request is sent to Google as a JSON body. All fields are optional.
+
+#[derive(Debug)]
+pub struct Request<'a> {
 
-use crate::geolocation::request::cell_tower::CellTower;
-use crate::geolocation::request::radio_type::RadioType;
-use crate::geolocation::request::wifi_access_point::WiFiAccessPoint;
-
-/// The request body must be formatted as JSON. All fields are optional.
-
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct Request {
     // Required parameters:
     // --------------------
 
-    /// Your application's API key. This key identifies your application for
-    /// purposes of quota management. Learn how to
-    /// [get a key](https://developers.google.com/maps/documentation/timezone/get-api-key).
-    #[serde(skip)]
-    key: String,
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    client: ClientRef<'a>,
 
     // Optional parameters:
     // --------------------
 
     /// The carrier name.
-    pub carrier: Option<String>,
+    carrier: Option<String>,
 
     /// An array of cell tower objects. See the [Cell Tower
     /// Objects](https://developers.google.com/maps/documentation/geolocation/intro#cell_tower_object)
     /// section.
-    #[serde(rename = "cellTowers")]
-    #[serde(alias = "cellTowers")]
-    pub cell_towers: Option<Vec<CellTower>>,
+    cell_towers: Option<Vec<CellTower>>,
 
     /// Specifies whether to fall back to IP geolocation if wifi and cell tower
     /// signals are not available. Defaults to `true`. Set `considerIp` to
     /// `false` to disable fall back.
-    #[serde(rename = "considerIp")]
-    #[serde(alias = "considerIp")]
-    pub consider_ip: Option<bool>,
+    consider_ip: Option<bool>,
 
     /// The mobile country code (MCC) for the device's home network.
-    #[serde(rename = "homeMobileCountryCode")]
-    #[serde(alias = "homeMobileCountryCode")]
-    pub home_mobile_country_code: Option<u16>,
+    home_mobile_country_code: Option<u16>,
 
     /// The mobile network code (MNC) for the device's home network.
-    #[serde(rename = "homeMobileNetworkCode")]
-    #[serde(alias = "homeMobileNetworkCode")]
-    pub home_mobile_network_code: Option<u16>,
+    home_mobile_network_code: Option<u16>,
 
     /// The mobile radio type. Supported values are `lte`, `gsm`, `cdma`, and
     /// `wcdma`. While this field is optional, it should be included if a value
     /// is available, for more accurate results.
-    #[serde(rename = "radioType")]
-    #[serde(alias = "radioType")]
-    pub radio_type: Option<RadioType>,
+    radio_type: Option<RadioType>,
 
     /// An array of WiFi access point objects. See the [WiFi Access Point
     /// Objects](https://developers.google.com/maps/documentation/geolocation/intro#wifi_access_point_object)
     /// section.
-    #[serde(rename = "wifiAccessPoints")]
-    #[serde(alias = "wifiAccessPoints")]
-    pub wifi_access_points: Option<Vec<WiFiAccessPoint>>,
+    wifi_access_points: Option<Vec<WiFiAccessPoint>>,
+
+    /// The deadline for the request, including rate limiter waits and
+    /// retries. It overrides the client's default timeout. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "enable-reqwest")]
+    timeout: Option<std::time::Duration>,
 
     // Internal use only:
     // ------------------
 
-    /// Request body that is to be submitted to the Google Cloud Maps Platform.
-    #[serde(skip)]
+    /// JSON request body that is to be submitted to the Google Cloud Maps
+    /// Platform.
     body: Option<String>,
 
-    /// Query string that is to be submitted to the Google Cloud Maps Platform.
-    #[serde(skip)]
-    query: Option<String>,
-
-    /// Has the request been validated?
-    #[serde(skip)]
-    validated: bool,
 } // struct
\ No newline at end of file
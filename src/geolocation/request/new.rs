@@ -0,0 +1,48 @@
+use crate::client::ClientRef;
+use crate::geolocation::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Geolocation API query. All of the
+    /// Geolocation API's parameters are optional.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let location = GeolocationRequest::new(&google_maps_client)
+    ///     .with_consider_ip(true)
+    ///     .execute()
+    ///     .await?;
+    /// ```
+
+    pub fn new(client: impl Into<ClientRef<'a>>) -> Self {
+
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client: client.into(),
+            // Optional parameters:
+            carrier: None,
+            cell_towers: None,
+            consider_ip: None,
+            home_mobile_country_code: None,
+            home_mobile_network_code: None,
+            radio_type: None,
+            wifi_access_points: None,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
+            // Internal use only:
+            body: None,
+        } // struct
+
+    } // fn
+
+} // impl
\ No newline at end of file
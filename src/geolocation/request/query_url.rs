@@ -0,0 +1,32 @@
+use crate::geolocation::{SERVICE_URL, request::Request};
+use crate::request_rate::api::Api;
+
+impl Request<'_> {
+
+    /// Returns the URL that the request will be posted to.
+    ///
+    /// ## Description:
+    ///
+    /// The _Geolocation API_ is sent its query as a JSON body rather than as
+    /// a query string, so the URL only carries the credentials. This method
+    /// could be useful for records or logging. It could also be used for
+    /// passing to your HTTP client of choice and executing the HTTP POST
+    /// request yourself.
+    ///
+    /// The API key (or client ID and signature) is appended to the URL. The
+    /// API key and signature are replaced with `REDACTED` unless the client
+    /// has opted-in to revealing secrets using
+    /// `GoogleMapsClient::with_reveal_secrets`. If a base URL override has
+    /// been set using `GoogleMapsClient::with_base_url`, it is used in place
+    /// of Google's.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn query_url(&self) -> String {
+        let service_url = self.client.service_url(&Api::Geolocation, SERVICE_URL);
+        self.client.redact_url(&self.client.authenticated_url(&format!("{service_url}?")))
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -1,19 +1,20 @@
+//! Contains the `RadioType` enum. It specifies the device's mobile radio type.
+
 use serde::{Deserialize, Serialize};
 
-// The mobile radio type.
+// -----------------------------------------------------------------------------
+//
+/// The mobile radio type.
 
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum RadioType {
     /// Code-Division Multiple Access
-    #[serde(alias = "cdma")]
     Cdma,
     /// Global System for Mobile communications
-    #[serde(alias = "gsm")]
     Gsm,
     /// Long-Term Evolution
-    #[serde(alias = "lte")]
     Lte,
     /// Wideband Code-Division Multiple Access
-    #[serde(alias = "wcdma")]
     Wcdma,
 } // enum
\ No newline at end of file
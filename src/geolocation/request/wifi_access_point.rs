@@ -1,31 +1,40 @@
+//! Contains the `WiFiAccessPoint` struct. It describes a WiFi access point that
+//! the device can detect.
+
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+// -----------------------------------------------------------------------------
+//
+/// A WiFi access point that the device can detect. See the [WiFi Access Point
+/// Objects](https://developers.google.com/maps/documentation/geolocation/intro#wifi_access_point_object)
+/// section.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct WiFiAccessPoint {
     // Required parameters:
     // --------------------
 
     /// (required) The MAC address of the WiFi node. It's typically called a
     /// BSS, BSSID or MAC address. Separators must be `:` (colon).
-    #[serde(alias = "macAddress")]
     pub mac_address: String,
 
     // Optional parameters:
     // --------------------
 
     /// The current signal strength measured in dBm.
-    #[serde(alias = "signalStrength")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub signal_strength: Option<i16>,
 
     /// The number of milliseconds since this access point was detected.
-    #[serde(alias = "age")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub age: Option<u16>,
 
     /// The channel over which the client is communicating with the access point.
-    #[serde(alias = "channel")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel: Option<u16>,
 
     /// The current signal to noise ratio measured in dB.
-    #[serde(alias = "signalToNoiseRatio")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub signal_to_noise_ratio: Option<i16>,
 } // struct
\ No newline at end of file
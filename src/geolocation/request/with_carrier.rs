@@ -0,0 +1,26 @@
+use crate::geolocation::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Specifies the device's carrier name.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `carrier` ‧ The carrier name.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_carrier("Vodafone")
+    /// ```
+
+    pub fn with_carrier(&mut self, carrier: &str) -> &mut Self {
+        // Set carrier in Request struct.
+        self.carrier = Some(carrier.to_string());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,68 @@
+use crate::geolocation::request::{cell_tower::CellTower, Request};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Adds a cell tower that the device can detect.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `cell_tower` ‧ A cell tower. See the [Cell Tower
+    ///   Objects](https://developers.google.com/maps/documentation/geolocation/intro#cell_tower_object)
+    ///   section.
+    ///
+    /// ## Examples:
+    ///
+    /// * Multiple cell towers may be stacked together:
+    ///
+    /// ```rust
+    /// .with_cell_tower(first_cell_tower)
+    /// .with_cell_tower(second_cell_tower)
+    /// ```
+
+    pub fn with_cell_tower(
+        &mut self,
+        cell_tower: CellTower
+    ) -> &mut Self {
+        // Add cell tower to Request struct.
+        match &mut self.cell_towers {
+            // If there are no cell towers in the request struct, initialize:
+            None => self.cell_towers = Some(vec![cell_tower]),
+            // If there are already cell towers, append to them:
+            Some(cell_towers) => cell_towers.push(cell_tower),
+        } // match
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+    /// Adds cell towers that the device can detect.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `cell_towers_slice` ‧ Cell towers. See the [Cell Tower
+    ///   Objects](https://developers.google.com/maps/documentation/geolocation/intro#cell_tower_object)
+    ///   section.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// .with_cell_towers(&[first_cell_tower, second_cell_tower])
+    /// ```
+
+    pub fn with_cell_towers(
+        &mut self,
+        cell_towers_slice: &[CellTower]
+    ) -> &mut Self {
+        // Add cell towers to Request struct.
+        match &mut self.cell_towers {
+            // If there are no cell towers in the request struct, initialize:
+            None => self.cell_towers = Some(cell_towers_slice.to_vec()),
+            // If there are already cell towers, append to them:
+            Some(cell_towers) => cell_towers.extend_from_slice(cell_towers_slice),
+        } // match
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,36 @@
+use crate::geolocation::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Specifies whether to fall back to IP geolocation if WiFi and cell tower
+    /// signals are not available.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `consider_ip` ‧ Whether to fall back to IP geolocation. Google
+    ///   defaults to `true`.
+    ///
+    /// ## Description:
+    ///
+    /// Note that the IP address in the request header may not be the IP of the
+    /// device. If the response has a very large `accuracy` radius, the service
+    /// may be geolocating based on the request IP. Set `consider_ip` to
+    /// `false` to confirm that your `wifi_access_points` and `cell_towers`
+    /// could not be geolocated: the API will then return a `notFound` error.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_consider_ip(false)
+    /// ```
+
+    pub fn with_consider_ip(&mut self, consider_ip: bool) -> &mut Self {
+        // Set IP fall-back in Request struct.
+        self.consider_ip = Some(consider_ip);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,36 @@
+use crate::geolocation::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Specifies the device's home mobile network.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `mobile_country_code` ‧ The mobile country code (MCC) for the
+    ///   device's home network.
+    ///
+    /// * `mobile_network_code` ‧ The mobile network code (MNC) for the
+    ///   device's home network.
+    ///
+    /// ## Example:
+    ///
+    /// * Vodafone Germany:
+    /// ```rust
+    /// .with_home_mobile_network(262, 2)
+    /// ```
+
+    pub fn with_home_mobile_network(
+        &mut self,
+        mobile_country_code: u16,
+        mobile_network_code: u16,
+    ) -> &mut Self {
+        // Set home network in Request struct.
+        self.home_mobile_country_code = Some(mobile_country_code);
+        self.home_mobile_network_code = Some(mobile_network_code);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
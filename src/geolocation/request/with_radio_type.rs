@@ -0,0 +1,28 @@
+use crate::geolocation::request::{radio_type::RadioType, Request};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Specifies the device's mobile radio type.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `radio_type` ‧ The mobile radio type. While this field is optional,
+    ///   it should be included if a value is available, for more accurate
+    ///   results.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_radio_type(RadioType::Lte)
+    /// ```
+
+    pub fn with_radio_type(&mut self, radio_type: RadioType) -> &mut Self {
+        // Set radio type in Request struct.
+        self.radio_type = Some(radio_type);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
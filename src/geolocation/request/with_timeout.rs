@@ -0,0 +1,34 @@
+use crate::geolocation::request::Request;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Sets a deadline for this Geolocation API request. If the location has
+    /// not been estimated in time, `Error::Timeout` is returned instead. The
+    /// deadline includes waiting on the client's rate limits and any retries.
+    ///
+    /// It overrides the client's default timeout, if one was set using
+    /// `GoogleMapsClient::with_timeout`. A device that needs a rough location
+    /// quickly may prefer to give up and fall back to its last known position.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `timeout` ‧ The maximum time that the request may take.
+    ///
+    /// ## Example:
+    ///
+    /// * Give up on the location after 5 seconds:
+    /// ```rust
+    /// .with_timeout(std::time::Duration::from_secs(5))
+    /// ```
+
+    pub fn with_timeout(&mut self, timeout: Duration) -> &mut Self {
+        // Set timeout in Request struct.
+        self.timeout = Some(timeout);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
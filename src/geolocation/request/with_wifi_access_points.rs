@@ -0,0 +1,74 @@
+use crate::geolocation::request::{Request, wifi_access_point::WiFiAccessPoint};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Adds a WiFi access point that the device can detect.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `wifi_access_point` ‧ A WiFi access point. See the [WiFi Access Point
+    ///   Objects](https://developers.google.com/maps/documentation/geolocation/intro#wifi_access_point_object)
+    ///   section.
+    ///
+    /// ## Description:
+    ///
+    /// Note that Google requires at least two WiFi access points to return a
+    /// location from WiFi data.
+    ///
+    /// ## Examples:
+    ///
+    /// * Multiple access points may be stacked together:
+    ///
+    /// ```rust
+    /// .with_wifi_access_point(first_access_point)
+    /// .with_wifi_access_point(second_access_point)
+    /// ```
+
+    pub fn with_wifi_access_point(
+        &mut self,
+        wifi_access_point: WiFiAccessPoint
+    ) -> &mut Self {
+        // Add access point to Request struct.
+        match &mut self.wifi_access_points {
+            // If there are no access points in the request struct, initialize:
+            None => self.wifi_access_points = Some(vec![wifi_access_point]),
+            // If there are already access points, append to them:
+            Some(wifi_access_points) => wifi_access_points.push(wifi_access_point),
+        } // match
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+    /// Adds WiFi access points that the device can detect.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `wifi_access_points_slice` ‧ WiFi access points. See the [WiFi Access
+    ///   Point
+    ///   Objects](https://developers.google.com/maps/documentation/geolocation/intro#wifi_access_point_object)
+    ///   section.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// .with_wifi_access_points(&[first_access_point, second_access_point])
+    /// ```
+
+    pub fn with_wifi_access_points(
+        &mut self,
+        wifi_access_points_slice: &[WiFiAccessPoint]
+    ) -> &mut Self {
+        // Add access points to Request struct.
+        match &mut self.wifi_access_points {
+            // If there are no access points in the request struct, initialize:
+            None => self.wifi_access_points = Some(wifi_access_points_slice.to_vec()),
+            // If there are already access points, append to them:
+            Some(wifi_access_points) => wifi_access_points.extend_from_slice(wifi_access_points_slice),
+        } // match
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,23 @@
+//! Contains the `ErrorDetail` struct. It describes one of the errors in a
+//! Geolocation API error response.
+
+use crate::geolocation::response::reason::Reason;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// One of the errors in a Geolocation API error response.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub struct ErrorDetail {
+
+    /// The domain of the error. This is usually `geolocation` or `global`.
+    pub domain: String,
+
+    /// The reason for the error. See the `Reason` enum for more information.
+    pub reason: Reason,
+
+    /// A short description of the error.
+    pub message: String,
+
+} // struct
\ No newline at end of file
@@ -1,11 +1,27 @@
-use crate::geolocation::response::error::Error;
+//! Contains the `ErrorObject` struct. It is the error object of a Geolocation
+//! API error response.
+
+use crate::geolocation::response::error_detail::ErrorDetail;
 use serde::{Deserialize, Serialize};
 
 // -----------------------------------------------------------------------------
+//
+/// In the case of an error, a standard format error response body will be
+/// returned and the HTTP status code will be set to an error status. The
+/// response contains an object with this error object.
 
 #[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct ErrorObject {
-    pub errors: Vec<Error>,
+
+    /// The errors that occurred. Google usually returns one.
+    pub errors: Vec<ErrorDetail>,
+
+    /// This is the same as the
+    /// [HTTP status](https://developer.mozilla.org/en-US/docs/Web/HTTP/Status)
+    /// of the response.
     pub code: u16,
+
+    /// A short description of the error.
     pub message: String,
+
 } // struct
\ No newline at end of file
@@ -0,0 +1,17 @@
+//! In the case of an error, a standard format error response body will be
+//! returned and the HTTP status code will be set to an error status.
+
+use crate::geolocation::response::error_object::ErrorObject;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The body of an unsuccessful Geolocation API response.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub struct ErrorResponse {
+
+    /// The error that occurred.
+    pub error: ErrorObject,
+
+} // struct
\ No newline at end of file
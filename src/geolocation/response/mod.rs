@@ -2,27 +2,68 @@
 //! from the Google Maps Platform. Look in here for more information about the
 //! data returned from Google's server and how to parse it with your program.
 
-mod error_object;
-mod error;
+pub mod error_detail;
+pub mod error_object;
+pub mod error_response;
+pub mod reason;
 
-use crate::{
-    geolocation::response::error_object::ErrorObject,
-    latlng::LatLng,
-}; // use
+// -----------------------------------------------------------------------------
+
+use crate::types::LatLng;
 use serde::{Deserialize, Serialize};
 
+// -----------------------------------------------------------------------------
+//
+/// The response from the Google Maps Geolocation API will be stored in this
+/// structure.
+///
+/// [Responses](https://developers.google.com/maps/documentation/geolocation/requests-geolocation#responses)
+/// ---------------------------------------------------------------------------------------------------------
+/// A successful geolocation request will return a JSON-formatted response
+/// defining a location and radius. Unsuccessful requests are returned as an
+/// HTTP error status, and are reported as a `GeolocationError`.
+
 #[derive(Clone, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct Response {
-    pub location: Option<LatLng>,
-    pub accuracy: Option<f64>,
-    pub error: Option<ErrorObject>,
+
+    /// The user’s estimated latitude and longitude, in degrees.
+    pub location: LatLng,
+
+    /// The accuracy of the estimated location, in meters. This represents the
+    /// radius of a circle around the given `location`.
+    pub accuracy: f64,
+
 } // struct
 
+// -----------------------------------------------------------------------------
+
 impl std::str::FromStr for Response {
     type Err = serde_json::error::Error;
-    /// Parse a Google Maps Geolocation API JSON `String` into a `Response`
+    /// Parse a Google Maps Geolocation API JSON `String` response into a
     /// usable `Response` struct.
     fn from_str(s: &str) -> Result<Self, serde_json::error::Error> {
         serde_json::from_str(s)
-    }
-}
\ No newline at end of file
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::traits::ServiceResponse for Response {
+    type Error = crate::geolocation::error::Error;
+    /// Google's Geolocation API only returns a response body like this one
+    /// when the request was successful.
+    fn into_result(self) -> Result<Self, (String, Self::Error)> {
+        Ok(self)
+    } // fn
+    /// Returns the reason and the corresponding error that is described by the
+    /// body of an unsuccessful Geolocation API response.
+    fn error_from_body(body: &[u8]) -> Option<(String, Self::Error)> {
+        let response: error_response::ErrorResponse = serde_json::from_slice(body).ok()?;
+        let reason = response.error.errors.into_iter().next()?.reason;
+        Some((
+            String::from(&reason),
+            crate::geolocation::error::Error::GoogleMapsService(reason, Some(response.error.message)),
+        ))
+    } // fn
+} // impl
\ No newline at end of file
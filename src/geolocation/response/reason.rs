@@ -0,0 +1,149 @@
+//! The `"reason"` field within each error of a Geolocation API error response
+//! indicates why the request could not be completed.
+
+use crate::geolocation::error::Error;
+use phf::phf_map;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// -----------------------------------------------------------------------------
+
+/// Indicates the reason for an unsuccessful response.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Reason {
+
+    /// You have exceeded your daily limit.
+    ///
+    /// Domain: `usageLimits`. HTTP status code: `403`.
+    DailyLimitExceeded,
+
+    /// Your API key is not valid for the Geolocation API. Please ensure that
+    /// you've included the entire key, and that you've either purchased the
+    /// API or have enabled billing and activated the API to obtain the free
+    /// quota.
+    ///
+    /// Domain: `usageLimits`. HTTP status code: `400`.
+    KeyInvalid,
+
+    /// You have exceeded the requests per second per user limit that you
+    /// configured in the Google Cloud Console. This limit should be configured
+    /// to prevent a single or small group of users from exhausting your daily
+    /// quota, while still allowing reasonable access to all users.
+    ///
+    /// Domain: `usageLimits`. HTTP status code: `403`.
+    UserRateLimitExceeded,
+
+    /// The request was valid, but no results were returned.
+    ///
+    /// Domain: `geolocation`. HTTP status code: `404`.
+    NotFound,
+
+    /// The request body is not valid JSON. Refer to the Request Body section
+    /// for details on each field.
+    ///
+    /// Domain: `global`. HTTP status code: `400`.
+    ParseError,
+
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for Reason {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        match Reason::try_from(string.as_str()) {
+            Ok(variant) => Ok(variant),
+            Err(error) => Err(serde::de::Error::custom(error.to_string()))
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Serialize for Reason {
+    /// Manual implementation of `Serialize` for `serde`.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer {
+        serializer.serialize_str(&String::from(self))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Reason> for String {
+    /// Converts a `Reason` enum to a `String` that contains a reason code.
+    fn from(reason: &Reason) -> String {
+        match reason {
+            Reason::DailyLimitExceeded => String::from("dailyLimitExceeded"),
+            Reason::KeyInvalid => String::from("keyInvalid"),
+            Reason::UserRateLimitExceeded => String::from("userRateLimitExceeded"),
+            Reason::NotFound => String::from("notFound"),
+            Reason::ParseError => String::from("parseError"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static REASONS_BY_CODE: phf::Map<&'static str, Reason> = phf_map! {
+    "dailyLimitExceeded" => Reason::DailyLimitExceeded,
+    "keyInvalid" => Reason::KeyInvalid,
+    "userRateLimitExceeded" => Reason::UserRateLimitExceeded,
+    "notFound" => Reason::NotFound,
+    "parseError" => Reason::ParseError,
+};
+
+impl std::convert::TryFrom<&str> for Reason {
+    // Error definitions are contained in the
+    // `google_maps\src\geolocation\error.rs` module.
+    type Error = crate::geolocation::error::Error;
+    /// Gets a `Reason` enum from a `String` that contains a valid reason
+    /// code.
+    fn try_from(reason_code: &str) -> Result<Self, Self::Error> {
+        REASONS_BY_CODE
+            .get(reason_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidReason(reason_code.to_string()))
+    } // fn
+} // impl
+
+impl std::str::FromStr for Reason {
+    // Error definitions are contained in the
+    // `google_maps\src\geolocation\error.rs` module.
+    type Err = crate::geolocation::error::Error;
+    /// Gets a `Reason` enum from a `String` that contains a valid reason
+    /// code.
+    fn from_str(reason_code: &str) -> Result<Self, Self::Err> {
+        REASONS_BY_CODE
+            .get(reason_code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidReason(reason_code.to_string()))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for Reason {
+    /// Returns a reasonable default variant for the `Reason` enum type.
+    fn default() -> Self {
+        Reason::NotFound
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Reason {
+    /// Formats a `Reason` enum into a string that is presentable to the end
+    /// user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Reason::DailyLimitExceeded => write!(f, "Daily Limit Exceeded"),
+            Reason::KeyInvalid => write!(f, "Key Invalid"),
+            Reason::UserRateLimitExceeded => write!(f, "User Rate Limit Exceeded"),
+            Reason::NotFound => write!(f, "Not Found"),
+            Reason::ParseError => write!(f, "Parse Error"),
+        } // match
+    } // fn
+} // impl
\ No newline at end of file
@@ -245,11 +245,36 @@
 //!
 //! ## [Geolocation API](https://developers.google.com/maps/documentation/geolocation/intro)
 //!
-//! Google's Geolocation API seems to be offline. While the online documentation
-//! is still available and the API appears configurable through the Google Cloud
-//! Platform console, the Geolocation API responds Status code `404 Not Found`
-//! with an empty body to all requests. This API cannot be implemented until the
-//! server responds as expected.
+//! The Geolocation API returns a location and accuracy radius based on
+//! information about cell towers and WiFi nodes that the mobile client can
+//! detect. The request is sent to Google as a JSON body.
+//!
+//! ```rust
+//! use google_maps::prelude::*;
+//!
+//! let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+//!
+//! // Example request:
+//!
+//! let geolocation = google_maps_client.geolocation()
+//!     .with_consider_ip(false)
+//!     .with_wifi_access_points(&[
+//!         WiFiAccessPoint {
+//!             mac_address: "3c:37:86:5d:75:d4".to_string(),
+//!             ..Default::default()
+//!         },
+//!         WiFiAccessPoint {
+//!             mac_address: "30:86:2d:c4:29:d0".to_string(),
+//!             ..Default::default()
+//!         },
+//!     ])
+//!     .execute()
+//!     .await?;
+//!
+//! // Print latitude & longitude coordinates and the accuracy radius:
+//!
+//! println!("{} ± {} meters", geolocation.location, geolocation.accuracy);
+//! ```
 //!
 //! ## Example Client Settings
 //!
@@ -263,6 +288,10 @@
 //!     // For all Google Maps Platform APIs, the client will limit 2 sucessful
 //!     // requests for every 10 seconds:
 //!     .with_rate(Api::All, 2, std::time::Duration::from_secs(10))
+//!     // Failed requests will be retried up to 5 times, waiting at most 32
+//!     // seconds between retries:
+//!     .with_max_retries(5)
+//!     .with_max_delay(std::time::Duration::from_secs(32))
 //!     // Returns the `GoogleMapsClient` struct to the caller. This struct is
 //!     // used to make Google Maps Platform requests.
 //!     .build();
@@ -281,11 +310,20 @@
 //! * distance_matrix
 //! * elevation
 //! * geocoding
+//! * geolocation
 //! * places
 //! * roads
 //! * time_zone
 //! * enable-reqwest (uses [reqwest](https://crates.io/crates/reqwest) for
 //! querying Google Maps API).
+//! * blocking (adds synchronous `execute_blocking` methods to every request.
+//!   Implies `enable-reqwest`).
+//! * service-account (adds the `oauth::ServiceAccount` token provider, which
+//!   exchanges a service account key for OAuth 2.0 access tokens. Implies
+//!   `enable-reqwest`).
+//! * metrics (adds the `metrics::MetricsFacade` metrics hook, which records
+//!   request metrics using the [metrics](https://crates.io/crates/metrics)
+//!   crate. Implies `enable-reqwest`).
 //! * geo (support for [geo](https://crates.io/crates/geo-types) crate types)
 //!
 //! Note: The Places autocomplete APIs have been put in the `autocomplete`
@@ -328,6 +366,7 @@
 //!     "distance_matrix",
 //!     "elevation",
 //!     "geocoding",
+//!     "geolocation",
 //!     "places",
 //!     "roads",
 //!     "time_zone",
@@ -348,12 +387,11 @@
 //! # To do
 //!
 //! 1. Track both _requests_ and request _elements_ for rate limiting.
-//! 2. Make a generic get() function for that can be used by all APIs.
-//! 3. Convert explicit query validation to session types wherever reasonable.
-//! 4. [Places API](https://developers.google.com/places/web-service/intro).
+//! 2. Convert explicit query validation to session types wherever reasonable.
+//! 3. [Places API](https://developers.google.com/places/web-service/intro).
 //! Only partly implemented. If you would like to have any missing pieces
 //! implemented, please contact me.
-//! 5. [Roads API](https://developers.google.com/maps/documentation/roads/intro).
+//! 4. [Roads API](https://developers.google.com/maps/documentation/roads/intro).
 //! Only partly implemented. If you would like to have any missing pieces
 //! implemented, please contact me.
 
@@ -369,6 +407,7 @@ mod serde;
 pub mod error;
 pub mod prelude;
 pub mod types;
+pub mod url_signing;
 
 // Optional Google Maps API modules. Their inclusion can be changed with
 // feature flags:
@@ -381,6 +420,8 @@ pub mod distance_matrix;
 pub mod elevation;
 #[cfg(feature = "geocoding")]
 pub mod geocoding;
+#[cfg(feature = "geolocation")]
+pub mod geolocation;
 #[cfg(feature = "time_zone")]
 pub mod time_zone;
 pub mod places;
@@ -391,6 +432,7 @@ pub mod roads;
 
 pub use crate::{
     client::GoogleMapsClient as ClientSettings,
+    client::ClientRef,
     client::GoogleMapsClient,
     error::Error as GoogleMapsError,
     error::Error,
@@ -407,7 +449,7 @@ pub use crate::types::country::Country;
 pub use crate::types::geometry::Geometry;
 #[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places", feature = "time_zone"))]
 pub use crate::types::language::Language;
-#[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "elevation", feature = "geocoding", feature = "places", feature = "roads", feature = "time_zone"))]
+#[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "elevation", feature = "geocoding", feature = "geolocation", feature = "places", feature = "roads", feature = "time_zone"))]
 pub use crate::types::latlng::LatLng;
 #[cfg(any(feature = "geocoding", feature = "places", feature = "geocoding"))]
 pub use crate::types::location_type::LocationType;
@@ -418,8 +460,25 @@ pub use crate::types::region::Region;
 
 // Optional dependencies:
 
+#[cfg(feature = "blocking")]
+mod blocking;
+#[cfg(feature = "enable-reqwest")]
+pub mod billing;
+#[cfg(feature = "enable-reqwest")]
+pub mod cache;
 #[cfg(feature = "enable-reqwest")]
+pub mod metrics;
+#[cfg(feature = "enable-reqwest")]
+pub mod oauth;
+#[cfg(feature = "enable-reqwest")]
+pub mod rate_store;
 mod request_rate;
+#[cfg(feature = "enable-reqwest")]
+mod retry_policy;
+#[cfg(feature = "enable-reqwest")]
+pub mod traits;
+pub mod transport;
 
+pub use crate::request_rate::api::Api;
 #[cfg(feature = "enable-reqwest")]
-pub use crate::request_rate::api::Api;
\ No newline at end of file
+pub use crate::retry_policy::RetryPolicy;
\ No newline at end of file
@@ -0,0 +1,34 @@
+//! Contains the `AttemptMetrics` struct. It describes a single HTTP request to
+//! Google.
+
+use crate::request_rate::api::Api;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+//
+/// Measurements of a single HTTP request to Google. A request that is retried
+/// makes several attempts.
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttemptMetrics {
+
+    /// The API that the request was made to.
+    pub api: Api,
+
+    /// Which attempt this was. The first attempt is `1`.
+    pub attempt: u32,
+
+    /// How long the HTTP request took, including obtaining an OAuth access
+    /// token.
+    pub latency: Duration,
+
+    /// The HTTP status code that was returned, or `None` if no response was
+    /// received.
+    pub http_status: Option<u16>,
+
+    /// The Google Maps Platform status that was returned, for example `OK`,
+    /// `ZERO_RESULTS` or `OVER_QUERY_LIMIT`. `None` if the response couldn't be
+    /// read.
+    pub google_status: Option<String>,
+
+} // struct
\ No newline at end of file
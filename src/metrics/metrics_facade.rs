@@ -0,0 +1,74 @@
+//! Contains the `MetricsFacade` struct. It records the client's request
+//! metrics using the [metrics](https://crates.io/crates/metrics) crate.
+
+use crate::metrics::{AttemptMetrics, Metrics, RequestMetrics};
+
+// -----------------------------------------------------------------------------
+//
+/// Records the client's request metrics using the
+/// [metrics](https://crates.io/crates/metrics) crate's global recorder. Every
+/// metric has an `api` label. The following metrics are recorded:
+///
+/// * `google_maps_requests_total` ‧ Counter of completed requests, with
+///   `success`, `cache_hit`, `http_status` and `google_status` labels.
+///
+/// * `google_maps_request_duration_seconds` ‧ Histogram of request latency.
+///
+/// * `google_maps_retries_total` ‧ Counter of retries.
+///
+/// * `google_maps_rate_limit_wait_seconds` ‧ Histogram of time spent waiting
+///   under the rate limiter.
+///
+/// * `google_maps_attempts_total` ‧ Counter of HTTP requests to Google,
+///   including retries, with `http_status` and `google_status` labels.
+///
+/// * `google_maps_attempt_duration_seconds` ‧ Histogram of HTTP request
+///   latency.
+///
+/// A missing status is recorded as `none`.
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MetricsFacade;
+
+// -----------------------------------------------------------------------------
+
+impl Metrics for MetricsFacade {
+
+    fn record_attempt(&self, attempt: &AttemptMetrics) {
+        let api = attempt.api.to_string();
+        ::metrics::counter!(
+            "google_maps_attempts_total",
+            "api" => api.clone(),
+            "http_status" => status_label(attempt.http_status),
+            "google_status" => attempt.google_status.clone().unwrap_or_else(|| "none".to_string()),
+        ).increment(1);
+        ::metrics::histogram!("google_maps_attempt_duration_seconds", "api" => api)
+            .record(attempt.latency.as_secs_f64());
+    } // fn
+
+    fn record_request(&self, request: &RequestMetrics) {
+        let api = request.api.to_string();
+        ::metrics::counter!(
+            "google_maps_requests_total",
+            "api" => api.clone(),
+            "success" => request.success.to_string(),
+            "cache_hit" => request.cache_hit.to_string(),
+            "http_status" => status_label(request.http_status),
+            "google_status" => request.google_status.clone().unwrap_or_else(|| "none".to_string()),
+        ).increment(1);
+        ::metrics::histogram!("google_maps_request_duration_seconds", "api" => api.clone())
+            .record(request.latency.as_secs_f64());
+        ::metrics::counter!("google_maps_retries_total", "api" => api.clone())
+            .increment(u64::from(request.retries));
+        ::metrics::histogram!("google_maps_rate_limit_wait_seconds", "api" => api)
+            .record(request.rate_limit_wait.as_secs_f64());
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Formats an HTTP status code as a label value.
+fn status_label(http_status: Option<u16>) -> String {
+    http_status.map_or_else(|| "none".to_string(), |status| status.to_string())
+} // fn
\ No newline at end of file
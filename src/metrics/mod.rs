@@ -0,0 +1,65 @@
+//! Contains the `Metrics` trait and its associated types. A metrics hook may
+//! be set on the `GoogleMapsClient` to observe its requests: how many are
+//! made to each API, how long they take, how often they are retried, how long
+//! they wait under the rate limiter, and which HTTP and Google statuses are
+//! returned. For example, to alert on a spike of `OVER_QUERY_LIMIT` or
+//! `ZERO_RESULTS` responses.
+//!
+//! With the `metrics` feature, the `MetricsFacade` implementation records
+//! these measurements using the [metrics](https://crates.io/crates/metrics)
+//! crate, so that they may be exported to Prometheus, StatsD and so on. Any
+//! other metrics system may be used by implementing the `Metrics` trait.
+
+pub mod attempt_metrics;
+#[cfg(feature = "metrics")]
+pub mod metrics_facade;
+mod request_recorder;
+pub mod request_metrics;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::metrics::{
+    attempt_metrics::AttemptMetrics,
+    request_metrics::RequestMetrics,
+}; // crate::metrics
+#[cfg(feature = "metrics")]
+pub use crate::metrics::metrics_facade::MetricsFacade;
+pub(crate) use crate::metrics::request_recorder::RequestRecorder;
+
+// -----------------------------------------------------------------------------
+//
+/// Implement this trait to record the client's request metrics in a metrics
+/// system of your choice. Both methods do nothing by default, so only the
+/// measurements of interest need to be implemented.
+///
+/// The methods are called from the request pipeline, so they should return
+/// quickly and should not block.
+///
+/// ## Example:
+///
+/// ```rust
+/// use google_maps::metrics::{Metrics, RequestMetrics};
+///
+/// #[derive(Debug)]
+/// struct StatusCounter { /* ... */ }
+///
+/// impl Metrics for StatusCounter {
+///     fn record_request(&self, request: &RequestMetrics) {
+///         if request.google_status.as_deref() == Some("OVER_QUERY_LIMIT") {
+///             /* ... */
+///         }
+///     }
+/// }
+/// ```
+
+pub trait Metrics: std::fmt::Debug + Send + Sync {
+
+    /// Called after each HTTP request to Google, including each retry.
+    fn record_attempt(&self, _attempt: &AttemptMetrics) {}
+
+    /// Called once each request has completed, successfully or not, including
+    /// requests answered from the response cache and requests that were
+    /// refused before being sent.
+    fn record_request(&self, _request: &RequestMetrics) {}
+
+} // trait
\ No newline at end of file
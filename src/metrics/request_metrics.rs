@@ -0,0 +1,42 @@
+//! Contains the `RequestMetrics` struct. It describes a request from start to
+//! finish.
+
+use crate::request_rate::api::Api;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+//
+/// Measurements of a request from start to finish, including any retries.
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RequestMetrics {
+
+    /// The API that the request was made to.
+    pub api: Api,
+
+    /// How long the request took, including waiting under the rate limiter
+    /// and between retries.
+    pub latency: Duration,
+
+    /// How many times the request was retried.
+    pub retries: u32,
+
+    /// How long the request waited under the rate limiter.
+    pub rate_limit_wait: Duration,
+
+    /// Whether the request was answered from the response cache.
+    pub cache_hit: bool,
+
+    /// Whether the request succeeded.
+    pub success: bool,
+
+    /// The HTTP status code of the last attempt, or `None` if no response was
+    /// received.
+    pub http_status: Option<u16>,
+
+    /// The Google Maps Platform status of the last attempt, for example `OK`,
+    /// `ZERO_RESULTS` or `OVER_QUERY_LIMIT`. `None` if the response couldn't be
+    /// read.
+    pub google_status: Option<String>,
+
+} // struct
\ No newline at end of file
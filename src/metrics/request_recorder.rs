@@ -0,0 +1,81 @@
+//! Contains the `RequestRecorder` struct. It collects a request's metrics as
+//! it passes through the client's request pipeline.
+
+use crate::metrics::{AttemptMetrics, Metrics, RequestMetrics};
+use crate::request_rate::api::Api;
+use std::sync::{Mutex, PoisonError};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{Duration, Instant};
+
+// -----------------------------------------------------------------------------
+//
+/// This struct is not for public consumption. It is for internal use only.
+///
+/// Collects a request's metrics and reports them to the client's metrics
+/// hook. If the client has no metrics hook, nothing is recorded.
+
+pub(crate) struct RequestRecorder<'a> {
+    metrics: Option<&'a dyn Metrics>,
+    api: Api,
+    start: Instant,
+    attempts: AtomicU32,
+    statuses: Mutex<(Option<u16>, Option<String>)>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl<'a> RequestRecorder<'a> {
+
+    /// Starts recording a request to the specified API.
+    pub(crate) fn new(metrics: Option<&'a dyn Metrics>, api: Api) -> Self {
+        RequestRecorder {
+            metrics,
+            api,
+            start: Instant::now(),
+            attempts: AtomicU32::new(0),
+            statuses: Mutex::new((None, None)),
+        } // RequestRecorder
+    } // fn
+
+    /// Records an HTTP request to Google that was started at `start`.
+    pub(crate) fn attempt(
+        &self,
+        start: Instant,
+        http_status: Option<u16>,
+        google_status: Option<String>,
+    ) {
+        if let Some(metrics) = self.metrics {
+            let attempt = self.attempts.fetch_add(1, Ordering::Relaxed) + 1;
+            *self.statuses.lock().unwrap_or_else(PoisonError::into_inner) =
+                (http_status, google_status.clone());
+            metrics.record_attempt(&AttemptMetrics {
+                api: self.api.clone(),
+                attempt,
+                latency: start.elapsed(),
+                http_status,
+                google_status,
+            }); // record_attempt
+        } // if
+    } // fn
+
+    /// Records the completed request.
+    pub(crate) fn finish(&self, rate_limit_wait: Duration, cache_hit: bool, success: bool) {
+        if let Some(metrics) = self.metrics {
+            let (http_status, google_status) = self.statuses
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .clone();
+            metrics.record_request(&RequestMetrics {
+                api: self.api.clone(),
+                latency: self.start.elapsed(),
+                retries: self.attempts.load(Ordering::Relaxed).saturating_sub(1),
+                rate_limit_wait,
+                cache_hit,
+                success,
+                http_status,
+                google_status,
+            }); // record_request
+        } // if
+    } // fn
+
+} // impl
\ No newline at end of file
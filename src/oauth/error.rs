@@ -0,0 +1,51 @@
+//! OAuth 2.0 error types and error messages.
+
+// -----------------------------------------------------------------------------
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced while obtaining an OAuth 2.0 access token.
+
+#[derive(Debug, Diagnostic, Error)]
+#[diagnostic(url(docsrs))]
+pub enum Error {
+
+    /// The request to the token endpoint could not be sent.
+    #[error("Google Maps API client could not reach the token endpoint: {0}")]
+    #[diagnostic(code(google_maps::oauth::transport))]
+    Transport(#[from] crate::transport::Error),
+
+    /// The token endpoint responded with an unsuccessful HTTP status code. The
+    /// HTTP status code and response body are returned.
+    #[error("token endpoint returned HTTP status code `{0}`: {1}")]
+    #[diagnostic(code(google_maps::oauth::token_endpoint))]
+    TokenEndpoint(u16, String),
+
+    /// The token endpoint's response could not be deserialized.
+    #[error("token endpoint response could not be parsed: {0}")]
+    #[diagnostic(code(google_maps::oauth::invalid_token_response))]
+    InvalidTokenResponse(serde_json::Error),
+
+    /// The service account key could not be parsed. For example, it is not
+    /// valid JSON or is missing a field.
+    #[cfg(feature = "service-account")]
+    #[error("service account key is not valid: {0}")]
+    #[diagnostic(code(google_maps::oauth::invalid_service_account))]
+    InvalidServiceAccount(String),
+
+    /// The JWT assertion could not be created. For example, the service
+    /// account's private key is not a valid RSA key in PEM format.
+    #[cfg(feature = "service-account")]
+    #[error("JWT assertion could not be created: {0}")]
+    #[diagnostic(code(google_maps::oauth::jwt))]
+    Jwt(#[from] jsonwebtoken::errors::Error),
+
+    /// A user-supplied token provider generated an error.
+    #[error("token provider failed: {0}")]
+    #[diagnostic(code(google_maps::oauth::custom))]
+    Custom(Box<dyn std::error::Error + Send + Sync>),
+
+} // enum Error
\ No newline at end of file
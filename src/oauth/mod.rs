@@ -0,0 +1,79 @@
+//! Contains the `TokenProvider` trait and its implementations. OAuth 2.0
+//! access tokens are accepted or required by some Google Maps Platform APIs
+//! (for example, the Routes API, Places API (New), Address Validation API and
+//! Route Optimization API) as an alternative to API keys.
+//!
+//! When a token provider has been set on the `GoogleMapsClient` using
+//! `with_bearer_token` or `with_token_provider`, an access token is requested
+//! from the provider before each HTTP request is sent and is passed to the
+//! server in an `Authorization: Bearer ...` header.
+//!
+//! * `StaticToken` ‧ An access token that was obtained elsewhere, for example
+//!   from `gcloud auth print-access-token`. It is never refreshed.
+//!
+//! * `ServiceAccount` ‧ Exchanges a signed JWT for an access token using a
+//!   Google Cloud service account key, and refreshes the token before it
+//!   expires. Requires the `service-account` feature.
+//!
+//! Any other source of access tokens may be used by implementing the
+//! `TokenProvider` trait.
+
+pub mod error;
+#[cfg(feature = "service-account")]
+pub mod service_account;
+pub mod static_token;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::oauth::{
+    error::Error,
+    static_token::StaticToken,
+}; // crate::oauth
+
+#[cfg(feature = "service-account")]
+pub use crate::oauth::service_account::ServiceAccount;
+
+// -----------------------------------------------------------------------------
+
+use futures::future::BoxFuture;
+
+// -----------------------------------------------------------------------------
+//
+/// Implement this trait to supply OAuth 2.0 access tokens to the Google Maps
+/// client from a source of your choice, such as a secrets manager or the
+/// metadata server of a Google Cloud runtime.
+///
+/// The provider is asked for a token before every HTTP request, including
+/// retries. Providers are responsible for caching their tokens and refreshing
+/// them before they expire.
+///
+/// ## Example:
+///
+/// ```rust
+/// use futures::future::BoxFuture;
+/// use google_maps::oauth::{Error, TokenProvider};
+///
+/// #[derive(Debug)]
+/// struct EnvironmentToken;
+///
+/// impl TokenProvider for EnvironmentToken {
+///     fn access_token(&self) -> BoxFuture<'_, Result<String, Error>> {
+///         Box::pin(async move {
+///             std::env::var("GOOGLE_ACCESS_TOKEN")
+///                 .map_err(|error| Error::Custom(Box::new(error)))
+///         })
+///     }
+/// }
+///
+/// let google_maps_client = GoogleMapsClient::new("")
+///     .with_token_provider(EnvironmentToken)
+///     .build();
+/// ```
+
+pub trait TokenProvider: std::fmt::Debug + Send + Sync {
+
+    /// Returns a valid OAuth 2.0 access token. The token is sent to the server
+    /// in an `Authorization: Bearer ...` header.
+    fn access_token(&self) -> BoxFuture<'_, Result<String, Error>>;
+
+} // trait
\ No newline at end of file
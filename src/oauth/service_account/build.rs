@@ -0,0 +1,19 @@
+use crate::oauth::ServiceAccount;
+
+// =============================================================================
+
+impl ServiceAccount {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Completes the builder pattern into a final structure.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn build(&self) -> ServiceAccount {
+        self.clone()
+    } // fn
+
+} // impl
\ No newline at end of file
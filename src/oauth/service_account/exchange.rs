@@ -0,0 +1,202 @@
+use crate::oauth::{Error, ServiceAccount};
+use crate::transport::HttpRequest;
+use jsonwebtoken::{Algorithm, Header};
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// -----------------------------------------------------------------------------
+
+/// How long the JWT assertion is valid for. Google accepts a maximum of one
+/// hour.
+const ASSERTION_LIFETIME: Duration = Duration::from_secs(3_600);
+
+/// The grant type for exchanging a JWT assertion for an access token,
+/// URL-encoded.
+const GRANT_TYPE: &str = "urn%3Aietf%3Aparams%3Aoauth%3Agrant-type%3Ajwt-bearer";
+
+// -----------------------------------------------------------------------------
+
+/// The claims of the JWT assertion that is sent to the token endpoint.
+
+#[derive(Serialize)]
+struct Claims<'a> {
+    iss: &'a str,
+    scope: String,
+    aud: &'a str,
+    iat: u64,
+    exp: u64,
+} // struct
+
+/// The token endpoint's response to a successful exchange.
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: u64,
+} // struct
+
+// =============================================================================
+
+impl ServiceAccount {
+
+    // -------------------------------------------------------------------------
+    //
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// ## Description
+    ///
+    /// Signs a JWT assertion with the service account's private key and
+    /// exchanges it for an access token at the token endpoint. Returns the
+    /// access token and the time at which it expires.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub(crate) async fn exchange(&self) -> Result<(String, SystemTime), Error> {
+
+        // Build and sign the JWT assertion:
+        let now = SystemTime::now();
+        let issued_at = now.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        let claims = Claims {
+            iss: &self.client_email,
+            scope: self.scopes.join(" "),
+            aud: &self.token_uri,
+            iat: issued_at,
+            exp: issued_at + ASSERTION_LIFETIME.as_secs(),
+        }; // Claims
+        let mut header = Header::new(Algorithm::RS256);
+        header.kid = self.private_key_id.clone();
+        let assertion = jsonwebtoken::encode(&header, &claims, &self.private_key)?;
+
+        // Exchange the assertion for an access token:
+        tracing::debug!(
+            "Requesting OAuth access token for `{}` from `{}`",
+            self.client_email,
+            self.token_uri,
+        ); // debug!
+        let body = format!("grant_type={GRANT_TYPE}&assertion={assertion}");
+        let request = HttpRequest::post(&self.token_uri, body.into_bytes())
+            .with_header("Content-Type", "application/x-www-form-urlencoded");
+        let response = self.transport.execute(request).await?;
+
+        if !response.is_success() {
+            return Err(Error::TokenEndpoint(
+                response.status,
+                String::from_utf8_lossy(&response.body).to_string(),
+            )) // TokenEndpoint
+        } // if
+
+        let token_response: TokenResponse = serde_json::from_slice(&response.body)
+            .map_err(Error::InvalidTokenResponse)?;
+
+        Ok((
+            token_response.access_token,
+            now + Duration::from_secs(token_response.expires_in),
+        ))
+
+    } // fn
+
+} // impl
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use crate::oauth::{Error, ServiceAccount};
+    use crate::transport::{Method, ScriptedTransport};
+    use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+    use serde::Deserialize;
+    use std::time::{Duration, SystemTime};
+
+    /// A key that was generated for these tests. It isn't valid for any Google
+    /// Cloud project.
+    const SERVICE_ACCOUNT: &str = include_str!("../../../tests/fixtures/oauth/service_account.json");
+
+    const PUBLIC_KEY: &str = include_str!("../../../tests/fixtures/oauth/service_account.pub.pem");
+
+    #[derive(Debug, Deserialize)]
+    struct Claims {
+        iss: String,
+        scope: String,
+        aud: String,
+        iat: u64,
+        exp: u64,
+    } // struct
+
+    fn service_account(transport: &ScriptedTransport) -> ServiceAccount {
+        ServiceAccount::from_json(SERVICE_ACCOUNT)
+            .unwrap()
+            .with_scopes(&["https://www.googleapis.com/auth/maps-platform.places"])
+            .with_transport(transport.clone())
+            .build()
+    } // fn
+
+    #[tokio::test]
+    async fn exchange_posts_a_signed_assertion_to_the_token_endpoint() {
+        let transport = ScriptedTransport::responses([
+            (200, r#"{ "access_token": "ya29.ACCESS_TOKEN", "expires_in": 3599, "token_type": "Bearer" }"#),
+        ]);
+        let service_account = service_account(&transport);
+
+        let before = SystemTime::now();
+        let (access_token, expires_at) = service_account.exchange().await.unwrap();
+
+        assert_eq!(access_token, "ya29.ACCESS_TOKEN");
+        assert!(expires_at >= before + Duration::from_secs(3_599));
+        assert!(expires_at <= SystemTime::now() + Duration::from_secs(3_599));
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        let request = &requests[0];
+        assert_eq!(request.method, Method::Post);
+        assert_eq!(request.url, "https://oauth2.googleapis.com/token");
+        assert!(request.headers.contains(&(
+            String::from("Content-Type"),
+            String::from("application/x-www-form-urlencoded"),
+        )));
+
+        // The body is a form with the grant type and the JWT assertion, which
+        // must be signed with the service account's private key:
+        let body = String::from_utf8(request.body.clone().unwrap()).unwrap();
+        let assertion = body
+            .strip_prefix("grant_type=urn%3Aietf%3Aparams%3Aoauth%3Agrant-type%3Ajwt-bearer&assertion=")
+            .unwrap();
+        let mut validation = Validation::new(Algorithm::RS256);
+        validation.set_audience(&["https://oauth2.googleapis.com/token"]);
+        let token = jsonwebtoken::decode::<Claims>(
+            assertion,
+            &DecodingKey::from_rsa_pem(PUBLIC_KEY.as_bytes()).unwrap(),
+            &validation,
+        ).unwrap();
+        assert_eq!(token.header.kid.as_deref(), Some("0123456789abcdef0123456789abcdef01234567"));
+        assert_eq!(token.claims.iss, "maps-client@google-maps-tests.iam.gserviceaccount.com");
+        assert_eq!(token.claims.scope, "https://www.googleapis.com/auth/maps-platform.places");
+        assert_eq!(token.claims.aud, "https://oauth2.googleapis.com/token");
+        assert_eq!(token.claims.exp - token.claims.iat, 3_600);
+    } // fn
+
+    #[tokio::test]
+    async fn token_endpoint_errors_are_returned() {
+        let transport = ScriptedTransport::responses([
+            (400, r#"{ "error": "invalid_grant", "error_description": "Invalid JWT Signature." }"#),
+        ]);
+
+        let error = service_account(&transport).exchange().await.unwrap_err();
+
+        assert!(
+            matches!(&error, Error::TokenEndpoint(400, body) if body.contains("invalid_grant")),
+            "unexpected error: {error:?}",
+        );
+    } // fn
+
+    #[tokio::test]
+    async fn invalid_token_responses_are_returned() {
+        let transport = ScriptedTransport::responses([(200, r#"{ "token_type": "Bearer" }"#)]);
+
+        let error = service_account(&transport).exchange().await.unwrap_err();
+
+        assert!(matches!(error, Error::InvalidTokenResponse(_)), "unexpected error: {error:?}");
+    } // fn
+
+} // mod
\ No newline at end of file
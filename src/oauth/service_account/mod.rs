@@ -0,0 +1,100 @@
+//! Contains the `ServiceAccount` struct and its associated traits. It
+//! exchanges a JWT that is signed with a Google Cloud service account's
+//! private key for an OAuth 2.0 access token.
+//!
+//! Learn more about [using OAuth 2.0 for server to server
+//! applications](https://developers.google.com/identity/protocols/oauth2/service-account).
+
+mod build;
+mod exchange;
+mod new;
+mod token_provider;
+mod with_scopes;
+mod with_token_uri;
+mod with_transport;
+
+// -----------------------------------------------------------------------------
+
+use crate::transport::Transport;
+use futures::lock::Mutex;
+use jsonwebtoken::EncodingKey;
+use std::{sync::Arc, time::SystemTime};
+
+// -----------------------------------------------------------------------------
+
+/// The token endpoint that is used when the service account key does not
+/// specify one.
+const DEFAULT_TOKEN_URI: &str = "https://oauth2.googleapis.com/token";
+
+/// The scope that is requested when no scopes have been specified. It grants
+/// access to all Google Cloud APIs that are enabled for the project.
+const DEFAULT_SCOPE: &str = "https://www.googleapis.com/auth/cloud-platform";
+
+// -----------------------------------------------------------------------------
+//
+/// A token provider that obtains OAuth 2.0 access tokens for a Google Cloud
+/// service account. A JWT assertion is signed with the service account's
+/// private key and exchanged for an access token at the token endpoint. The
+/// access token is cached and is refreshed shortly before it expires.
+///
+/// The token endpoint may be changed using `with_token_uri`, and the HTTP
+/// transport used to reach it may be changed using `with_transport`. This
+/// allows the exchange to be tested against a local mock server.
+///
+/// How to use this structure's methods in a builder pattern:
+///
+/// ```rust
+/// let service_account = ServiceAccount::from_json(&std::fs::read_to_string("key.json")?)?
+///     .with_scopes(&["https://www.googleapis.com/auth/cloud-platform"])
+///     .build();
+///
+/// let google_maps_client = GoogleMapsClient::new("")
+///     .with_token_provider(service_account)
+///     .build();
+/// ```
+
+#[derive(Clone)]
+pub struct ServiceAccount {
+
+    /// The email address of the service account. For example,
+    /// `maps@your-project.iam.gserviceaccount.com`.
+    pub client_email: String,
+
+    /// The identifier of the service account's private key. It is sent in the
+    /// JWT header so that Google can select the matching public key.
+    pub private_key_id: Option<String>,
+
+    /// The service account's private key, used to sign JWT assertions.
+    private_key: EncodingKey,
+
+    /// The token endpoint that the JWT assertion is exchanged at.
+    pub token_uri: String,
+
+    /// The OAuth 2.0 scopes that are requested for the access token.
+    pub scopes: Vec<String>,
+
+    /// The HTTP transport that is used to reach the token endpoint.
+    pub transport: Arc<dyn Transport>,
+
+    /// The most recently obtained access token, and when it expires. It is
+    /// shared between clones of the service account.
+    cached_token: Arc<Mutex<Option<(String, SystemTime)>>>,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for ServiceAccount {
+    /// Formats the service account for debugging. The private key and cached
+    /// access token are not shown.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ServiceAccount")
+            .field("client_email", &self.client_email)
+            .field("private_key_id", &self.private_key_id)
+            .field("private_key", &crate::client::REDACTED)
+            .field("token_uri", &self.token_uri)
+            .field("scopes", &self.scopes)
+            .field("transport", &self.transport)
+            .finish()
+    } // fn
+} // impl
\ No newline at end of file
@@ -0,0 +1,100 @@
+use crate::oauth::{Error, ServiceAccount, TokenProvider};
+use futures::future::BoxFuture;
+use std::time::{Duration, SystemTime};
+
+// -----------------------------------------------------------------------------
+
+/// How long before the access token expires that it is refreshed. This allows
+/// for clock skew and for requests that are in flight when the token expires.
+const REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+// -----------------------------------------------------------------------------
+
+impl TokenProvider for ServiceAccount {
+    /// Returns the cached access token, or exchanges a new JWT assertion for
+    /// an access token if the cached token has expired or is about to expire.
+    fn access_token(&self) -> BoxFuture<'_, Result<String, Error>> {
+        Box::pin(async move {
+
+            // The lock is held during the exchange so that concurrent requests
+            // share a single refresh, rather than each requesting a token:
+            let mut cached_token = self.cached_token.lock().await;
+
+            if let Some((access_token, expires_at)) = cached_token.as_ref() {
+                if SystemTime::now() + REFRESH_MARGIN < *expires_at {
+                    return Ok(access_token.clone())
+                } // if
+            } // if
+
+            let (access_token, expires_at) = self.exchange().await?;
+            *cached_token = Some((access_token.clone(), expires_at));
+            Ok(access_token)
+
+        }) // Box::pin
+    } // fn
+} // impl
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use crate::oauth::{ServiceAccount, TokenProvider};
+    use crate::transport::ScriptedTransport;
+
+    const SERVICE_ACCOUNT: &str = include_str!("../../../tests/fixtures/oauth/service_account.json");
+
+    fn service_account(transport: &ScriptedTransport) -> ServiceAccount {
+        ServiceAccount::from_json(SERVICE_ACCOUNT)
+            .unwrap()
+            .with_transport(transport.clone())
+            .build()
+    } // fn
+
+    #[tokio::test]
+    async fn access_token_is_cached_until_it_nears_expiry() {
+        let transport = ScriptedTransport::responses([
+            (200, r#"{ "access_token": "FIRST", "expires_in": 3599 }"#),
+            (200, r#"{ "access_token": "SECOND", "expires_in": 3599 }"#),
+        ]);
+        let service_account = service_account(&transport);
+
+        assert_eq!(service_account.access_token().await.unwrap(), "FIRST");
+        assert_eq!(service_account.access_token().await.unwrap(), "FIRST");
+        // Clones of the service account share the cached token:
+        assert_eq!(service_account.clone().access_token().await.unwrap(), "FIRST");
+
+        assert_eq!(transport.requests().len(), 1);
+    } // fn
+
+    #[tokio::test]
+    async fn access_token_is_refreshed_before_it_expires() {
+        // The first token expires within the refresh margin, so it's replaced
+        // the next time a token is needed:
+        let transport = ScriptedTransport::responses([
+            (200, r#"{ "access_token": "FIRST", "expires_in": 30 }"#),
+            (200, r#"{ "access_token": "SECOND", "expires_in": 3599 }"#),
+        ]);
+        let service_account = service_account(&transport);
+
+        assert_eq!(service_account.access_token().await.unwrap(), "FIRST");
+        assert_eq!(service_account.access_token().await.unwrap(), "SECOND");
+        assert_eq!(service_account.access_token().await.unwrap(), "SECOND");
+
+        assert_eq!(transport.requests().len(), 2);
+    } // fn
+
+    #[tokio::test]
+    async fn failed_refresh_is_retried_on_the_next_request() {
+        let transport = ScriptedTransport::responses([
+            (500, "Internal Server Error"),
+            (200, r#"{ "access_token": "FIRST", "expires_in": 3599 }"#),
+        ]);
+        let service_account = service_account(&transport);
+
+        assert!(service_account.access_token().await.is_err());
+        assert_eq!(service_account.access_token().await.unwrap(), "FIRST");
+
+        assert_eq!(transport.requests().len(), 2);
+    } // fn
+
+} // mod
\ No newline at end of file
@@ -0,0 +1,33 @@
+use crate::oauth::ServiceAccount;
+
+// =============================================================================
+
+impl ServiceAccount {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the OAuth 2.0 scopes that are requested for the access token. By
+    /// default, the `https://www.googleapis.com/auth/cloud-platform` scope is
+    /// requested.
+    ///
+    /// ## Arguments
+    ///
+    /// * `scopes` ‧ The scopes to request.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let service_account = ServiceAccount::from_json(&key_json)?
+    ///     .with_scopes(&["https://www.googleapis.com/auth/cloud-platform"])
+    ///     .build();
+    /// ```
+
+    pub fn with_scopes(
+        &mut self,
+        scopes: &[&str],
+    ) -> &mut ServiceAccount {
+        self.scopes = scopes.iter().map(|scope| scope.to_string()).collect();
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
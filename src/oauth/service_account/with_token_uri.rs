@@ -0,0 +1,37 @@
+use crate::oauth::ServiceAccount;
+
+// =============================================================================
+
+impl ServiceAccount {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the token endpoint that the JWT assertion is exchanged at. By
+    /// default, the `token_uri` of the service account key file is used, or
+    /// `https://oauth2.googleapis.com/token` if there is none.
+    ///
+    /// This is useful for exchanging tokens with a local mock server in
+    /// integration tests.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token_uri` ‧ The URL of the token endpoint. For example,
+    ///   `http://localhost:8080/token`.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let service_account = ServiceAccount::from_json(&key_json)?
+    ///     .with_token_uri("http://localhost:8080/token")
+    ///     .build();
+    /// ```
+
+    pub fn with_token_uri(
+        &mut self,
+        token_uri: &str,
+    ) -> &mut ServiceAccount {
+        self.token_uri = token_uri.to_string();
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
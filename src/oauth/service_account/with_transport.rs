@@ -0,0 +1,36 @@
+use crate::oauth::ServiceAccount;
+use crate::transport::Transport;
+use std::sync::Arc;
+
+// =============================================================================
+
+impl ServiceAccount {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Passes a user supplied HTTP transport for the service account to use
+    /// when it reaches the token endpoint. By default, a `ReqwestTransport` is
+    /// used.
+    ///
+    /// ## Arguments
+    ///
+    /// * `transport` ‧ Any type that implements the
+    ///   `google_maps::transport::Transport` trait.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let service_account = ServiceAccount::from_json(&key_json)?
+    ///     .with_transport(MyHyperTransport::new())
+    ///     .build();
+    /// ```
+
+    pub fn with_transport(
+        &mut self,
+        transport: impl Transport + 'static,
+    ) -> &mut ServiceAccount {
+        self.transport = Arc::new(transport);
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
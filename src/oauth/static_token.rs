@@ -0,0 +1,46 @@
+//! Contains the `StaticToken` struct and its associated traits. It supplies
+//! an access token that was obtained elsewhere.
+
+use crate::oauth::{Error, TokenProvider};
+use futures::future::BoxFuture;
+
+// -----------------------------------------------------------------------------
+//
+/// A token provider that always returns the same OAuth 2.0 access token. The
+/// token is never refreshed, so this is best suited to short-lived programs
+/// and scripts. The token is replaced with `REDACTED` in `Debug` output.
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct StaticToken(String);
+
+// -----------------------------------------------------------------------------
+
+impl StaticToken {
+
+    /// Initializes a token provider that returns the specified access token.
+    pub fn new(access_token: &str) -> StaticToken {
+        StaticToken(access_token.to_string())
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl TokenProvider for StaticToken {
+    /// Returns the access token.
+    fn access_token(&self) -> BoxFuture<'_, Result<String, Error>> {
+        Box::pin(async move { Ok(self.0.clone()) })
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for StaticToken {
+    /// Formats the token provider for debugging. The access token is replaced
+    /// with `REDACTED`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("StaticToken")
+            .field(&crate::client::REDACTED)
+            .finish()
+    } // fn
+} // impl
\ No newline at end of file
@@ -39,9 +39,31 @@ pub enum Error {
     /// sort order type code. See
     /// `google_maps\src\places\place_details\sort_order.rs` for more information.
     InvalidSortOrderCode(String),
+    /// API client library attempted to parse a string that contained an invalid
+    /// rank by code. See
+    /// `google_maps\src\places\place_search\nearby_search\request\rank_by.rs`
+    /// for more information.
+    InvalidRankByCode(String),
+    /// API client library attempted to parse a string that contained an invalid
+    /// input type code. See
+    /// `google_maps\src\places\place_search\find_place\request\input_type.rs`
+    /// for more information.
+    InvalidInputTypeCode(String),
+    /// A Nearby Search request that ranks results by distance must have a
+    /// keyword, a type, or both.
+    KeywordOrTypeRequired,
+    /// A Place Photo request must have a maximum height, a maximum width, or
+    /// both.
+    MaxHeightOrMaxWidthRequired,
     /// The query string must be built before the request may be sent to the
     /// Google Maps Places API server.
     QueryNotBuilt,
+    /// A Nearby Search request that doesn't rank results by distance must have
+    /// a radius.
+    RadiusRequired,
+    /// A Nearby Search request that ranks results by distance must not have a
+    /// radius.
+    RadiusWithRankByDistance,
     /// The dependency library Reqwest generated an error.
     #[cfg(feature = "enable-reqwest")]
     Reqwest(reqwest::Error),
@@ -112,9 +134,27 @@ impl std::fmt::Display for Error {
             Error::InvalidSortOrderCode(sort_order_code) => write!(f, "Google Maps Places API client: \
                 `{sort_order_code}` is not a valid sort order code. \
                 Valid codes are `most_relevant` and `newest`."),
+            Error::InvalidRankByCode(rank_by_code) => write!(f, "Google Maps Places API client: \
+                `{rank_by_code}` is not a valid rank by code. \
+                Valid codes are `prominence` and `distance`."),
+            Error::InvalidInputTypeCode(input_type_code) => write!(f, "Google Maps Places API client: \
+                `{input_type_code}` is not a valid input type code. \
+                Valid codes are `textquery` and `phonenumber`."),
+            Error::KeywordOrTypeRequired => write!(f, "Google Maps Places API client: \
+                A Nearby Search request that ranks results by distance requires a keyword, a type, or both. \
+                Try again after calling with_keyword() or with_type()."),
+            Error::MaxHeightOrMaxWidthRequired => write!(f, "Google Maps Places API client: \
+                A Place Photo request requires a maximum height, a maximum width, or both. \
+                Try again after calling with_max_height() or with_max_width()."),
             Error::QueryNotBuilt => write!(f, "Google Maps Places API client library: \
                 The query string must be built before the request may be sent to the Google Cloud Maps Platform. \
                 Ensure the build() method is called before run()."),
+            Error::RadiusRequired => write!(f, "Google Maps Places API client: \
+                A Nearby Search request requires a radius unless it ranks results by distance. \
+                Try again using Request::new() with a radius."),
+            Error::RadiusWithRankByDistance => write!(f, "Google Maps Places API client: \
+                A Nearby Search request that ranks results by distance must not have a radius. \
+                Try again using Request::new_ranked_by_distance()."),
             #[cfg(feature = "enable-reqwest")]
             Error::Reqwest(error) => write!(f, "Google Maps Places API client in the Reqwest library: {error}"),
             #[cfg(feature = "enable-reqwest")]
@@ -134,4 +174,22 @@ impl From<serde_json::error::Error> for Error {
     fn from(error: serde_json::error::Error) -> Error {
         Error::SerdeJson(error)
     } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::traits::ServiceError for Error {
+    /// Returns the `QueryNotBuilt` error of the Google Maps Places API.
+    fn query_not_built() -> Self {
+        Error::QueryNotBuilt
+    } // fn
+    /// Returns the `HttpUnsuccessful` error of the Google Maps Places API.
+    fn http_unsuccessful(status: String) -> Self {
+        Error::HttpUnsuccessful(status)
+    } // fn
+    /// Returns the `SerdeJson` error of the Google Maps Places API.
+    fn serde_json(error: serde_json::Error) -> Self {
+        Error::SerdeJson(error)
+    } // fn
 } // impl
\ No newline at end of file
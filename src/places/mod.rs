@@ -26,8 +26,9 @@
 //! The following place requests are available:
 //!
 //! * [Place Search](https://developers.google.com/maps/documentation/places/web-service/search)
-//! **(Not yet implemented in this client.)**
-//! returns a list of places based on a user's location or searchstring.
+//!   **(Find Place, Nearby Search and Text Search are implemented in this
+//!   client.)**
+//!   returns a list of places based on a user's location or searchstring.
 //!
 //! * [Place Details](https://developers.google.com/maps/documentation/places/web-service/details)
 //! **(Not yet implemented in this client.)**
@@ -35,7 +36,6 @@
 //! reviews.
 //!
 //! * [Place Photos](https://developers.google.com/maps/documentation/places/web-service/photos)
-//! **(Not yet implemented in this client.)**
 //! provides access to the millions of place-related photos stored in Google's
 //! Place database.
 //!
@@ -88,6 +88,7 @@ pub use crate::places::place_autocomplete::{
     }, // response
     request::{
         autocomplete_type::AutocompleteType,
+        description::Description as PlaceAutocompleteRequestDescription,
         Request as PlaceAutocompleteRequest,
     }, // request
 }; // crate::places::place_autocomplete
@@ -106,6 +107,8 @@ pub mod business_status;
 #[cfg(feature = "places")]
 pub mod error;
 #[cfg(feature = "places")]
+pub mod photo;
+#[cfg(feature = "places")]
 pub mod place;
 #[cfg(feature = "places")]
 pub mod place_details;
@@ -0,0 +1,34 @@
+//! The **Place Photo** service gives you access to the millions of photos
+//! stored in the Places database. When you get place information using a Place
+//! Details, Nearby Search or Text Search request, you can also request photo
+//! resources for relevant photographic content. Using the Place Photo service,
+//! you can then access the referenced photos and resize the image to the
+//! optimal size for your application.
+//!
+//! * The Place Photo service responds with a redirect to the image itself. The
+//!   redirect is followed by the client's transport, and the image's bytes and
+//!   content type are returned in the `Response`.
+//!
+//! * Photos returned by the Place Photo service are sourced from a variety of
+//!   locations, including business owners and user contributed photos. In most
+//!   cases, these photos can be used without attribution, or will have the
+//!   required attribution included as a part of the image. However, if the
+//!   returned `PlacePhoto` element includes a value in the `html_attributions`
+//!   field, you will have to include the additional attribution in your
+//!   application wherever you display the image.
+
+pub mod request;
+pub mod response;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://maps.googleapis.com/maps/api/place/photo";
+
+// -----------------------------------------------------------------------------
+
+pub use crate::places::error::Error;
+
+pub use crate::places::photo::{
+    request::Request,
+    response::Response,
+}; // photo
\ No newline at end of file
@@ -0,0 +1,88 @@
+//! Contains the `Description` struct. It is a client-independent, serializable
+//! description of a _Place Photo_ request that may be persisted (for
+//! example, in a job queue) and later bound to a client for execution.
+
+use crate::client::ClientRef;
+use crate::places::photo::request::Request;
+
+// -----------------------------------------------------------------------------
+//
+/// A client-independent description of a _Place Photo_ request. Use
+/// `Request::describe()` to obtain one, store it wherever you like using
+/// `serde`, and then call `bind()` to turn it back into a `Request` that may
+/// be executed.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Description {
+    /// A string identifier that uniquely identifies a photo.
+    pub photo_reference: String,
+    /// The maximum desired height, in pixels, of the image.
+    pub max_height: Option<u16>,
+    /// The maximum desired width, in pixels, of the image.
+    pub max_width: Option<u16>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Request<'_>> for Description {
+    /// Describes a _Place Photo_ request, leaving its client behind.
+    fn from(request: &Request<'_>) -> Self {
+        Description {
+            photo_reference: request.photo_reference.clone(),
+            max_height: request.max_height,
+            max_width: request.max_width,
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Description {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Binds this description to a client, producing a `Request` that is ready
+    /// to be executed.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct. This may
+    ///   be a reference to a `GoogleMapsClient` or an `Arc<GoogleMapsClient>`.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let description: PlacePhotoRequestDescription = serde_json::from_str(&job)?;
+    /// let response = description.bind(&google_maps_client).execute().await?;
+    /// ```
+
+    pub fn bind<'a>(self, client: impl Into<ClientRef<'a>>) -> Request<'a> {
+        Request {
+            client: client.into(),
+            photo_reference: self.photo_reference,
+            max_height: self.max_height,
+            max_width: self.max_width,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
+            query: None,
+        } // struct
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::traits::RequestDescription for Description {
+
+    type Response = crate::places::photo::response::Response;
+
+    /// Binds this description to the client and executes the request.
+    fn execute(
+        self,
+        client: &crate::client::GoogleMapsClient,
+    ) -> futures::future::BoxFuture<'_, Result<Self::Response, crate::error::Error>> {
+        Box::pin(async move { self.bind(client).execute().await })
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,33 @@
+use crate::error::Error as GoogleMapsError;
+use crate::places::photo::{
+    request::Request as PlacePhotoRequest,
+    response::Response as PlacePhotoResponse,
+}; // crate::places::photo
+
+// =============================================================================
+
+impl<'a> PlacePhotoRequest<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built.
+    ///
+    /// ## Description:
+    ///
+    /// My adventures in Rust became messy so I had to make this method. It
+    /// wraps the `.validate()?.build()?.get()?` chain needed at the end of the
+    /// builder pattern.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub async fn execute(
+        &mut self
+    ) -> Result<PlacePhotoResponse, GoogleMapsError> {
+
+        self.validate()?.build().get().await
+
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,52 @@
+use crate::billing::{Charge, Sku};
+use crate::error::Error as GoogleMapsError;
+use crate::request_rate::api::Api;
+use crate::places::photo::{
+    SERVICE_URL,
+    request::Request as PlacePhotoRequest,
+    response::Response as PlacePhotoResponse,
+}; // crate::places::photo
+use crate::traits::EndPoint;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+
+impl EndPoint for PlacePhotoRequest<'_> {
+
+    type Response = PlacePhotoResponse;
+
+    fn service_url() -> &'static str { SERVICE_URL }
+
+    fn output_format() -> Option<&'static str> { None }
+
+    fn title() -> &'static str { "Places Place Photo" }
+
+    fn api() -> Api { Api::PlacePhoto }
+
+    fn query_string(&self) -> Option<&str> { self.query.as_deref() }
+
+    fn timeout(&self) -> Option<Duration> { self.timeout }
+
+    fn charge(&self) -> Charge { Sku::PlacePhoto.into() }
+
+    /// Images are not JSON, so they are not stored in the response cache.
+    fn cacheable(&self) -> bool { false }
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<'a> PlacePhotoRequest<'a> {
+
+    /// Performs the HTTP get request and returns the response to the caller.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    #[tracing::instrument(level = "debug", name = "Google Maps Places Place Photo", skip(self))]
+    pub async fn get(&mut self) -> Result<PlacePhotoResponse, GoogleMapsError> {
+        self.client.get_request(self).await
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,84 @@
+//! **Look in this module for documentation on building your _Places API_
+//! _Place Photo_ query**. In particular, look at the _Request_ struct
+//! for examples of the builder pattern. This module contains the tools (enums,
+//! structs, methods) for building your Google Maps Platform request.
+
+mod build;
+mod describe;
+#[cfg(feature = "enable-reqwest")]
+mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
+#[cfg(feature = "enable-reqwest")]
+mod get;
+#[cfg(feature = "enable-reqwest")]
+mod into_future;
+mod new;
+mod query_url;
+mod validate;
+mod with_max_height;
+mod with_max_width;
+#[cfg(feature = "enable-reqwest")]
+mod with_timeout;
+pub mod description;
+
+// -----------------------------------------------------------------------------
+
+use crate::client::ClientRef;
+
+// -----------------------------------------------------------------------------
+
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Place Photo_ query**. The methods implemented for this struct are
+/// what's used to build your request.
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    // Required parameters:
+    // --------------------
+
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    client: ClientRef<'a>,
+
+    /// A string identifier that uniquely identifies a photo. Photo references
+    /// are returned from either a Place Search or Place Details request, in
+    /// the `photo_reference` field of each `PlacePhoto`.
+    photo_reference: String,
+
+    // Optional parameters:
+    // --------------------
+
+    /// Specifies the maximum desired height, in pixels, of the image. If the
+    /// image is smaller than the values specified, the original image will be
+    /// returned. If the image is larger in either dimension, it will be scaled
+    /// to match the smaller of the two dimensions, restricted to its original
+    /// aspect ratio. Both the `maxheight` and `maxwidth` properties accept an
+    /// integer between `1` and `1600`.
+    ///
+    /// * One of `max_height` or `max_width` is required.
+    max_height: Option<u16>,
+
+    /// Specifies the maximum desired width, in pixels, of the image. If the
+    /// image is smaller than the values specified, the original image will be
+    /// returned. If the image is larger in either dimension, it will be scaled
+    /// to match the smaller of the two dimensions, restricted to its original
+    /// aspect ratio. Both the `maxheight` and `maxwidth` properties accept an
+    /// integer between `1` and `1600`.
+    ///
+    /// * One of `max_height` or `max_width` is required.
+    max_width: Option<u16>,
+
+    /// The deadline for the request, including rate limiter waits and
+    /// retries. It overrides the client's default timeout. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "enable-reqwest")]
+    timeout: Option<std::time::Duration>,
+
+    // Internal use only:
+    // ------------------
+
+    /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    query: Option<String>,
+
+} // struct
\ No newline at end of file
@@ -0,0 +1,42 @@
+use crate::client::ClientRef;
+use crate::places::photo::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Places API _Place Photo_ query
+    /// with the required, non-optional parameters. A maximum height or width
+    /// must also be set, using `with_max_height()` or `with_max_width()`.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    /// * `photo_reference` ‧ A string identifier that uniquely identifies a
+    ///   photo. Photo references are returned in the `photo_reference` field of
+    ///   each `PlacePhoto` of a Place Search or Place Details response.
+
+    pub fn new(
+        client: impl Into<ClientRef<'a>>,
+        photo_reference: String,
+    ) -> Self {
+
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client: client.into(),
+            photo_reference,
+            // Optional parameters:
+            max_height: None,
+            max_width: None,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
+            // Internal use only:
+            query: None,
+        } // struct
+
+    } // fn
+
+} // impl
\ No newline at end of file
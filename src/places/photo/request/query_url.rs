@@ -0,0 +1,44 @@
+use crate::places::photo::{
+    SERVICE_URL,
+    request::Request,
+}; // crate::places::photo
+use crate::request_rate::api::Api;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Returns the URL query string that represents the query you've built.
+    ///
+    /// ## Description:
+    ///
+    /// Returns the query string that will be sent to the Places API. It is the
+    /// result of the builder pattern. This method could be useful for records
+    /// or logging. It could also be used for passing to your HTTP client of
+    /// choice and executing the HTTP GET request yourself, or for use as the
+    /// source of an image in a web page.
+    ///
+    /// The API key (or client ID and signature) is appended to the URL. The
+    /// API key and signature are replaced with `REDACTED` unless the client
+    /// has opted-in to revealing secrets using
+    /// `GoogleMapsClient::with_reveal_secrets`. If a base URL override has
+    /// been set using `GoogleMapsClient::with_base_url`, it is used in place
+    /// of Google's.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn query_url(&mut self) -> String {
+        let client = self.client.clone();
+        let query_string = match &self.query {
+            // If query string has already been built, return it:
+            Some(query_string) => query_string,
+            // If it hasn't been built, build it:
+            None => self.build().query.as_ref().unwrap(),
+        }; // match
+        let service_url = client.service_url(&Api::PlacePhoto, SERVICE_URL);
+        client.redact_url(&client.authenticated_url(&format!("{service_url}?{query_string}")))
+    } // fn
+
+} // impl
\ No newline at end of file
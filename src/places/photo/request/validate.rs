@@ -0,0 +1,28 @@
+use crate::places::{error::Error, photo::request::Request};
+
+impl<'a> Request<'a> {
+
+    /// Ensures the built query is valid. This function checks the combination
+    /// of parameters to ensure that they make sense together and that Google
+    /// Maps Places API will accept them - i.e. it will not allow a request
+    /// with neither a maximum height nor a maximum width. This function does
+    /// not check parameter values for validity - i.e. it will not ensure that
+    /// the photo reference exists.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn validate(&mut self) -> Result<&mut Self, Error> {
+
+        // At least one of the maximum height or maximum width must be set:
+        if self.max_height.is_none() && self.max_width.is_none() {
+            return Err(Error::MaxHeightOrMaxWidthRequired);
+        } // if
+
+        // Return modified Request struct to caller.
+        Ok(self)
+
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,33 @@
+use crate::places::photo::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Adds the maximum height parameter to the Places API _Place Photo_
+    /// query.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `max_height` ‧ Specifies the maximum desired height, in pixels, of the
+    ///   image. If the image is smaller than the values specified, the original
+    ///   image will be returned. If the image is larger in either dimension, it
+    ///   will be scaled to match the smaller of the two dimensions, restricted
+    ///   to its original aspect ratio. Accepts an integer between `1` and
+    ///   `1600`.
+    ///
+    /// ## Example:
+    ///
+    /// * Scale the photo down to, at most, 400 pixels high:
+    /// ```rust
+    /// .with_max_height(400)
+    /// ```
+
+    pub fn with_max_height(&mut self, max_height: u16) -> &mut Self {
+        // Set maximum height in Request struct.
+        self.max_height = Some(max_height);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,36 @@
+use crate::places::photo::request::Request;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Sets a deadline for this Place Photo request. If the image has not been
+    /// downloaded in time, `Error::Timeout` is returned instead. The deadline
+    /// covers the whole download, as well as waiting on the client's rate
+    /// limits and any retries.
+    ///
+    /// It overrides the client's default timeout, if one was set using
+    /// `GoogleMapsClient::with_timeout`. Large images take longer to download
+    /// than JSON responses, so allow for the requested `maxwidth` or
+    /// `maxheight` when choosing a deadline.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `timeout` ‧ The maximum time that the request may take.
+    ///
+    /// ## Example:
+    ///
+    /// * Give up on the image after 30 seconds:
+    /// ```rust
+    /// .with_timeout(std::time::Duration::from_secs(30))
+    /// ```
+
+    pub fn with_timeout(&mut self, timeout: Duration) -> &mut Self {
+        // Set timeout in Request struct.
+        self.timeout = Some(timeout);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
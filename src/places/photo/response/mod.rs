@@ -0,0 +1,51 @@
+//! Resources (enums, structs) for processing the _Places API_ _Place Photo_
+//! response from the Google Maps Platform. Look in here for more information
+//! about the data returned from Google's server and how to use it with your
+//! program.
+
+// -----------------------------------------------------------------------------
+
+use serde::{Deserialize, Serialize};
+
+/// The response from the Google Maps Places API _Place Photo_ request will be
+/// stored in this structure. Unlike other Places API responses, it is not
+/// JSON: it is the image itself.
+///
+/// See [Place Photo response](https://developers.google.com/maps/documentation/places/web-service/photos#place-photo-response)
+/// for more information.
+
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct Response {
+
+    /// The bytes of the image, for example a JPEG or PNG file. These may be
+    /// written to a file, or passed to an image decoding library.
+    pub bytes: Vec<u8>,
+
+    /// The [media type](https://developer.mozilla.org/en-US/docs/Web/HTTP/Basics_of_HTTP/MIME_types)
+    /// of the image, as reported in the response's `Content-Type` header. For
+    /// example, `image/jpeg`. If the server did not report one,
+    /// `application/octet-stream` is used.
+    pub content_type: String,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::traits::ServiceResponse for Response {
+    type Error = crate::places::error::Error;
+    /// The image is only returned when Google's servers were successful, so
+    /// the response is always returned.
+    fn into_result(self) -> Result<Self, (String, Self::Error)> {
+        Ok(self)
+    } // fn
+    /// Builds the response from the image in the body of the HTTP response,
+    /// rather than parsing it as JSON.
+    fn from_http_response(response: &crate::transport::HttpResponse) -> Result<Self, serde_json::Error> {
+        let content_type = response
+            .header("Content-Type")
+            .unwrap_or("application/octet-stream")
+            .to_string();
+        Ok(Response { bytes: response.body.clone(), content_type })
+    } // fn
+} // impl
\ No newline at end of file
@@ -12,14 +12,13 @@ impl<'a> Request<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> &'a mut Request {
+    pub fn build(&mut self) -> &mut Self {
 
         // This section builds the "required parameters" portion of the query
         // string:
 
         let mut query = format!(
-            "key={}&input={}",
-            self.client.key,
+            "input={}",
             utf8_percent_encode(&self.input, NON_ALPHANUMERIC),
         );
 
@@ -0,0 +1,126 @@
+//! Contains the `Description` struct. It is a client-independent, serializable
+//! description of a _Place Autocomplete_ request that may be persisted (for
+//! example, in a job queue) and later bound to a client for execution.
+
+use crate::client::ClientRef;
+use crate::places::place_autocomplete::request::{autocomplete_type::AutocompleteType, Request};
+use crate::types::{Country, Language, LatLng, Region};
+
+// -----------------------------------------------------------------------------
+//
+/// A client-independent description of a _Place Autocomplete_ request. Use
+/// `Request::describe()` to obtain one, store it wherever you like using
+/// `serde`, and then call `bind()` to turn it back into a `Request` that may
+/// be executed.
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Description {
+    /// The text string on which to search.
+    pub input: String,
+    /// Countries to which results are restricted.
+    #[serde(default)]
+    pub components: Vec<Country>,
+    /// The language in which to return results.
+    pub language: Option<Language>,
+    /// The point around which to retrieve place information.
+    pub location: Option<LatLng>,
+    /// The position of the last character that the service uses to match
+    /// predictions.
+    pub offset: Option<u8>,
+    /// The origin point from which to calculate straight-line distance.
+    pub origin: Option<LatLng>,
+    /// The distance (in meters) within which to return place results.
+    pub radius: Option<u32>,
+    /// The region code, specified as a ccTLD two-character value.
+    pub region: Option<Region>,
+    /// A random string which identifies an autocomplete session for billing
+    /// purposes.
+    pub sessiontoken: Option<String>,
+    /// Returns only those places that are strictly within the region defined
+    /// by `location` and `radius`.
+    pub strictbounds: Option<bool>,
+    /// Restricts the results to places matching the specified types.
+    #[serde(default)]
+    pub types: Vec<AutocompleteType>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Request<'_>> for Description {
+    /// Describes a _Place Autocomplete_ request, leaving its client behind.
+    fn from(request: &Request<'_>) -> Self {
+        Description {
+            input: request.input.clone(),
+            components: request.components.clone(),
+            language: request.language.clone(),
+            location: request.location.clone(),
+            offset: request.offset,
+            origin: request.origin.clone(),
+            radius: request.radius,
+            region: request.region.clone(),
+            sessiontoken: request.sessiontoken.clone(),
+            strictbounds: request.strictbounds,
+            types: request.types.clone(),
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Description {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Binds this description to a client, producing a `Request` that is ready
+    /// to be executed.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct. This may
+    ///   be a reference to a `GoogleMapsClient` or an `Arc<GoogleMapsClient>`.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let description: PlaceAutocompleteRequestDescription = serde_json::from_str(&job)?;
+    /// let response = description.bind(&google_maps_client).execute().await?;
+    /// ```
+
+    pub fn bind<'a>(self, client: impl Into<ClientRef<'a>>) -> Request<'a> {
+        Request {
+            client: client.into(),
+            input: self.input,
+            components: self.components,
+            language: self.language,
+            location: self.location,
+            offset: self.offset,
+            origin: self.origin,
+            radius: self.radius,
+            region: self.region,
+            sessiontoken: self.sessiontoken,
+            strictbounds: self.strictbounds,
+            types: self.types,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
+            query: None,
+        } // struct
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::traits::RequestDescription for Description {
+
+    type Response = crate::places::place_autocomplete::response::Response;
+
+    /// Binds this description to the client and executes the request.
+    fn execute(
+        self,
+        client: &crate::client::GoogleMapsClient,
+    ) -> futures::future::BoxFuture<'_, Result<Self::Response, crate::error::Error>> {
+        Box::pin(async move { self.bind(client).execute().await })
+    } // fn
+
+} // impl
\ No newline at end of file
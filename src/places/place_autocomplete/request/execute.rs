@@ -23,7 +23,7 @@ impl<'a> PlaceAutocompleteRequest<'a> {
     /// This method accepts no arguments.
 
     pub async fn execute(
-        &'a mut self
+        &mut self
     ) -> Result<PlaceAutocompleteResponse, GoogleMapsError> {
 
         self.build().get().await
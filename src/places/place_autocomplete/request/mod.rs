@@ -4,10 +4,15 @@
 //! structs, methods) for building your Google Maps Platform request.
 
 mod build;
+mod describe;
 #[cfg(feature = "enable-reqwest")]
 mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
 #[cfg(feature = "enable-reqwest")]
 mod get;
+#[cfg(feature = "enable-reqwest")]
+mod into_future;
 mod new;
 mod query_url;
 mod with_language;
@@ -17,12 +22,15 @@ mod with_origin;
 mod with_region;
 mod with_sessiontoken;
 mod with_components;
+#[cfg(feature = "enable-reqwest")]
+mod with_timeout;
 mod with_types;
 pub mod autocomplete_type;
+pub mod description;
 
 // -----------------------------------------------------------------------------
 
-use crate::client::GoogleMapsClient;
+use crate::client::ClientRef;
 use crate::places::place_autocomplete::request::autocomplete_type::AutocompleteType;
 use crate::types::{Country, Language, LatLng, Region};
 
@@ -39,7 +47,7 @@ pub struct Request<'a> {
 
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
-    client: &'a GoogleMapsClient,
+    client: ClientRef<'a>,
 
     /// The text string on which to search. The Place Autocomplete service will
     /// return candidate matches based on this string and order results based on
@@ -183,6 +191,12 @@ pub struct Request<'a> {
     /// specifying no types.
     types: Vec<AutocompleteType>,
 
+    /// The deadline for the request, including rate limiter waits and
+    /// retries. It overrides the client's default timeout. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "enable-reqwest")]
+    timeout: Option<std::time::Duration>,
+
     // Internal use only:
     // ------------------
 
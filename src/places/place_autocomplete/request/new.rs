@@ -1,4 +1,4 @@
-use crate::client::GoogleMapsClient;
+use crate::client::ClientRef;
 use crate::places::place_autocomplete::request::Request;
 
 // =============================================================================
@@ -16,14 +16,14 @@ impl<'a> Request<'a> {
     /// * `input` ‧ The text string on which to search.
 
     pub fn new(
-        client: &GoogleMapsClient,
+        client: impl Into<ClientRef<'a>>,
         input: String,
-    ) -> Request {
+    ) -> Self {
 
         // Instantiate struct and return it to caller:
         Request {
             // Required parameters:
-            client,
+            client: client.into(),
             input,
             // Optional parameters:
             components: vec![],
@@ -36,6 +36,8 @@ impl<'a> Request<'a> {
             sessiontoken: None,
             strictbounds: None,
             types: vec![],
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
             // Internal use only:
             query: None,
         } // struct
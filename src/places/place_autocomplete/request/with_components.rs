@@ -16,7 +16,7 @@ impl<'a> Request<'a> {
     ///
     /// * Multiple components may be stacked together.
 
-    pub fn with_component(&'a mut self, component: Country) -> &'a mut Request {
+    pub fn with_component(&mut self, component: Country) -> &mut Self {
         // Set components in Request struct.
         self.components.extend(vec![component]);
         // Return modified Request struct to caller.
@@ -40,7 +40,7 @@ impl<'a> Request<'a> {
     ///
     /// * Multiple components may be stacked together.
 
-    pub fn with_components(&'a mut self, components: Vec<Country>) -> &'a mut Request {
+    pub fn with_components(&mut self, components: Vec<Country>) -> &mut Self {
         // Set components in Request struct.
         self.components.extend(components);
         // Return modified Request struct to caller.
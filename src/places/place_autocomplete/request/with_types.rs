@@ -19,7 +19,7 @@ impl<'a> Request<'a> {
     ///
     /// * Multiple result type filters may be stacked together.
 
-    pub fn with_type(&'a mut self, autocomplete_type: AutocompleteType) -> &'a mut Request {
+    pub fn with_type(&mut self, autocomplete_type: AutocompleteType) -> &mut Self {
         // Set types in Request struct.
         self.types.extend(vec![autocomplete_type]);
         // Return modified Request struct to caller.
@@ -46,7 +46,7 @@ impl<'a> Request<'a> {
     ///
     /// * Multiple result type filters may be stacked together.
 
-    pub fn with_types(&'a mut self, types: Vec<AutocompleteType>) -> &'a mut Request {
+    pub fn with_types(&mut self, types: Vec<AutocompleteType>) -> &mut Self {
         // Set types in Request struct.
         self.types.extend(types);
         // Return modified Request struct to caller.
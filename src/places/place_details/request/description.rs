@@ -0,0 +1,106 @@
+//! Contains the `Description` struct. It is a client-independent, serializable
+//! description of a _Place Details_ request that may be persisted (for
+//! example, in a job queue) and later bound to a client for execution.
+
+use crate::client::ClientRef;
+use crate::places::place_details::{Field, request::Request, SortOrder};
+use crate::types::{Language, Region};
+
+// -----------------------------------------------------------------------------
+//
+/// A client-independent description of a _Place Details_ request. Use
+/// `Request::describe()` to obtain one, store it wherever you like using
+/// `serde`, and then call `bind()` to turn it back into a `Request` that may
+/// be executed.
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Description {
+    /// A textual identifier that uniquely identifies a place.
+    pub place_id: String,
+    /// Place data types to return.
+    pub fields: Option<Vec<Field>>,
+    /// The language in which to return results.
+    pub language: Option<Language>,
+    /// The region code, specified as a ccTLD two-character value.
+    pub region: Option<Region>,
+    /// Whether to disable translation of reviews.
+    pub reviews_no_translations: Option<bool>,
+    /// The sorting method to use when returning reviews.
+    pub reviews_sort: Option<SortOrder>,
+    /// A random string which identifies an autocomplete session for billing
+    /// purposes.
+    pub sessiontoken: Option<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Request<'_>> for Description {
+    /// Describes a _Place Details_ request, leaving its client behind.
+    fn from(request: &Request<'_>) -> Self {
+        Description {
+            place_id: request.place_id.clone(),
+            fields: request.fields.clone(),
+            language: request.language.clone(),
+            region: request.region.clone(),
+            reviews_no_translations: request.reviews_no_translations,
+            reviews_sort: request.reviews_sort.clone(),
+            sessiontoken: request.sessiontoken.clone(),
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Description {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Binds this description to a client, producing a `Request` that is ready
+    /// to be executed.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct. This may
+    ///   be a reference to a `GoogleMapsClient` or an `Arc<GoogleMapsClient>`.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let description: PlaceDetailsRequestDescription = serde_json::from_str(&job)?;
+    /// let response = description.bind(&google_maps_client).execute().await?;
+    /// ```
+
+    pub fn bind<'a>(self, client: impl Into<ClientRef<'a>>) -> Request<'a> {
+        Request {
+            client: client.into(),
+            place_id: self.place_id,
+            fields: self.fields,
+            language: self.language,
+            region: self.region,
+            reviews_no_translations: self.reviews_no_translations,
+            reviews_sort: self.reviews_sort,
+            sessiontoken: self.sessiontoken,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
+            query: None,
+        } // struct
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::traits::RequestDescription for Description {
+
+    type Response = crate::places::place_details::response::Response;
+
+    /// Binds this description to the client and executes the request.
+    fn execute(
+        self,
+        client: &crate::client::GoogleMapsClient,
+    ) -> futures::future::BoxFuture<'_, Result<Self::Response, crate::error::Error>> {
+        Box::pin(async move { self.bind(client).execute().await })
+    } // fn
+
+} // impl
\ No newline at end of file
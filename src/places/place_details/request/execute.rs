@@ -23,7 +23,7 @@ impl<'a> PlaceDetailsRequest<'a> {
     /// This method accepts no arguments.
 
     pub async fn execute(
-        &'a mut self
+        &mut self
     ) -> Result<PlaceDetailsResponse, GoogleMapsError> {
 
         self.build().get().await
@@ -4,10 +4,15 @@
 //! structs, methods) for building your Google Maps Platform request.
 
 mod build;
+mod describe;
 #[cfg(feature = "enable-reqwest")]
 mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
 #[cfg(feature = "enable-reqwest")]
 mod get;
+#[cfg(feature = "enable-reqwest")]
+mod into_future;
 mod new;
 mod query_url;
 mod with_fields;
@@ -16,11 +21,14 @@ mod with_no_review_translations;
 mod with_region;
 mod with_reviews_sort;
 mod with_sessiontoken;
+#[cfg(feature = "enable-reqwest")]
+mod with_timeout;
+pub mod description;
 
 // -----------------------------------------------------------------------------
 
 use crate::places::place_details::{Field, SortOrder};
-use crate::{client::GoogleMapsClient, types::Language, types::Region};
+use crate::{client::ClientRef, types::Language, types::Region};
 
 // -----------------------------------------------------------------------------
 
@@ -35,7 +43,7 @@ pub struct Request<'a> {
 
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
-    client: &'a GoogleMapsClient,
+    client: ClientRef<'a>,
 
     /// A textual identifier that uniquely identifies a place, returned from a
     /// [Place Search](https://developers.google.com/maps/documentation/places/web-service/search).
@@ -159,6 +167,12 @@ pub struct Request<'a> {
     /// billed individually.
     sessiontoken: Option<String>,
 
+    /// The deadline for the request, including rate limiter waits and
+    /// retries. It overrides the client's default timeout. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "enable-reqwest")]
+    timeout: Option<std::time::Duration>,
+
     // Internal use only:
     // ------------------
 
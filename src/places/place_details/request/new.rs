@@ -1,4 +1,4 @@
-use crate::client::GoogleMapsClient;
+use crate::client::ClientRef;
 use crate::places::place_details::request::Request;
 
 // =============================================================================
@@ -20,14 +20,14 @@ impl<'a> Request<'a> {
     /// [place ID overview](https://developers.google.com/maps/documentation/places/web-service/place-id).
 
     pub fn new(
-        client: &GoogleMapsClient,
+        client: impl Into<ClientRef<'a>>,
         place_id: String,
-    ) -> Request {
+    ) -> Self {
 
         // Instantiate struct and return it to caller:
         Request {
             // Required parameters:
-            client,
+            client: client.into(),
             place_id,
             // Optional parameters:
             fields: None,
@@ -36,6 +36,8 @@ impl<'a> Request<'a> {
             reviews_no_translations: None,
             reviews_sort: None,
             sessiontoken: None,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
             // Internal use only:
             query: None,
         } // struct
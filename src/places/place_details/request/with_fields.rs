@@ -36,7 +36,7 @@ impl<'a> Request<'a> {
     /// will be returned, and you will be billed accordingly. This applies only
     /// to Place Details requests.
 
-    pub fn with_fields(&'a mut self, fields: Vec<Field>) -> &'a mut Request {
+    pub fn with_fields(&mut self, fields: Vec<Field>) -> &mut Self {
         // Set fields in Request struct.
         self.fields = Some(fields);
         // Return modified Request struct to caller.
@@ -35,7 +35,7 @@ impl<'a> Request<'a> {
     ///     or synonyms that may be valid in one language but not in another.
     ///     For example, _utca_ and _tér_ are synonyms for street in Hungarian.
 
-    pub fn with_language(&'a mut self, language: Language) -> &'a mut Request {
+    pub fn with_language(&mut self, language: Language) -> &mut Self {
         // Set language in Request struct.
         self.language = Some(language);
         // Return modified Request struct to caller.
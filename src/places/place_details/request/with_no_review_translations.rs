@@ -19,7 +19,7 @@ impl<'a> Request<'a> {
     /// `language` is omitted, the API attempts to use the `Accept-Language`
     /// header as the preferred language.
 
-    pub fn with_no_review_translations(&'a mut self, no_translations: bool) -> &'a mut Request {
+    pub fn with_no_review_translations(&mut self, no_translations: bool) -> &mut Self {
         // Set translations setting in Request struct.
         self.reviews_no_translations = Some(no_translations);
         // Return modified Request struct to caller.
@@ -37,7 +37,7 @@ impl<'a> Request<'a> {
     /// same token for more than one session will result in each request being
     /// billed individually.
 
-    pub fn with_sessiontoken(&'a mut self, sessiontoken: String) -> &'a mut Request {
+    pub fn with_sessiontoken(&mut self, sessiontoken: String) -> &mut Self {
         // Set session token in Request struct.
         self.sessiontoken = Some(sessiontoken);
         // Return modified Request struct to caller.
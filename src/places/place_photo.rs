@@ -14,6 +14,9 @@ use serde::{Deserialize, Serialize};
 /// https://maps.googleapis.com/maps/api/place/photo?maxwidth=400&photo_reference=photo_reference&key=YOUR_API_KEY
 /// ```
 ///
+/// The photo may also be downloaded using this client's
+/// `GoogleMapsClient::place_photo` method.
+///
 /// See [Place Photos](https://developers.google.com/places/web-service/photos) for more information.
 
 #[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
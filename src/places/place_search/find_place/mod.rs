@@ -0,0 +1,30 @@
+//! A **Find Place** request takes a text input and returns a place. The input
+//! can be any kind of Places text data, such as a name, address, or phone
+//! number. The request must be a string. A Find Place request using non-string
+//! data such as a lat/lng coordinate or plus code generates an error.
+//!
+//! * Unlike Nearby Search and Text Search, a Find Place request may be
+//!   constrained to only return specific fields. When no fields are requested,
+//!   only the `place_id` of each candidate is returned, which Google bills at
+//!   the lower _Find Place - ID only_ rate. This makes Find Place the cheapest
+//!   way to resolve a business name or phone number into a `place_id`.
+
+pub mod request;
+pub mod response;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://maps.googleapis.com/maps/api/place/findplacefromtext";
+const OUTPUT_FORMAT: &str = "json"; // json or xml
+
+// -----------------------------------------------------------------------------
+
+pub use crate::places::{
+    error::Error,
+    status::Status
+}; // place_search
+
+pub use crate::places::place_search::find_place::{
+    request::Request,
+    response::Response,
+}; // find_place
\ No newline at end of file
@@ -0,0 +1,56 @@
+use crate::places::place_details::Field;
+use crate::places::place_search::find_place::request::Request;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Builds the query string for the Google Maps Places API _Find Place_
+    /// query based on the input provided by the client.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn build(&mut self) -> &mut Self {
+
+        // This section builds the "required parameters" portion of the query
+        // string:
+
+        let mut query = format!(
+            "input={}&inputtype={}",
+            utf8_percent_encode(&self.input, NON_ALPHANUMERIC),
+            String::from(&self.input_type),
+        );
+
+        // This section builds the "optional parameters" portion of the query
+        // string:
+
+        // Fields key/value pair:
+        if let Some(fields) = &self.fields {
+            query.push_str("&fields=");
+            query.push_str(&Field::vec_to_csv(fields))
+        }
+
+        // Language key/value pair:
+        if let Some(language) = &self.language {
+            query.push_str("&language=");
+            query.push_str(&String::from(language))
+        }
+
+        // Location Bias key/value pair:
+        if let Some(location_bias) = &self.location_bias {
+            query.push_str("&locationbias=");
+            query.push_str(&utf8_percent_encode(&String::from(location_bias), NON_ALPHANUMERIC).to_string())
+        }
+
+        // Set query string in Request struct.
+        self.query = Some(query);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,102 @@
+//! Contains the `Description` struct. It is a client-independent, serializable
+//! description of a _Find Place_ request that may be persisted (for
+//! example, in a job queue) and later bound to a client for execution.
+
+use crate::client::ClientRef;
+use crate::places::place_details::Field;
+use crate::places::place_search::find_place::request::{
+    input_type::InputType,
+    location_bias::LocationBias,
+    Request,
+}; // crate::places::place_search::find_place::request
+use crate::types::Language;
+
+// -----------------------------------------------------------------------------
+//
+/// A client-independent description of a _Find Place_ request. Use
+/// `Request::describe()` to obtain one, store it wherever you like using
+/// `serde`, and then call `bind()` to turn it back into a `Request` that may
+/// be executed.
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Description {
+    /// The text string on which to search.
+    pub input: String,
+    /// The type of input.
+    pub input_type: InputType,
+    /// The place data types to return.
+    pub fields: Option<Vec<Field>>,
+    /// The language in which to return results.
+    pub language: Option<Language>,
+    /// Prefer results in a specified area.
+    pub location_bias: Option<LocationBias>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Request<'_>> for Description {
+    /// Describes a _Find Place_ request, leaving its client behind.
+    fn from(request: &Request<'_>) -> Self {
+        Description {
+            input: request.input.clone(),
+            input_type: request.input_type.clone(),
+            fields: request.fields.clone(),
+            language: request.language.clone(),
+            location_bias: request.location_bias.clone(),
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Description {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Binds this description to a client, producing a `Request` that is ready
+    /// to be executed.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct. This may
+    ///   be a reference to a `GoogleMapsClient` or an `Arc<GoogleMapsClient>`.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let description: FindPlaceRequestDescription = serde_json::from_str(&job)?;
+    /// let response = description.bind(&google_maps_client).execute().await?;
+    /// ```
+
+    pub fn bind<'a>(self, client: impl Into<ClientRef<'a>>) -> Request<'a> {
+        Request {
+            client: client.into(),
+            input: self.input,
+            input_type: self.input_type,
+            fields: self.fields,
+            language: self.language,
+            location_bias: self.location_bias,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
+            query: None,
+        } // struct
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::traits::RequestDescription for Description {
+
+    type Response = crate::places::place_search::find_place::response::Response;
+
+    /// Binds this description to the client and executes the request.
+    fn execute(
+        self,
+        client: &crate::client::GoogleMapsClient,
+    ) -> futures::future::BoxFuture<'_, Result<Self::Response, crate::error::Error>> {
+        Box::pin(async move { self.bind(client).execute().await })
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,61 @@
+use crate::billing::{Charge, Sku};
+use crate::error::Error as GoogleMapsError;
+use crate::places::place_details::Field;
+use crate::request_rate::api::Api;
+use crate::places::place_search::find_place::{
+    SERVICE_URL,
+    OUTPUT_FORMAT,
+    request::Request as PlacesFindPlaceRequest,
+    response::Response as PlacesFindPlaceResponse,
+}; // crate::places::place_search::find_place
+use crate::traits::EndPoint;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+
+impl EndPoint for PlacesFindPlaceRequest<'_> {
+
+    type Response = PlacesFindPlaceResponse;
+
+    fn service_url() -> &'static str { SERVICE_URL }
+
+    fn output_format() -> Option<&'static str> { Some(OUTPUT_FORMAT) }
+
+    fn title() -> &'static str { "Places Find Place" }
+
+    fn api() -> Api { Api::FindPlace }
+
+    fn query_string(&self) -> Option<&str> { self.query.as_deref() }
+
+    fn timeout(&self) -> Option<Duration> { self.timeout }
+
+    /// Requests that only return each candidate's `place_id` are billed at
+    /// the lower _Find Place - ID only_ rate.
+    fn charge(&self) -> Charge {
+        let id_only = self.fields.as_ref().is_none_or(|fields|
+            fields.iter().all(|field| *field == Field::PlaceId)
+        ); // is_none_or
+        match id_only {
+            true => Sku::FindPlaceIdOnly.into(),
+            false => Sku::FindPlace.into(),
+        } // match
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<'a> PlacesFindPlaceRequest<'a> {
+
+    /// Performs the HTTP get request and returns the response to the caller.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    #[tracing::instrument(level = "debug", name = "Google Maps Places Find Place", skip(self))]
+    pub async fn get(&mut self) -> Result<PlacesFindPlaceResponse, GoogleMapsError> {
+        self.client.get_request(self).await
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,101 @@
+//! Contains the `LocationBias` enum and its associated traits. It is used to
+//! prefer results in a specified area of a _Find Place_ request.
+
+use crate::types::{Bounds, LatLng};
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Prefer results in a specified area, by specifying either a radius plus
+/// lat/lng, or two lat/lng pairs representing the points of a rectangle. If
+/// this parameter is not specified, the API uses IP address biasing by
+/// default.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum LocationBias {
+    /// Instructs the API to use IP address biasing.
+    IpBias,
+    /// A single lat/lng coordinate.
+    Point(LatLng),
+    /// A string specifying radius in meters, plus lat/lng in decimal degrees.
+    Circle {
+        /// The radius of the circle, in meters.
+        radius: u32,
+        /// The centre of the circle.
+        center: LatLng,
+    }, // Circle
+    /// Two lat/lng pairs in decimal degrees, representing the south/west and
+    /// north/east points of a rectangle.
+    Rectangle(Bounds),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for LocationBias {
+    /// Converts a `LocationBias` enum to a `String` that contains a
+    /// [location bias](https://developers.google.com/maps/documentation/places/web-service/search-find-place#locationbias)
+    /// parameter value.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LocationBias::IpBias => write!(f, "ipbias"),
+            LocationBias::Point(point) => write!(f, "point:{point}"),
+            LocationBias::Circle { radius, center } => write!(f, "circle:{radius}@{center}"),
+            LocationBias::Rectangle(bounds) => write!(f, "rectangle:{bounds}"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&LocationBias> for String {
+    /// Converts a `LocationBias` enum to a `String` that contains a
+    /// [location bias](https://developers.google.com/maps/documentation/places/web-service/search-find-place#locationbias)
+    /// parameter value.
+    fn from(location_bias: &LocationBias) -> Self {
+        location_bias.to_string()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn latlng(lat: rust_decimal::Decimal, lng: rust_decimal::Decimal) -> LatLng {
+        LatLng::try_from_dec(lat, lng).unwrap()
+    } // fn
+
+    #[test]
+    fn ip_bias_is_displayed() {
+        assert_eq!(LocationBias::IpBias.to_string(), "ipbias");
+    } // fn
+
+    #[test]
+    fn point_is_displayed() {
+        let location_bias = LocationBias::Point(latlng(dec!(37.7749), dec!(-122.4194)));
+        assert_eq!(location_bias.to_string(), "point:37.7749,-122.4194");
+    } // fn
+
+    #[test]
+    fn circle_is_displayed() {
+        let location_bias = LocationBias::Circle {
+            radius: 2000,
+            center: latlng(dec!(47.6918452), dec!(-122.2226413)),
+        }; // Circle
+        assert_eq!(location_bias.to_string(), "circle:2000@47.6918452,-122.2226413");
+    } // fn
+
+    #[test]
+    fn rectangle_is_displayed() {
+        let location_bias = LocationBias::Rectangle(Bounds {
+            southwest: latlng(dec!(47.6), dec!(-122.4)),
+            northeast: latlng(dec!(47.7), dec!(-122.2)),
+        }); // Rectangle
+        assert_eq!(location_bias.to_string(), "rectangle:47.6,-122.4|47.7,-122.2");
+        assert_eq!(String::from(&location_bias), location_bias.to_string());
+    } // fn
+
+} // mod
\ No newline at end of file
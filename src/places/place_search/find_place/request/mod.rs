@@ -0,0 +1,106 @@
+//! **Look in this module for documentation on building your _Places API_
+//! _Find Place_ query**. In particular, look at the _Request_ struct
+//! for examples of the builder pattern. This module contains the tools (enums,
+//! structs, methods) for building your Google Maps Platform request.
+
+mod build;
+mod describe;
+#[cfg(feature = "enable-reqwest")]
+mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
+#[cfg(feature = "enable-reqwest")]
+mod get;
+#[cfg(feature = "enable-reqwest")]
+mod into_future;
+mod new;
+mod query_url;
+mod with_fields;
+mod with_language;
+mod with_location_bias;
+#[cfg(feature = "enable-reqwest")]
+mod with_timeout;
+pub mod description;
+pub mod input_type;
+pub mod location_bias;
+
+// -----------------------------------------------------------------------------
+
+use crate::places::place_details::Field;
+use crate::places::place_search::find_place::request::{
+    input_type::InputType,
+    location_bias::LocationBias,
+}; // crate::places::place_search::find_place::request
+use crate::{client::ClientRef, types::Language};
+
+// -----------------------------------------------------------------------------
+
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Find Place_ query**. The methods implemented for this struct are
+/// what's used to build your request.
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    // Required parameters:
+    // --------------------
+
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    client: ClientRef<'a>,
+
+    /// The text string on which to search, for example: "restaurant" or "123
+    /// Main Street". This must be a place name, address, or category of
+    /// establishments. Any other types of input can generate errors and are
+    /// not guaranteed to return valid results. The Places API will return
+    /// candidate matches based on this string and order the results based on
+    /// their perceived relevance.
+    input: String,
+
+    /// The type of input. This can be one of either `TextQuery` or
+    /// `PhoneNumber`. Phone numbers must be in international format (prefixed
+    /// by a plus sign ("+"), followed by the country code, then the phone
+    /// number itself).
+    input_type: InputType,
+
+    // Optional parameters:
+    // --------------------
+
+    /// Use the fields parameter to specify a comma-separated list of place
+    /// data types to return. For example:
+    /// `fields=formatted_address,name,geometry`.
+    ///
+    /// * If no fields are specified, only the `place_id` of each candidate is
+    ///   returned.
+    fields: Option<Vec<Field>>,
+
+    /// The language in which to return results.
+    ///
+    /// * See the list of supported languages. Google often updates the
+    ///   supported languages, so this list may not be exhaustive.
+    ///
+    /// * If `language` is not supplied, the API attempts to use the preferred
+    ///   language as specified in the `Accept-Language` header.
+    ///
+    /// * If a name is not available in the preferred language, the API uses the
+    ///   closest match.
+    language: Option<Language>,
+
+    /// Prefer results in a specified area, by specifying either a radius plus
+    /// lat/lng, or two lat/lng pairs representing the points of a rectangle.
+    /// If this parameter is not specified, the API uses IP address biasing by
+    /// default.
+    location_bias: Option<LocationBias>,
+
+    /// The deadline for the request, including rate limiter waits and
+    /// retries. It overrides the client's default timeout. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "enable-reqwest")]
+    timeout: Option<std::time::Duration>,
+
+    // Internal use only:
+    // ------------------
+
+    /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    query: Option<String>,
+
+} // struct
\ No newline at end of file
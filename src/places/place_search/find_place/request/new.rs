@@ -0,0 +1,46 @@
+use crate::client::ClientRef;
+use crate::places::place_search::find_place::request::{input_type::InputType, Request};
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Places API _Find Place_ query
+    /// with the required, non-optional parameters.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    /// * `input` ‧ The text string on which to search, for example: "Museum of
+    ///   Contemporary Art Australia" or "+61293744000". This may be a name,
+    ///   address, or phone number.
+    /// * `input_type` ‧ The type of input. This can be one of either
+    ///   `InputType::TextQuery` or `InputType::PhoneNumber`.
+
+    pub fn new(
+        client: impl Into<ClientRef<'a>>,
+        input: String,
+        input_type: InputType,
+    ) -> Self {
+
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client: client.into(),
+            input,
+            input_type,
+            // Optional parameters:
+            fields: None,
+            language: None,
+            location_bias: None,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
+            // Internal use only:
+            query: None,
+        } // struct
+
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,45 @@
+use crate::places::place_details::Field;
+use crate::places::place_search::find_place::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Adds the requested fields to the Places API _Find Place_ query.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `fields` ‧ Use the fields parameter to specify a comma-separated list
+    ///   of place data types to return. For example:
+    ///   `fields=formatted_address,name,geometry`. Use a forward slash when
+    ///   specifying compound values. For example: `opening_hours/open_now`.
+    ///
+    /// Fields are divided into three billing categories: Basic, Contact, and
+    /// Atmosphere. Basic fields are billed at base rate, and incur no
+    /// additional charges. Contact and Atmosphere fields are billed at a higher
+    /// rate. See the [pricing sheet](https://cloud.google.com/maps-platform/pricing/sheet/)
+    /// for more information. Attributions, `html_attributions`, are always
+    /// returned with every call, regardless of whether the field has been
+    /// requested.
+    ///
+    /// * Caution: Place Search requests and Place Details requests do not
+    ///   return the same fields. Place Search requests return a subset of the
+    ///   fields that are returned by Place Details requests. If the field you
+    ///   want is not returned by Place Search, you can use Place Search to get
+    ///   a `place_id`, then use that Place ID to make a Place Details request.
+    ///   For more information on the fields that are unavailable in a Place
+    ///   Search request, see
+    ///   [Places API fields support](https://developers.google.com/maps/documentation/places/web-service/place-data-fields#places-api-fields-support).
+    ///
+    /// * If you omit the `fields` parameter from a _Find Place_ request, only
+    ///   the `place_id` for the result will be returned. The request is then
+    ///   billed at the lower _Find Place - ID only_ rate.
+
+    pub fn with_fields(&mut self, fields: Vec<Field>) -> &mut Self {
+        // Set fields in Request struct.
+        self.fields = Some(fields);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
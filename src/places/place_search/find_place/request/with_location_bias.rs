@@ -0,0 +1,33 @@
+use crate::places::place_search::find_place::request::{location_bias::LocationBias, Request};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Adds the location bias parameter to the Places API _Find Place_ query.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `location_bias` ‧ Prefer results in a specified area, by specifying
+    ///   either a radius plus lat/lng, or two lat/lng pairs representing the
+    ///   points of a rectangle. If this parameter is not specified, the API
+    ///   uses IP address biasing by default.
+    ///
+    /// ## Example:
+    ///
+    /// * Prefer results within 2 kilometres of a point:
+    /// ```rust
+    /// .with_location_bias(LocationBias::Circle {
+    ///     radius: 2_000,
+    ///     center: LatLng::try_from_dec(dec!(-33.867), dec!(151.195))?,
+    /// })
+    /// ```
+
+    pub fn with_location_bias(&mut self, location_bias: LocationBias) -> &mut Self {
+        // Set location bias in Request struct.
+        self.location_bias = Some(location_bias);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
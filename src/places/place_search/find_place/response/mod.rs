@@ -0,0 +1,81 @@
+//! Resources (enums, structs) for processing the _Places API_ _Find
+//! Place_ response from the Google Maps Platform. Look in here for more
+//! information about the data returned from Google's server and how to parse it
+//! with your program.
+
+// -----------------------------------------------------------------------------
+
+use crate::places::Place;
+use crate::places::status::Status;
+use serde::{Deserialize, Serialize};
+
+/// The response from the Google Maps Places API _Find Place_ request will be
+/// stored in this structure.
+///
+/// See [Find Place responses](https://developers.google.com/maps/documentation/places/web-service/search-find-place#find-place-responses)
+/// for more information.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct Response {
+
+    /// Contains an array of candidates. Usually only one candidate is
+    /// returned. Each candidate only contains the fields that were requested
+    /// with `with_fields`. If no fields were requested, only the `place_id` is
+    /// returned.
+    ///
+    /// See [Place](https://developers.google.com/maps/documentation/places/web-service/search-find-place#Place)
+    /// for more information.
+    pub candidates: Vec<Place>,
+
+    /// Contains the status of the request, and may contain debugging
+    /// information to help you track down why the request failed.
+    ///
+    /// See [PlacesSearchStatus](https://developers.google.com/maps/documentation/places/web-service/search-find-place#PlacesSearchStatus)
+    /// for more information.
+    pub status: Status,
+
+    /// When the service returns a status code other than `OK`, there may be an
+    /// additional `error_message` field within the response object. This field
+    /// contains more detailed information about the reasons behind the given
+    /// status code. This field is not always returned, and its content is
+    /// subject to change.
+    pub error_message: Option<String>,
+
+    /// When the service returns additional information about the request
+    /// specification, there may be an additional `info_messages` field within
+    /// the response object. This field is only returned for successful
+    /// requests. It may not always be returned, and its content is subject to
+    /// change.
+    pub info_messages: Option<Vec<String>>,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for Response {
+    type Err = serde_json::error::Error;
+    /// Parse a Google Maps Places API _Find Place_ JSON response into a usable
+    /// `Response` struct.
+    fn from_str(s: &str) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_str(s)
+    } // fn from_str
+} // impl FromStr
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::traits::ServiceResponse for Response {
+    type Error = crate::places::error::Error;
+    /// Returns the response if the Google Maps Find Place API status is `OK`.
+    /// Otherwise, returns the status code and the corresponding error.
+    fn into_result(self) -> Result<Self, (String, Self::Error)> {
+        if self.status == Status::Ok {
+            Ok(self)
+        } else {
+            Err((
+                String::from(&self.status),
+                crate::places::error::Error::GoogleMapsService(self.status, self.error_message),
+            ))
+        } // if
+    } // fn
+} // impl
\ No newline at end of file
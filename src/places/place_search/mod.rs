@@ -44,4 +44,6 @@
 //! Nearby Search and Text Search allow additional parameters to filter results,
 //! (e.g. `minprice`, `maxprice`, `opennow`, and `type`).
 
+pub mod find_place;
+pub mod nearby_search;
 pub mod text_search;
\ No newline at end of file
@@ -0,0 +1,87 @@
+use crate::places::place_search::nearby_search::request::Request;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Builds the query string for the Google Maps Places API _Nearby Search_
+    /// query based on the input provided by the client.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn build(&mut self) -> &mut Self {
+
+        // This section builds the "required parameters" portion of the query
+        // string:
+
+        let mut query = format!("location={}", String::from(&self.location));
+
+        // Radius key/value pair. It's omitted when ranking by distance:
+        if let Some(radius) = &self.radius {
+            query.push_str("&radius=");
+            query.push_str(&radius.to_string())
+        }
+
+        // This section builds the "optional parameters" portion of the query
+        // string:
+
+        // Keyword key/value pair:
+        if let Some(keyword) = &self.keyword {
+            query.push_str("&keyword=");
+            query.push_str(&utf8_percent_encode(keyword, NON_ALPHANUMERIC).to_string())
+        }
+
+        // Language key/value pair:
+        if let Some(language) = &self.language {
+            query.push_str("&language=");
+            query.push_str(&String::from(language))
+        }
+
+        // Maximum price key/value pair:
+        if let Some(maxprice) = &self.maxprice {
+            query.push_str("&maxprice=");
+            query.push_str(&maxprice.to_string())
+        }
+
+        // Minimum price key/value pair:
+        if let Some(minprice) = &self.minprice {
+            query.push_str("&minprice=");
+            query.push_str(&minprice.to_string())
+        }
+
+        // Open now key/value pair:
+        if let Some(opennow) = &self.opennow {
+            query.push_str("&opennow=");
+            query.push_str(&opennow.to_string())
+        }
+
+        // Page Token key/value pair:
+        if let Some(pagetoken) = &self.pagetoken {
+            query.push_str("&pagetoken=");
+            query.push_str(pagetoken)
+        }
+
+        // Rank by key/value pair:
+        if let Some(rankby) = &self.rankby {
+            query.push_str("&rankby=");
+            query.push_str(&String::from(rankby))
+        }
+
+        // Place Type key/value pair:
+        if let Some(place_type) = &self.place_type {
+            query.push_str("&type=");
+            query.push_str(&String::from(place_type))
+        }
+
+        // Set query string in Request struct.
+        self.query = Some(query);
+
+        // Return modified Request struct to caller.
+        self
+
+    } // fn
+
+} // impl
\ No newline at end of file
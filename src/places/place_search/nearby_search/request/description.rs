@@ -0,0 +1,118 @@
+//! Contains the `Description` struct. It is a client-independent, serializable
+//! description of a _Nearby Search_ request that may be persisted (for
+//! example, in a job queue) and later bound to a client for execution.
+
+use crate::client::ClientRef;
+use crate::places::place_search::nearby_search::request::{rank_by::RankBy, Request};
+use crate::types::{Language, LatLng, PlaceType};
+
+// -----------------------------------------------------------------------------
+//
+/// A client-independent description of a _Nearby Search_ request. Use
+/// `Request::describe()` to obtain one, store it wherever you like using
+/// `serde`, and then call `bind()` to turn it back into a `Request` that may
+/// be executed.
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Description {
+    /// The point around which to retrieve place information.
+    pub location: LatLng,
+    /// The distance (in meters) within which to return place results. It's
+    /// `None` when ranking results by distance.
+    pub radius: Option<u32>,
+    /// A term to be matched against all content that Google has indexed.
+    pub keyword: Option<String>,
+    /// The language in which to return results.
+    pub language: Option<Language>,
+    /// Restricts results to only those places within the specified range.
+    pub maxprice: Option<u8>,
+    /// Restricts results to only those places within the specified range.
+    pub minprice: Option<u8>,
+    /// Returns only those places that are open for business.
+    pub opennow: Option<bool>,
+    /// Returns up to 20 results from a previously run search.
+    pub pagetoken: Option<String>,
+    /// The order in which results are listed.
+    pub rankby: Option<RankBy>,
+    /// Restricts the results to places matching the specified type.
+    pub place_type: Option<PlaceType>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Request<'_>> for Description {
+    /// Describes a _Nearby Search_ request, leaving its client behind.
+    fn from(request: &Request<'_>) -> Self {
+        Description {
+            location: request.location.clone(),
+            radius: request.radius,
+            keyword: request.keyword.clone(),
+            language: request.language.clone(),
+            maxprice: request.maxprice,
+            minprice: request.minprice,
+            opennow: request.opennow,
+            pagetoken: request.pagetoken.clone(),
+            rankby: request.rankby.clone(),
+            place_type: request.place_type.clone(),
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Description {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Binds this description to a client, producing a `Request` that is ready
+    /// to be executed.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct. This may
+    ///   be a reference to a `GoogleMapsClient` or an `Arc<GoogleMapsClient>`.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let description: NearbySearchRequestDescription = serde_json::from_str(&job)?;
+    /// let response = description.bind(&google_maps_client).execute().await?;
+    /// ```
+
+    pub fn bind<'a>(self, client: impl Into<ClientRef<'a>>) -> Request<'a> {
+        Request {
+            client: client.into(),
+            location: self.location,
+            radius: self.radius,
+            keyword: self.keyword,
+            language: self.language,
+            maxprice: self.maxprice,
+            minprice: self.minprice,
+            opennow: self.opennow,
+            pagetoken: self.pagetoken,
+            rankby: self.rankby,
+            place_type: self.place_type,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
+            query: None,
+        } // struct
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::traits::RequestDescription for Description {
+
+    type Response = crate::places::place_search::nearby_search::response::Response;
+
+    /// Binds this description to the client and executes the request.
+    fn execute(
+        self,
+        client: &crate::client::GoogleMapsClient,
+    ) -> futures::future::BoxFuture<'_, Result<Self::Response, crate::error::Error>> {
+        Box::pin(async move { self.bind(client).execute().await })
+    } // fn
+
+} // impl
\ No newline at end of file
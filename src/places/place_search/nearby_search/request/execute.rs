@@ -0,0 +1,33 @@
+use crate::error::Error as GoogleMapsError;
+use crate::places::place_search::nearby_search::{
+    request::Request as NearbySearchRequest,
+    response::Response as NearbySearchResponse,
+}; // crate::places::place_search::nearby_search
+
+// =============================================================================
+
+impl<'a> NearbySearchRequest<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built.
+    ///
+    /// ## Description:
+    ///
+    /// My adventures in Rust became messy so I had to make this method. It
+    /// wraps the `.validate()?.build()?.get()?` chain needed at the end of the
+    /// builder pattern.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub async fn execute(
+        &mut self
+    ) -> Result<NearbySearchResponse, GoogleMapsError> {
+
+        self.validate()?.build().get().await
+
+    } // fn
+
+} // impl
\ No newline at end of file
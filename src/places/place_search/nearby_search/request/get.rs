@@ -0,0 +1,50 @@
+use crate::billing::{Charge, Sku};
+use crate::error::Error as GoogleMapsError;
+use crate::request_rate::api::Api;
+use crate::places::place_search::nearby_search::{
+    SERVICE_URL,
+    OUTPUT_FORMAT,
+    request::Request as PlacesNearbySearchRequest,
+    response::Response as PlacesNearbySearchResponse,
+}; // crate::places::place_search::nearby_search
+use crate::traits::EndPoint;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+
+impl EndPoint for PlacesNearbySearchRequest<'_> {
+
+    type Response = PlacesNearbySearchResponse;
+
+    fn service_url() -> &'static str { SERVICE_URL }
+
+    fn output_format() -> Option<&'static str> { Some(OUTPUT_FORMAT) }
+
+    fn title() -> &'static str { "Places Nearby Search" }
+
+    fn api() -> Api { Api::NearbySearch }
+
+    fn query_string(&self) -> Option<&str> { self.query.as_deref() }
+
+    fn timeout(&self) -> Option<Duration> { self.timeout }
+
+    fn charge(&self) -> Charge { Sku::NearbySearch.into() }
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<'a> PlacesNearbySearchRequest<'a> {
+
+    /// Performs the HTTP get request and returns the response to the caller.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    #[tracing::instrument(level = "debug", name = "Google Maps Places Nearby Search", skip(self))]
+    pub async fn get(&mut self) -> Result<PlacesNearbySearchResponse, GoogleMapsError> {
+        self.client.get_request(self).await
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,134 @@
+//! **Look in this module for documentation on building your _Places API_
+//! _Nearby Search_ query**. In particular, look at the _Request_ struct
+//! for examples of the builder pattern. This module contains the tools (enums,
+//! structs, methods) for building your Google Maps Platform request.
+
+mod build;
+mod describe;
+#[cfg(feature = "enable-reqwest")]
+mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
+#[cfg(feature = "enable-reqwest")]
+mod get;
+#[cfg(feature = "enable-reqwest")]
+mod into_future;
+mod is_open_now;
+mod new;
+mod new_ranked_by_distance;
+mod query_url;
+mod with_keyword;
+mod with_language;
+mod with_max_price;
+mod with_min_price;
+mod with_pagetoken;
+mod with_rank_by;
+#[cfg(feature = "enable-reqwest")]
+mod with_timeout;
+mod with_type;
+mod validate;
+pub mod description;
+pub mod rank_by;
+
+// -----------------------------------------------------------------------------
+
+use crate::{client::ClientRef, types::Language, types::LatLng, types::PlaceType};
+use crate::places::place_search::nearby_search::request::rank_by::RankBy;
+
+// -----------------------------------------------------------------------------
+
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Nearby Search_ query**. The methods implemented for this struct are
+/// what's used to build your request.
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    // Required parameters:
+    // --------------------
+
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    client: ClientRef<'a>,
+
+    /// The point around which to retrieve place information.
+    location: LatLng,
+
+    /// Defines the distance (in meters) within which to return place results.
+    /// The maximum allowed radius is 50,000 meters.
+    ///
+    /// * When using `rankby=distance`, the radius parameter will not be
+    ///   accepted, and will result in an `INVALID_REQUEST`. Use
+    ///   `Request::new_ranked_by_distance()` to create a request without a
+    ///   radius.
+    radius: Option<u32>,
+
+    // Optional parameters:
+    // --------------------
+
+    /// A term to be matched against all content that Google has indexed for
+    /// this place, including but not limited to name and type, as well as
+    /// customer reviews and other third-party content.
+    keyword: Option<String>,
+
+    /// The language in which to return results.
+    ///
+    /// * See the list of supported languages. Google often updates the
+    ///   supported languages, so this list may not be exhaustive.
+    ///
+    /// * If `language` is not supplied, the API attempts to use the preferred
+    ///   language as specified in the `Accept-Language` header.
+    ///
+    /// * If a name is not available in the preferred language, the API uses the
+    ///   closest match.
+    language: Option<Language>,
+
+    /// Restricts results to only those places within the specified range. Valid
+    /// values range between 0 (most affordable) to 4 (most expensive),
+    /// inclusive. The exact amount indicated by a specific value will vary from
+    /// region to region.
+    maxprice: Option<u8>,
+
+    /// Restricts results to only those places within the specified range. Valid
+    /// values range between 0 (most affordable) to 4 (most expensive),
+    /// inclusive. The exact amount indicated by a specific value will vary from
+    /// region to region.
+    minprice: Option<u8>,
+
+    /// Returns only those places that are open for business at the time the
+    /// query is sent. Places that do not specify opening hours in the Google
+    /// Places database will not be returned if you include this parameter in
+    /// your query.
+    opennow: Option<bool>,
+
+    /// Returns up to 20 results from a previously run search. Setting a
+    /// pagetoken parameter will execute a search with the same parameters used
+    /// previously — all parameters other than pagetoken will be ignored.
+    pagetoken: Option<String>,
+
+    /// Specifies the order in which results are listed. See the `RankBy` enum
+    /// for more information.
+    rankby: Option<RankBy>,
+
+    /// Restricts the results to places matching the specified type. Only one
+    /// type may be specified.
+    ///
+    /// See the list of [supported types](https://developers.google.com/maps/documentation/places/web-service/supported_types).
+    ///
+    /// * Note: Adding both `keyword` and `type` with the same value
+    ///   (`keyword=cafe&type=cafe` or `keyword=parking&type=parking`) can yield
+    ///   `ZERO_RESULTS`.
+    place_type: Option<PlaceType>,
+
+    /// The deadline for the request, including rate limiter waits and
+    /// retries. It overrides the client's default timeout. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "enable-reqwest")]
+    timeout: Option<std::time::Duration>,
+
+    // Internal use only:
+    // ------------------
+
+    /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    query: Option<String>,
+
+} // struct
\ No newline at end of file
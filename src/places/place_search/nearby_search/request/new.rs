@@ -0,0 +1,51 @@
+use crate::client::ClientRef;
+use crate::places::place_search::nearby_search::request::Request;
+use crate::types::LatLng;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Places API _Nearby Search_ query
+    /// with the required, non-optional parameters.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    /// * `location` ‧ The point around which to retrieve place information.
+    /// * `radius` ‧ Defines the distance (in meters) within which to return
+    ///   place results. The maximum allowed radius is 50,000 meters. To rank
+    ///   results by distance, use `new_ranked_by_distance()` instead.
+
+    pub fn new(
+        client: impl Into<ClientRef<'a>>,
+        location: LatLng,
+        radius: u32,
+    ) -> Self {
+
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client: client.into(),
+            location,
+            radius: Some(radius),
+            // Optional parameters:
+            keyword: None,
+            language: None,
+            maxprice: None,
+            minprice: None,
+            opennow: None,
+            pagetoken: None,
+            rankby: None,
+            place_type: None,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
+            // Internal use only:
+            query: None,
+        } // struct
+
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,47 @@
+use crate::client::ClientRef;
+use crate::places::place_search::nearby_search::request::{rank_by::RankBy, Request};
+use crate::types::LatLng;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Places API _Nearby Search_ query
+    /// that lists results in ascending order by their distance from the
+    /// `location`. Google doesn't accept a radius when ranking by distance, so
+    /// the request has none. One or more of `keyword` or `type` must be added
+    /// before the request is executed.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    /// * `location` ‧ The point around which to retrieve place information.
+    ///
+    /// ## Example:
+    ///
+    /// * List the closest pharmacies first:
+    /// ```rust
+    /// let nearby_pharmacies = NearbySearchRequest::new_ranked_by_distance(
+    ///     &google_maps_client,
+    ///     LatLng::try_from_dec(dec!(-33.8670522), dec!(151.1957362))?,
+    /// )
+    /// .with_type(PlaceType::Pharmacy)
+    /// .execute()
+    /// .await?;
+    /// ```
+
+    pub fn new_ranked_by_distance(
+        client: impl Into<ClientRef<'a>>,
+        location: LatLng,
+    ) -> Self {
+
+        let mut request = Request::new(client, location, 0);
+        request.radius = None;
+        request.rankby = Some(RankBy::Distance);
+        request
+
+    } // fn
+
+} // impl
\ No newline at end of file
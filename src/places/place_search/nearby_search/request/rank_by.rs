@@ -0,0 +1,139 @@
+//! Contains the `RankBy` enum and its associated traits. It is used to specify
+//! the order in which _Nearby Search_ results are listed.
+
+use crate::error::Error as GoogleMapsError;
+use crate::places::error::Error as PlacesError;
+use phf::phf_map;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// -----------------------------------------------------------------------------
+//
+/// Specifies the order in which results are listed. Note that `rankby=distance`
+/// must not be combined with a `radius`. Use
+/// `Request::new_ranked_by_distance()` to create a request without a radius.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum RankBy {
+    /// **Default** This option sorts results based on their importance.
+    /// Ranking will favor prominent places within the set radius over nearby
+    /// places that match but that are less prominent. Prominence can be
+    /// affected by a place's ranking in Google's index, global popularity, and
+    /// other factors.
+    #[default] Prominence = 0,
+    /// This option biases search results in ascending order by their distance
+    /// from the specified location. When `distance` is specified, one or more
+    /// of `keyword` or `type` is required.
+    Distance = 1,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for RankBy {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        match RankBy::try_from(string.as_str()) {
+            Ok(variant) => Ok(variant),
+            Err(error) => Err(serde::de::Error::custom(error.to_string()))
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Serialize for RankBy {
+    /// Manual implementation of `Serialize` for `serde`.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer {
+        serializer.serialize_str(std::convert::Into::<&str>::into(self))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&RankBy> for &str {
+    /// Converts a `RankBy` enum to a `String` that contains a
+    /// [rank by](https://developers.google.com/maps/documentation/places/web-service/search-nearby#rankby)
+    /// code.
+    fn from(rank_by: &RankBy) -> Self {
+        match rank_by {
+            RankBy::Prominence => "prominence",
+            RankBy::Distance => "distance",
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for RankBy {
+    /// Converts a `RankBy` enum to a `String` that contains a
+    /// [rank by](https://developers.google.com/maps/documentation/places/web-service/search-nearby#rankby)
+    /// code.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", std::convert::Into::<&str>::into(self))
+    } // fmt
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&RankBy> for String {
+    /// Converts a `RankBy` enum to a `String` that contains a
+    /// [rank by](https://developers.google.com/maps/documentation/places/web-service/search-nearby#rankby)
+    /// code.
+    fn from(rank_by: &RankBy) -> Self {
+        std::convert::Into::<&str>::into(rank_by).to_string()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static RANK_BY_TYPES_BY_CODE: phf::Map<&'static str, RankBy> = phf_map! {
+    "prominence" => RankBy::Prominence,
+    "distance" => RankBy::Distance,
+};
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::TryFrom<&str> for RankBy {
+    // Error definitions are contained in the `google_maps\src\places\error.rs` module.
+    type Error = GoogleMapsError;
+    /// Gets a `RankBy` enum from a `String` that contains a supported
+    /// [rank by](https://developers.google.com/maps/documentation/places/web-service/search-nearby#rankby)
+    /// code.
+    fn try_from(rank_by_code: &str) -> Result<Self, Self::Error> {
+        Ok(RANK_BY_TYPES_BY_CODE
+            .get(rank_by_code)
+            .cloned()
+            .ok_or_else(|| PlacesError::InvalidRankByCode(rank_by_code.to_string()))?)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for RankBy {
+    // Error definitions are contained in the `google_maps\src\places\error.rs` module.
+    type Err = GoogleMapsError;
+    /// Gets a `RankBy` enum from a `String` that contains a supported
+    /// [rank by](https://developers.google.com/maps/documentation/places/web-service/search-nearby#rankby)
+    /// code.
+    fn from_str(rank_by_code: &str) -> Result<Self, Self::Err> {
+        Ok(RANK_BY_TYPES_BY_CODE
+            .get(rank_by_code)
+            .cloned()
+            .ok_or_else(|| PlacesError::InvalidRankByCode(rank_by_code.to_string()))?)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl RankBy {
+    /// Formats a `RankBy` enum into a string that is presentable to the end
+    /// user.
+    pub fn display(&self) -> &str {
+        match self {
+            RankBy::Prominence => "Prominence",
+            RankBy::Distance => "Distance",
+        } // match
+    } // fn
+} // impl
\ No newline at end of file
@@ -0,0 +1,49 @@
+use crate::places::{error::Error, place_search::nearby_search::request::{rank_by::RankBy, Request}};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Ensures the built query is valid. This function checks the combination
+    /// of parameters to ensure that they make sense together and that Google
+    /// Maps Places API will accept them - i.e. it will not allow a request
+    /// that ranks results by distance and also has a radius. This function
+    /// does not check parameter values for validity - i.e. it will not ensure
+    /// that the keyword matches any places.
+    ///
+    /// A request with a page token is not checked, since Google ignores every
+    /// other parameter when a page token is present.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn validate(&mut self) -> Result<&mut Self, Error> {
+
+        if self.pagetoken.is_none() {
+
+            if self.rankby == Some(RankBy::Distance) {
+
+                // Google rejects a radius when ranking by distance:
+                if self.radius.is_some() {
+                    return Err(Error::RadiusWithRankByDistance);
+                } // if
+
+                // Ranking by distance requires a keyword or a type:
+                if self.keyword.is_none() && self.place_type.is_none() {
+                    return Err(Error::KeywordOrTypeRequired);
+                } // if
+
+            // Otherwise, a radius is required:
+            } else if self.radius.is_none() {
+                return Err(Error::RadiusRequired);
+            } // if
+
+        } // if
+
+        // Return modified Request struct to caller.
+        Ok(self)
+
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,25 @@
+use crate::places::place_search::nearby_search::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Adds the keyword parameter to the Places API _Nearby Search_ query.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `keyword` ‧ A term to be matched against all content that Google has
+    ///   indexed for this place, including but not limited to name and type, as
+    ///   well as customer reviews and other third-party content. Note that
+    ///   explicitly including location information using this parameter may
+    ///   conflict with the location and radius parameters, and may cause
+    ///   unexpected results.
+
+    pub fn with_keyword(&mut self, keyword: &str) -> &mut Self {
+        // Set keyword in Request struct.
+        self.keyword = Some(keyword.to_string());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
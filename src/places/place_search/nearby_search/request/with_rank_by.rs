@@ -0,0 +1,31 @@
+use crate::places::place_search::nearby_search::request::{rank_by::RankBy, Request};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Adds the rank by parameter to the Places API _Nearby Search_ query.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `rankby` ‧ Specifies the order in which results are listed. When
+    ///   ranking by `RankBy::Distance`, the request must not have a radius (see
+    ///   `Request::new_ranked_by_distance()`), and one or more of `keyword` or
+    ///   `type` is required.
+    ///
+    /// ## Example:
+    ///
+    /// * List the most prominent pharmacies first:
+    /// ```rust
+    /// .with_type(PlaceType::Pharmacy)
+    /// .with_rank_by(RankBy::Prominence)
+    /// ```
+
+    pub fn with_rank_by(&mut self, rankby: RankBy) -> &mut Self {
+        // Set rank by in Request struct.
+        self.rankby = Some(rankby);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
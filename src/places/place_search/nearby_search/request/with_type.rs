@@ -0,0 +1,36 @@
+use crate::types::PlaceType;
+use crate::places::place_search::nearby_search::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Adds the type parameter to the Places API _Nearby Search_ query.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `place_type` ‧ Restricts the results to places matching the specified
+    ///   type. Google only accepts one type, so calling this method again
+    ///   replaces the previous type.
+    ///
+    /// See the list of [supported types](https://developers.google.com/maps/documentation/places/web-service/supported_types).
+    ///
+    /// Note: Adding both `keyword` and `type` with the same value
+    /// (`keyword=cafe&type=cafe` or `keyword=parking&type=parking`) can yield
+    /// `ZERO_RESULTS`.
+    ///
+    /// ## Examples:
+    ///
+    /// * Only returns pharmacies:
+    /// ```rust
+    /// .with_type(PlaceType::Pharmacy)
+    /// ```
+
+    pub fn with_type(&mut self, place_type: PlaceType) -> &mut Self {
+        // Set place type filter in Request struct.
+        self.place_type = Some(place_type);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
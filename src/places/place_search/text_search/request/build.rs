@@ -13,14 +13,13 @@ impl<'a> Request<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> &'a mut Request {
+    pub fn build(&mut self) -> &mut Self {
 
         // This section builds the "required parameters" portion of the query
         // string:
 
         let mut query = format!(
-            "key={}&query={}",
-            self.client.key,
+            "query={}",
             utf8_percent_encode(&self.input, NON_ALPHANUMERIC),
         );
 
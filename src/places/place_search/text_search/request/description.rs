@@ -0,0 +1,119 @@
+//! Contains the `Description` struct. It is a client-independent, serializable
+//! description of a _Text Search_ request that may be persisted (for
+//! example, in a job queue) and later bound to a client for execution.
+
+use crate::client::ClientRef;
+use crate::places::place_search::text_search::request::Request;
+use crate::types::{Language, LatLng, PlaceType, Region};
+
+// -----------------------------------------------------------------------------
+//
+/// A client-independent description of a _Text Search_ request. Use
+/// `Request::describe()` to obtain one, store it wherever you like using
+/// `serde`, and then call `bind()` to turn it back into a `Request` that may
+/// be executed.
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Description {
+    /// The text string on which to search.
+    pub input: String,
+    /// The language in which to return results.
+    pub language: Option<Language>,
+    /// The point around which to retrieve place information.
+    pub location: Option<LatLng>,
+    /// Restricts results to only those places within the specified range.
+    pub maxprice: Option<u8>,
+    /// Restricts results to only those places within the specified range.
+    pub minprice: Option<u8>,
+    /// Returns only those places that are open for business.
+    pub opennow: Option<bool>,
+    /// Returns up to 20 results from a previously run search.
+    pub pagetoken: Option<String>,
+    /// The distance (in meters) within which to return place results.
+    pub radius: Option<u32>,
+    /// The region code, specified as a ccTLD two-character value.
+    pub region: Option<Region>,
+    /// Restricts the results to places matching the specified types.
+    pub place_types: Option<Vec<PlaceType>>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Request<'_>> for Description {
+    /// Describes a _Text Search_ request, leaving its client behind.
+    fn from(request: &Request<'_>) -> Self {
+        Description {
+            input: request.input.clone(),
+            language: request.language.clone(),
+            location: request.location.clone(),
+            maxprice: request.maxprice,
+            minprice: request.minprice,
+            opennow: request.opennow,
+            pagetoken: request.pagetoken.clone(),
+            radius: request.radius,
+            region: request.region.clone(),
+            place_types: request.place_types.clone(),
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Description {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Binds this description to a client, producing a `Request` that is ready
+    /// to be executed.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct. This may
+    ///   be a reference to a `GoogleMapsClient` or an `Arc<GoogleMapsClient>`.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let description: TextSearchRequestDescription = serde_json::from_str(&job)?;
+    /// let response = description.bind(&google_maps_client).execute().await?;
+    /// ```
+
+    pub fn bind<'a>(self, client: impl Into<ClientRef<'a>>) -> Request<'a> {
+        Request {
+            client: client.into(),
+            input: self.input,
+            language: self.language,
+            location: self.location,
+            maxprice: self.maxprice,
+            minprice: self.minprice,
+            opennow: self.opennow,
+            pagetoken: self.pagetoken,
+            radius: self.radius,
+            region: self.region,
+            place_types: self.place_types,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
+            query: None,
+            #[cfg(feature = "enable-reqwest")]
+            paginating: false,
+        } // struct
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::traits::RequestDescription for Description {
+
+    type Response = crate::places::place_search::text_search::response::Response;
+
+    /// Binds this description to the client and executes the request.
+    fn execute(
+        self,
+        client: &crate::client::GoogleMapsClient,
+    ) -> futures::future::BoxFuture<'_, Result<Self::Response, crate::error::Error>> {
+        Box::pin(async move { self.bind(client).execute().await })
+    } // fn
+
+} // impl
\ No newline at end of file
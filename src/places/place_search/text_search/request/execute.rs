@@ -23,7 +23,7 @@ impl<'a> TextSearchRequest<'a> {
     /// This method accepts no arguments.
 
     pub async fn execute(
-        &'a mut self
+        &mut self
     ) -> Result<TextSearchResponse, GoogleMapsError> {
 
         self.build().get().await
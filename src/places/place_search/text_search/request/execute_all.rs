@@ -0,0 +1,77 @@
+use crate::error::Error as GoogleMapsError;
+use crate::places::Place;
+use crate::places::place_search::text_search::request::Request as TextSearchRequest;
+
+// =============================================================================
+
+impl<'a> TextSearchRequest<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built, and every page of results that follows
+    /// it.
+    ///
+    /// ## Description:
+    ///
+    /// Google returns up to 20 results per page, and up to 60 results in
+    /// total. This method follows each response's `next_page_token` until
+    /// there are no more pages, waiting for each page token to become valid,
+    /// and returns the places from every page. Use `stream()` to handle the
+    /// places as they arrive instead.
+    ///
+    /// Pages are not read from, or stored in, the client's response cache.
+    /// Once pagination finishes, successfully or not, the request's page token
+    /// is put back the way it was.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let places = google_maps_client.text_search("pizza in New York".to_string())
+    ///     .execute_all()
+    ///     .await?;
+    /// ```
+
+    pub async fn execute_all(
+        &mut self
+    ) -> Result<Vec<Place>, GoogleMapsError> {
+
+        // Following the page tokens overwrites the request's page token. Keep
+        // the caller's so that it can be put back afterwards:
+        let pagetoken = self.pagetoken.clone();
+        self.paginating = true;
+
+        let places = self.get_all_pages().await;
+
+        self.paginating = false;
+        self.pagetoken = pagetoken;
+        self.build();
+
+        places
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Requests the first page, and every page that follows it.
+
+    async fn get_all_pages(
+        &mut self
+    ) -> Result<Vec<Place>, GoogleMapsError> {
+
+        let mut response = self.execute().await?;
+        let mut places = response.results;
+
+        while let Some(next_page_token) = response.next_page_token {
+            response = self.get_next_page(next_page_token).await?;
+            places.append(&mut response.results);
+        } // while
+
+        Ok(places)
+
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,35 @@
+use crate::error::Error as GoogleMapsError;
+use crate::places::Place;
+use crate::places::place_search::text_search::request::Request as TextSearchRequest;
+
+// =============================================================================
+
+impl<'a> TextSearchRequest<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built, and every page of results that follows
+    /// it, blocking the current thread until all of the pages have been
+    /// received.
+    ///
+    /// ## Description:
+    ///
+    /// This is the synchronous version of `execute_all()`. The same rate
+    /// limiting and retry settings are observed.
+    ///
+    /// This method must not be called from within an `async` context. Use
+    /// `execute_all()` instead.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn execute_all_blocking(
+        &mut self
+    ) -> Result<Vec<Place>, GoogleMapsError> {
+
+        crate::blocking::block_on(self.execute_all())
+
+    } // fn
+
+} // impl
\ No newline at end of file
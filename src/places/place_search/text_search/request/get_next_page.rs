@@ -0,0 +1,122 @@
+use crate::error::Error as GoogleMapsError;
+use crate::places::{error::Error as PlacesError, status::Status};
+use crate::places::place_search::text_search::{
+    request::Request as TextSearchRequest,
+    response::Response as TextSearchResponse,
+}; // crate::places::place_search::text_search
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+
+/// How long to wait for a page token to become valid. Google issues the
+/// `next_page_token` a short time before it may be used.
+const PAGE_TOKEN_DELAY: Duration = Duration::from_secs(2);
+
+/// How many times a page is requested before giving up on its page token.
+const PAGE_TOKEN_ATTEMPTS: u32 = 3;
+
+// =============================================================================
+
+impl<'a> TextSearchRequest<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// ## Description
+    ///
+    /// Requests the page of results identified by a `next_page_token`. There is
+    /// a short delay between when a page token is issued and when it becomes
+    /// valid, during which Google responds with `INVALID_REQUEST`. The request
+    /// waits for the token to become valid, and is retried while Google
+    /// continues to respond with `INVALID_REQUEST`. Refused attempts are not
+    /// charged against the client's budget, so the page is only charged once.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `next_page_token` ‧ The `next_page_token` of the previous page.
+
+    pub(super) async fn get_next_page(
+        &mut self,
+        next_page_token: String,
+    ) -> Result<TextSearchResponse, GoogleMapsError> {
+
+        self.pagetoken = Some(next_page_token);
+        self.build();
+
+        let mut attempt = 1;
+        loop {
+            tokio::time::sleep(PAGE_TOKEN_DELAY).await;
+            match self.get().await {
+                // The page token was not valid yet. Try again, unless we have
+                // run out of attempts:
+                Err(GoogleMapsError::Places(PlacesError::GoogleMapsService(Status::InvalidRequest, _)))
+                    if attempt < PAGE_TOKEN_ATTEMPTS => {
+                    tracing::debug!(
+                        "Google Maps Places Text Search page token is not valid yet (attempt {} of {})",
+                        attempt,
+                        PAGE_TOKEN_ATTEMPTS,
+                    ); // debug!
+                    attempt += 1;
+                }, // Err
+                result => return result,
+            } // match
+        } // loop
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use crate::client::GoogleMapsClient;
+    use crate::request_rate::api::Api;
+    use crate::transport::ScriptedTransport;
+    use rust_decimal_macros::dec;
+
+    const FIRST_PAGE: &str = r#"{ "html_attributions": [], "results": [], "next_page_token": "TOKEN", "status": "OK" }"#;
+    const NOT_VALID_YET: &str = r#"{ "html_attributions": [], "results": [], "status": "INVALID_REQUEST" }"#;
+    const LAST_PAGE: &str = r#"{ "html_attributions": [], "results": [], "status": "OK" }"#;
+
+    #[tokio::test(start_paused = true)]
+    async fn page_is_charged_once() {
+        let transport = ScriptedTransport::responses([
+            (200, FIRST_PAGE),
+            (200, NOT_VALID_YET),
+            (200, NOT_VALID_YET),
+            (200, LAST_PAGE),
+        ]);
+        // The budget only has room for the two pages, so the warm-up attempts
+        // would be refused if they were charged:
+        let client = GoogleMapsClient::new("TEST_KEY")
+            .with_transport(transport.clone())
+            .with_budget(dec!(0.064))
+            .build();
+
+        client.text_search(String::from("pizza")).execute_all().await.unwrap();
+
+        assert_eq!(transport.requests().len(), 4);
+        assert_eq!(client.costs().get(&Api::TextSearch), Some(&dec!(0.064)));
+    } // fn
+
+    #[tokio::test(start_paused = true)]
+    async fn page_token_that_never_becomes_valid_is_an_error() {
+        let transport = ScriptedTransport::responses([
+            (200, FIRST_PAGE),
+            (200, NOT_VALID_YET),
+            (200, NOT_VALID_YET),
+            (200, NOT_VALID_YET),
+        ]);
+        let client = GoogleMapsClient::new("TEST_KEY")
+            .with_transport(transport.clone())
+            .build();
+
+        assert!(client.text_search(String::from("pizza")).execute_all().await.is_err());
+        assert_eq!(transport.requests().len(), 4);
+        assert_eq!(client.costs().get(&Api::TextSearch), Some(&dec!(0.032)));
+    } // fn
+
+} // mod
\ No newline at end of file
@@ -4,24 +4,40 @@
 //! structs, methods) for building your Google Maps Platform request.
 
 mod build;
+mod describe;
 #[cfg(feature = "enable-reqwest")]
 mod execute;
 #[cfg(feature = "enable-reqwest")]
+mod execute_all;
+#[cfg(feature = "blocking")]
+mod execute_all_blocking;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
+#[cfg(feature = "enable-reqwest")]
 mod get;
+#[cfg(feature = "enable-reqwest")]
+mod get_next_page;
+#[cfg(feature = "enable-reqwest")]
+mod into_future;
 mod is_open_now;
 mod new;
 mod query_url;
+#[cfg(feature = "enable-reqwest")]
+mod stream;
 mod with_language;
 mod with_location;
 mod with_max_price;
 mod with_min_price;
 mod with_pagetoken;
 mod with_region;
+#[cfg(feature = "enable-reqwest")]
+mod with_timeout;
 mod with_type;
+pub mod description;
 
 // -----------------------------------------------------------------------------
 
-use crate::{client::GoogleMapsClient, types::Language, types::LatLng, types::PlaceType, types::Region};
+use crate::{client::ClientRef, types::Language, types::LatLng, types::PlaceType, types::Region};
 
 // -----------------------------------------------------------------------------
 
@@ -36,7 +52,7 @@ pub struct Request<'a> {
 
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
-    client: &'a GoogleMapsClient,
+    client: ClientRef<'a>,
 
     /// The text string on which to search, for example: "restaurant" or "123
     /// Main Street". This must a place name, address, or category of
@@ -149,10 +165,22 @@ pub struct Request<'a> {
     /// `ZERO_RESULTS`.
     place_types: Option<Vec<PlaceType>>,
 
+    /// The deadline for the request, including rate limiter waits and
+    /// retries. It overrides the client's default timeout. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "enable-reqwest")]
+    timeout: Option<std::time::Duration>,
+
     // Internal use only:
     // ------------------
 
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
     query: Option<String>,
 
+    /// Whether the request is being paginated by `execute_all` or `stream`.
+    /// Pages are not cached, since page tokens expire and Google's results may
+    /// change between the first page and the next.
+    #[cfg(feature = "enable-reqwest")]
+    paginating: bool,
+
 } // struct
\ No newline at end of file
@@ -1,4 +1,4 @@
-use crate::client::GoogleMapsClient;
+use crate::client::ClientRef;
 use crate::places::place_search::text_search::request::Request;
 
 // =============================================================================
@@ -21,14 +21,14 @@ impl<'a> Request<'a> {
     /// results based on their perceived relevance.
 
     pub fn new(
-        client: &GoogleMapsClient,
+        client: impl Into<ClientRef<'a>>,
         query: String,
-    ) -> Request {
+    ) -> Self {
 
         // Instantiate struct and return it to caller:
         Request {
             // Required parameters:
-            client,
+            client: client.into(),
             input: query,
             // Optional parameters:
             language: None,
@@ -40,8 +40,12 @@ impl<'a> Request<'a> {
             radius: None,
             region: None,
             place_types: None,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
             // Internal use only:
             query: None,
+            #[cfg(feature = "enable-reqwest")]
+            paginating: false,
         } // struct
 
     } // fn
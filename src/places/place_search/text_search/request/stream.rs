@@ -0,0 +1,59 @@
+use crate::error::Error as GoogleMapsError;
+use crate::places::Place;
+use crate::places::place_search::text_search::request::Request as TextSearchRequest;
+use futures::stream::{self, Stream, TryStreamExt};
+
+// =============================================================================
+
+impl<'a> TextSearchRequest<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built, consuming the request, and returns a
+    /// stream of the places from every page of results.
+    ///
+    /// ## Description:
+    ///
+    /// Google returns up to 20 results per page, and up to 60 results in
+    /// total. The next page is only requested once the places from the
+    /// previous page have been consumed. Each page token is given time to
+    /// become valid before it is used. If a page can't be retrieved, the error
+    /// is the last item of the stream. Pages are not read from, or stored in,
+    /// the client's response cache.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let mut places = google_maps_client.text_search("pizza in New York".to_string())
+    ///     .stream();
+    ///
+    /// while let Some(place) = places.try_next().await? {
+    ///     println!("{:?}", place.name);
+    /// } // while
+    /// ```
+
+    pub fn stream(mut self) -> impl Stream<Item = Result<Place, GoogleMapsError>> + 'a {
+
+        self.paginating = true;
+
+        // The state is the request, and the page token of the next page to
+        // request. The first page has no page token, and `None` is used once
+        // there are no more pages:
+        stream::try_unfold((self, Some(None)), |(mut request, next_page)| async move {
+            let response = match next_page {
+                Some(None) => request.execute().await?,
+                Some(Some(next_page_token)) => request.get_next_page(next_page_token).await?,
+                None => return Ok::<_, GoogleMapsError>(None),
+            }; // match
+            let places = stream::iter(response.results.into_iter().map(Ok));
+            Ok(Some((places, (request, response.next_page_token.map(Some)))))
+        }) // try_unfold
+        .try_flatten()
+
+    } // fn
+
+} // impl
\ No newline at end of file
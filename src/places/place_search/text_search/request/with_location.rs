@@ -19,9 +19,9 @@ impl<'a> Request<'a> {
     /// `radius`.
 
     pub fn with_location(
-        &'a mut self,
+        &mut self,
         location: LatLng,
-    ) -> &'a mut Request {
+    ) -> &mut Self {
         // Set location in Request struct.
         self.location = Some(location);
         // Return modified Request struct to caller.
@@ -62,10 +62,10 @@ impl<'a> Request<'a> {
     /// * Text Search: 50,000 meters
 
     pub fn with_location_and_radius(
-        &'a mut self,
+        &mut self,
         location: LatLng,
         radius: u32,
-    ) -> &'a mut Request {
+    ) -> &mut Self {
         // Set location in Request struct.
         self.location = Some(location);
         // Set radius in Request struct.
@@ -13,7 +13,7 @@ impl<'a> Request<'a> {
     /// (most expensive), inclusive. The exact amount indicated by a specific
     /// value will vary from region to region.
 
-    pub fn with_max_price(&'a mut self, maxprice: u8) -> &'a mut Request {
+    pub fn with_max_price(&mut self, maxprice: u8) -> &mut Self {
         // Set maximum price in Request struct.
         self.maxprice = Some(maxprice);
         // Return modified Request struct to caller.
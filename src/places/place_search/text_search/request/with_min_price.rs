@@ -13,7 +13,7 @@ impl<'a> Request<'a> {
     /// (most expensive), inclusive. The exact amount indicated by a specific
     /// value will vary from region to region.
 
-    pub fn with_min_price(&'a mut self, minprice: u8) -> &'a mut Request {
+    pub fn with_min_price(&mut self, minprice: u8) -> &mut Self {
         // Set minimum price in Request struct.
         self.minprice = Some(minprice);
         // Return modified Request struct to caller.
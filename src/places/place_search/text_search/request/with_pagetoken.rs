@@ -12,8 +12,11 @@ impl<'a> Request<'a> {
     /// Setting a `pagetoken` parameter will execute a search with the same
     /// parameters used previously — all parameters other than pagetoken will be
     /// ignored.
+    ///
+    /// * Use `execute_all()` or `stream()` to have every page of results
+    ///   requested for you instead.
 
-    pub fn with_pagetoken(&'a mut self, pagetoken: String) -> &'a mut Request {
+    pub fn with_pagetoken(&mut self, pagetoken: String) -> &mut Self {
         // Set page token in Request struct.
         self.pagetoken = Some(pagetoken);
         // Return modified Request struct to caller.
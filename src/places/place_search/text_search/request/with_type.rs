@@ -22,7 +22,7 @@ impl<'a> Request<'a> {
     /// (`keyword=cafe&type=cafe` or `keyword=parking&type=parking`) can yield
     /// `ZERO_RESULTS`.
 
-    pub fn with_type(&'a mut self, place_type: PlaceType) -> &'a mut Request {
+    pub fn with_type(&mut self, place_type: PlaceType) -> &mut Self {
         // Set location in Request struct.
         self.place_types = Some(vec![place_type]);
         // Return modified Request struct to caller.
@@ -46,7 +46,7 @@ impl<'a> Request<'a> {
     /// (`keyword=cafe&type=cafe` or `keyword=parking&type=parking`) can yield
     /// `ZERO_RESULTS`.
 
-    pub fn with_types(&'a mut self, place_types_slice: &[PlaceType]) -> &'a mut Request {
+    pub fn with_types(&mut self, place_types_slice: &[PlaceType]) -> &mut Self {
         // Add place_types to Request struct.
         match &mut self.place_types {
             // If there are no filters in the request struct, initialize field:
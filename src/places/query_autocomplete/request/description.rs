@@ -0,0 +1,98 @@
+//! Contains the `Description` struct. It is a client-independent, serializable
+//! description of a _Query Autocomplete_ request that may be persisted (for
+//! example, in a job queue) and later bound to a client for execution.
+
+use crate::client::ClientRef;
+use crate::places::query_autocomplete::request::Request;
+use crate::types::{Language, LatLng};
+
+// -----------------------------------------------------------------------------
+//
+/// A client-independent description of a _Query Autocomplete_ request. Use
+/// `Request::describe()` to obtain one, store it wherever you like using
+/// `serde`, and then call `bind()` to turn it back into a `Request` that may
+/// be executed.
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Description {
+    /// The text string on which to search.
+    pub input: String,
+    /// The language in which to return results.
+    pub language: Option<Language>,
+    /// The point around which to retrieve place information.
+    pub location: Option<LatLng>,
+    /// The position of the last character that the service uses to match
+    /// predictions.
+    pub offset: Option<u8>,
+    /// The distance (in meters) within which to return place results.
+    pub radius: Option<u32>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Request<'_>> for Description {
+    /// Describes a _Query Autocomplete_ request, leaving its client behind.
+    fn from(request: &Request<'_>) -> Self {
+        Description {
+            input: request.input.clone(),
+            language: request.language.clone(),
+            location: request.location.clone(),
+            offset: request.offset,
+            radius: request.radius,
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Description {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Binds this description to a client, producing a `Request` that is ready
+    /// to be executed.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct. This may
+    ///   be a reference to a `GoogleMapsClient` or an `Arc<GoogleMapsClient>`.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let description: QueryAutocompleteRequestDescription = serde_json::from_str(&job)?;
+    /// let response = description.bind(&google_maps_client).execute().await?;
+    /// ```
+
+    pub fn bind<'a>(self, client: impl Into<ClientRef<'a>>) -> Request<'a> {
+        Request {
+            client: client.into(),
+            input: self.input,
+            language: self.language,
+            location: self.location,
+            offset: self.offset,
+            radius: self.radius,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
+            query: None,
+        } // struct
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::traits::RequestDescription for Description {
+
+    type Response = crate::places::place_autocomplete::response::Response;
+
+    /// Binds this description to the client and executes the request.
+    fn execute(
+        self,
+        client: &crate::client::GoogleMapsClient,
+    ) -> futures::future::BoxFuture<'_, Result<Self::Response, crate::error::Error>> {
+        Box::pin(async move { self.bind(client).execute().await })
+    } // fn
+
+} // impl
\ No newline at end of file
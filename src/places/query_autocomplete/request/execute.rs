@@ -21,7 +21,7 @@ impl<'a> QueryAutocompleteRequest<'a> {
     /// This method accepts no arguments.
 
     pub async fn execute(
-        &'a mut self
+        &mut self
     ) -> Result<PlaceAutocompleteResponse, GoogleMapsError> {
 
         self.build().get().await
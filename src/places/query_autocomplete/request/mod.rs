@@ -4,19 +4,27 @@
 //! structs, methods) for building your Google Maps Platform request.
 
 mod build;
+mod describe;
 #[cfg(feature = "enable-reqwest")]
 mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
 #[cfg(feature = "enable-reqwest")]
 mod get;
+#[cfg(feature = "enable-reqwest")]
+mod into_future;
 mod new;
 mod query_url;
 mod with_language;
 mod with_location;
 mod with_offset;
+#[cfg(feature = "enable-reqwest")]
+mod with_timeout;
+pub mod description;
 
 // -----------------------------------------------------------------------------
 
-use crate::{client::GoogleMapsClient, types::Language, types::LatLng};
+use crate::{client::ClientRef, types::Language, types::LatLng};
 
 // -----------------------------------------------------------------------------
 //
@@ -32,7 +40,7 @@ pub struct Request<'a> {
 
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
-    client: &'a GoogleMapsClient,
+    client: ClientRef<'a>,
 
     /// The text string on which to search. The Query Autocomplete service will
     /// return candidate matches based on this string and order results based on
@@ -107,6 +115,12 @@ pub struct Request<'a> {
     /// * Text Search: 50,000 meters
     radius: Option<u32>,
 
+    /// The deadline for the request, including rate limiter waits and
+    /// retries. It overrides the client's default timeout. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "enable-reqwest")]
+    timeout: Option<std::time::Duration>,
+
     // Internal use only:
     // ------------------
 
@@ -1,4 +1,4 @@
-use crate::client::GoogleMapsClient;
+use crate::client::ClientRef;
 use crate::places::query_autocomplete::request::Request;
 
 // =============================================================================
@@ -16,20 +16,22 @@ impl<'a> Request<'a> {
     /// * `input` ‧ The text string on which to search.
 
     pub fn new(
-        client: &GoogleMapsClient,
+        client: impl Into<ClientRef<'a>>,
         input: String,
-    ) -> Request {
+    ) -> Self {
 
         // Instantiate struct and return it to caller:
         Request {
             // Required parameters:
-            client,
+            client: client.into(),
             input,
             // Optional parameters:
             language: None,
             location: None,
             offset: None,
             radius: None,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
             // Internal use only:
             query: None,
         } // struct
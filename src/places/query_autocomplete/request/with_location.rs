@@ -36,10 +36,10 @@ impl<'a> Request<'a> {
     /// * Text Search: 50,000 meters
 
     pub fn with_location_and_radius(
-        &'a mut self,
+        &mut self,
         location: LatLng,
         radius: u32,
-    ) -> &'a mut Request {
+    ) -> &mut Self {
         // Set location in Request struct.
         self.location = Some(location);
         // Set radius in Request struct.
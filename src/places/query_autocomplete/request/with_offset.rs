@@ -17,7 +17,7 @@ impl<'a> Request<'a> {
     /// offset is supplied, the service will use the whole term. The offset
     /// should generally be set to the position of the text caret.
 
-    pub fn with_offset(&'a mut self, offset: u8) -> &'a mut Request {
+    pub fn with_offset(&mut self, offset: u8) -> &mut Self {
         // Set offset in Request struct.
         self.offset = Some(offset);
         // Return modified Request struct to caller.
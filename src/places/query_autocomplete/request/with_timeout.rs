@@ -0,0 +1,35 @@
+use crate::places::query_autocomplete::request::Request;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Sets a deadline for this Query Autocomplete request. If the query
+    /// predictions have not been returned in time, `Error::Timeout` is returned
+    /// instead. The deadline includes waiting on the client's rate limits and
+    /// any retries.
+    ///
+    /// It overrides the client's default timeout, if one was set using
+    /// `GoogleMapsClient::with_timeout`. As with Place Autocomplete, stale
+    /// predictions are rarely useful, so a short deadline is often appropriate.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `timeout` ‧ The maximum time that the request may take.
+    ///
+    /// ## Example:
+    ///
+    /// * Give up on the predictions after 1 second:
+    /// ```rust
+    /// .with_timeout(std::time::Duration::from_secs(1))
+    /// ```
+
+    pub fn with_timeout(&mut self, timeout: Duration) -> &mut Self {
+        // Set timeout in Request struct.
+        self.timeout = Some(timeout);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -18,6 +18,7 @@ pub use chrono_tz::Tz;
 
 pub use crate::{
     client::GoogleMapsClient as ClientSettings,
+    client::ClientRef,
     client::GoogleMapsClient,
     error::Error as GoogleMapsError,
     error::Error,
@@ -34,7 +35,7 @@ pub use crate::types::country::Country;
 pub use crate::types::geometry::Geometry;
 #[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "geocoding", feature = "places", feature = "time_zone"))]
 pub use crate::types::language::Language;
-#[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "elevation", feature = "geocoding", feature = "places", feature = "roads", feature = "time_zone"))]
+#[cfg(any(feature = "autocomplete", feature = "directions", feature = "distance_matrix", feature = "elevation", feature = "geocoding", feature = "geolocation", feature = "places", feature = "roads", feature = "time_zone"))]
 pub use crate::types::latlng::LatLng;
 #[cfg(any(feature = "geocoding", feature = "places", feature = "geocoding"))]
 pub use crate::types::location_type::LocationType;
@@ -51,6 +52,8 @@ pub use crate::types::region::Region;
 
 #[cfg(feature = "enable-reqwest")]
 pub use crate::request_rate::api::Api;
+#[cfg(feature = "enable-reqwest")]
+pub use crate::retry_policy::RetryPolicy;
 
 // -----------------------------------------------------------------------------
 
@@ -93,6 +96,7 @@ pub use crate::directions::{
 #[cfg(feature = "directions")]
 pub use crate::directions::{
     error::Error as DirectionsError,
+    request::description::Description as DirectionsRequestDescription,
     request::Request as DirectionsRequest,
     response::{
         directions_distance::DirectionsDistance,
@@ -105,6 +109,7 @@ pub use crate::directions::{
 #[cfg(feature = "distance_matrix")]
 pub use crate::distance_matrix::{
     error::Error as DistanceMatrixError,
+    request::description::Description as DistanceMatrixRequestDescription,
     request::Request as DistanceMatrixRequest,
     response::Response as DistanceMatrixResponse,
     response::status::Status as DistanceMatrixStatus,
@@ -116,6 +121,7 @@ pub use crate::distance_matrix::{
 pub use crate::elevation::{
     error::Error as ElevationError,
     request::{
+        description::Description as ElevationRequestDescription,
         locations::Locations as ElevationLocations,
         Request as ElevationRequest
     }, // request
@@ -133,6 +139,7 @@ pub use crate::geocoding::{
     error::Error as GeocodingError,
     forward::{
         component::Component as GeocodingComponent,
+        description::Description as GeocodingRequestDescription,
         ForwardRequest as GeocodingRequest,
     }, // forward
     response::{
@@ -141,14 +148,36 @@ pub use crate::geocoding::{
         Response as GeocodingResponse,
         status::Status as GeocodingStatus,
     }, // response
-    reverse::ReverseRequest as GeocodingReverseRequest,
+    reverse::{
+        description::Description as GeocodingReverseRequestDescription,
+        ReverseRequest as GeocodingReverseRequest,
+    }, // reverse
 }; // crate::geocoding
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "geolocation")]
+pub use crate::geolocation::{
+    error::Error as GeolocationError,
+    request::{
+        cell_tower::CellTower,
+        description::Description as GeolocationRequestDescription,
+        radio_type::RadioType,
+        Request as GeolocationRequest,
+        wifi_access_point::WiFiAccessPoint,
+    }, // request
+    response::{
+        reason::Reason as GeolocationReason,
+        Response as GeolocationResponse,
+    }, // response
+}; // crate::geolocation
+
+// -----------------------------------------------------------------------------
+
 #[cfg(feature = "time_zone")]
 pub use crate::time_zone::{
     error::Error as TimeZoneError,
+    request::description::Description as TimeZoneRequestDescription,
     request::Request as TimeZoneRequest,
     response::{
         Response as TimeZoneResponse,
@@ -171,6 +200,7 @@ pub use crate::places::place_autocomplete::{
     }, // response
     request::{
         autocomplete_type::AutocompleteType,
+        description::Description as PlaceAutocompleteRequestDescription,
         Request as PlaceAutocompleteRequest,
     }, // request
 }; // crate::places::place_autocomplete
@@ -184,6 +214,10 @@ pub use crate::places::place_autocomplete::{
     }, // request
 }; // crate::places::place_autocomplete
 
+#[cfg(feature = "autocomplete")]
+pub use crate::places::query_autocomplete::request::description::Description
+    as QueryAutocompleteRequestDescription;
+
 // -----------------------------------------------------------------------------
 
 #[cfg(feature = "places")]
@@ -204,8 +238,39 @@ pub use crate::places::{
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "places")]
+pub use crate::places::photo::{
+    request::description::Description as PlacePhotoRequestDescription,
+    request::Request as PlacePhotoRequest,
+    response::Response as PlacePhotoResponse,
+}; // photo
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "places")]
+pub use crate::places::place_search::find_place::{
+    request::description::Description as FindPlaceRequestDescription,
+    request::input_type::InputType,
+    request::location_bias::LocationBias,
+    request::Request as FindPlaceRequest,
+    response::Response as FindPlaceResponse,
+}; // find_place
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "places")]
+pub use crate::places::place_search::nearby_search::{
+    request::description::Description as NearbySearchRequestDescription,
+    request::rank_by::RankBy,
+    request::Request as NearbySearchRequest,
+    response::Response as NearbySearchResponse,
+}; // nearby_search
+
+// -----------------------------------------------------------------------------
+
 #[cfg(feature = "places")]
 pub use crate::places::place_search::text_search::{
+    request::description::Description as TextSearchRequestDescription,
     request::Request as TextSearchRequest,
     response::Response as TextSearchResponse,
 }; // text_search
@@ -215,6 +280,7 @@ pub use crate::places::place_search::text_search::{
 #[cfg(feature = "places")]
 pub use crate::places::place_details::{
     field::Field,
+    request::description::Description as PlaceDetailsRequestDescription,
     request::Request as PlaceDetailsRequest,
     response::Response as PlaceDetailsResponse,
     sort_order::SortOrder,
@@ -235,6 +301,7 @@ pub use crate::roads::{
 #[cfg(feature = "roads")]
 pub use crate::roads::snap_to_roads::{
     response::Response as SnapToRoadsResponse,
+    request::description::Description as SnapToRoadsRequestDescription,
     request::Request as SnapToRoadsRequest,
 }; // crate::roads::snap_to_roads
 
@@ -243,5 +310,6 @@ pub use crate::roads::snap_to_roads::{
 #[cfg(feature = "roads")]
 pub use crate::roads::nearest_roads::{
     response::Response as NearestRoadsResponse,
+    request::description::Description as NearestRoadsRequestDescription,
     request::Request as NearestRoadsRequest,
 }; // crate::roads::nearest_roads
\ No newline at end of file
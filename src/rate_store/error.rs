@@ -0,0 +1,38 @@
+//! Rate store error types and error messages.
+
+// -----------------------------------------------------------------------------
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced by a rate store. When the rate store fails, the
+/// client logs the error and falls back to its in-memory rate limiter, so that
+/// requests are not blocked.
+
+#[derive(Debug, Diagnostic, Error)]
+#[diagnostic(url(docsrs))]
+pub enum Error {
+
+    /// The rate store could not be read or written.
+    #[error("rate store could not be accessed: {0}")]
+    #[diagnostic(code(google_maps::rate_store::io))]
+    Io(#[from] std::io::Error),
+
+    /// The rate store's contents could not be parsed.
+    #[error("rate store could not be parsed: {0}")]
+    #[diagnostic(code(google_maps::rate_store::parse))]
+    Parse(#[from] serde_json::Error),
+
+    /// The rate store's lock could not be acquired in time.
+    #[error("rate store lock `{0}` could not be acquired")]
+    #[diagnostic(code(google_maps::rate_store::lock_timeout))]
+    LockTimeout(String),
+
+    /// A user-supplied rate store generated an error.
+    #[error("rate store failed: {0}")]
+    #[diagnostic(code(google_maps::rate_store::custom))]
+    Custom(Box<dyn std::error::Error + Send + Sync>),
+
+} // enum Error
\ No newline at end of file
@@ -0,0 +1,268 @@
+//! Contains the `FileRateStore` struct and its associated traits. It stores
+//! the rate limiter state in a JSON file that is guarded by an advisory lock.
+
+use crate::rate_store::{Error, RateStore};
+use fs2::FileExt;
+use futures::future::BoxFuture;
+use std::{
+    collections::BTreeMap,
+    fs::{File, OpenOptions},
+    path::PathBuf,
+    time::{Duration, SystemTime},
+}; // use std
+
+// -----------------------------------------------------------------------------
+
+/// How long to wait between attempts to acquire the lock.
+const LOCK_RETRY_INTERVAL: Duration = Duration::from_millis(5);
+
+/// How long to keep trying to acquire the lock before giving up.
+const LOCK_TIMEOUT: Duration = Duration::from_secs(10);
+
+// -----------------------------------------------------------------------------
+//
+/// A rate store that keeps the rate limiter state in a JSON file. Updates are
+/// serialized across processes using an operating system advisory lock (i.e.
+/// `flock` or `LockFileEx`) on a lock file that is created next to the state
+/// file (for example, `rate_limits.json.lock`). The operating system releases
+/// the lock if the process that holds it crashes, so a lock is never left
+/// behind. The lock file itself is left in place.
+///
+/// The file is small (one number per API) and is only held locked while it is
+/// read and written, so it may be shared by many processes. File system access
+/// is performed using `tokio::fs` so that the async runtime is not blocked.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct FileRateStore {
+
+    /// The path of the JSON file that the rate limiter state is stored in.
+    pub path: PathBuf,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl FileRateStore {
+
+    /// Initializes a rate store that keeps its state in the file at `path`.
+    /// The file and its directory are created when the first request is made.
+    pub fn new(path: impl Into<PathBuf>) -> FileRateStore {
+        FileRateStore { path: path.into() }
+    } // fn
+
+    /// Returns the path of the lock file that guards the state file.
+    fn lock_path(&self) -> PathBuf {
+        let mut lock_path = self.path.clone().into_os_string();
+        lock_path.push(".lock");
+        PathBuf::from(lock_path)
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl RateStore for FileRateStore {
+
+    /// Locks the state file, reads the value under the key, stores the updated
+    /// value and unlocks the state file.
+    fn update<'a>(
+        &'a self,
+        key: &'a str,
+        update: &'a mut (dyn FnMut(Option<u64>) -> u64 + Send),
+    ) -> BoxFuture<'a, Result<(), Error>> {
+        Box::pin(async move {
+
+            if let Some(directory) = self.path.parent() {
+                tokio::fs::create_dir_all(directory).await?;
+            } // if
+
+            let _lock = FileLock::acquire(self.lock_path()).await?;
+
+            let mut state: BTreeMap<String, u64> = match tokio::fs::read(&self.path).await {
+                Ok(json) if !json.is_empty() => serde_json::from_slice(&json)?,
+                Ok(_empty) => BTreeMap::new(),
+                Err(error) if error.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+                Err(error) => return Err(error.into()),
+            }; // match
+
+            let value = update(state.get(key).copied());
+            state.insert(key.to_string(), value);
+
+            // Write to a temporary file and rename it over the state file, so
+            // that a crash can't leave a half-written state file behind:
+            let mut temporary_path = self.path.clone().into_os_string();
+            temporary_path.push(".tmp");
+            tokio::fs::write(&temporary_path, serde_json::to_vec(&state)?).await?;
+            tokio::fs::rename(&temporary_path, &self.path).await?;
+
+            Ok(())
+
+        }) // Box::pin
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// An exclusive advisory lock on a lock file. The lock is released when this
+/// structure is dropped, or by the operating system if the process exits.
+
+struct FileLock(File);
+
+impl FileLock {
+
+    /// Opens the lock file and locks it, waiting for any other process that
+    /// holds the lock to release it first.
+    async fn acquire(path: PathBuf) -> Result<FileLock, Error> {
+
+        // Opening the file may block, so it's done on a blocking thread:
+        let (file, path) = tokio::task::spawn_blocking(move || {
+            let file = OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .truncate(false)
+                .open(&path);
+            (file, path)
+        }) // spawn_blocking
+        .await
+        .map_err(std::io::Error::other)?;
+        let file = file?;
+
+        // Attempting to lock the file never blocks, so the lock is polled
+        // rather than tying up a blocking thread while another process holds
+        // it:
+        let started = SystemTime::now();
+        loop {
+            match file.try_lock_exclusive() {
+                Ok(()) => return Ok(FileLock(file)),
+                Err(error) if error.kind() == fs2::lock_contended_error().kind() => {
+                    if started.elapsed().unwrap_or_default() > LOCK_TIMEOUT {
+                        return Err(Error::LockTimeout(path.display().to_string()))
+                    } // if
+                    tokio::time::sleep(LOCK_RETRY_INTERVAL).await;
+                }, // Err
+                Err(error) => return Err(error.into()),
+            } // match
+        } // loop
+
+    } // fn
+
+} // impl
+
+impl Drop for FileLock {
+    /// Releases the lock.
+    fn drop(&mut self) {
+        let _ = self.0.unlock();
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use crate::rate_store::{FileRateStore, RateStore};
+    use fs2::FileExt;
+    use std::{fs::File, time::Duration};
+
+    /// Reads the value under the key and stores it, incremented by one.
+    async fn increment(store: &FileRateStore, key: &str) -> Option<u64> {
+        let mut previous = None;
+        store.update(key, &mut |value| {
+            previous = value;
+            value.unwrap_or(0) + 1
+        }).await.unwrap();
+        previous
+    } // fn
+
+    #[tokio::test]
+    async fn state_persists_across_stores() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("nested").join("rate_limits.json");
+
+        assert_eq!(increment(&FileRateStore::new(&path), "geocoding").await, None);
+        assert_eq!(increment(&FileRateStore::new(&path), "geocoding").await, Some(1));
+        assert_eq!(increment(&FileRateStore::new(&path), "time_zone").await, None);
+        assert_eq!(increment(&FileRateStore::new(&path), "geocoding").await, Some(2));
+    } // fn
+
+    #[tokio::test]
+    async fn state_is_written_through_a_temporary_file() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("rate_limits.json");
+        let temporary_path = directory.path().join("rate_limits.json.tmp");
+
+        // A temporary file left behind by a crash is replaced, and doesn't
+        // affect the state:
+        std::fs::write(&temporary_path, "{ \"geocoding\": 10").unwrap();
+
+        let store = FileRateStore::new(&path);
+        increment(&store, "geocoding").await;
+        increment(&store, "geocoding").await;
+
+        assert!(!temporary_path.exists());
+        let json = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(json, r#"{"geocoding":2}"#);
+    } // fn
+
+    #[tokio::test]
+    async fn empty_state_file_is_treated_as_no_state() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("rate_limits.json");
+        std::fs::write(&path, "").unwrap();
+        assert_eq!(increment(&FileRateStore::new(&path), "geocoding").await, None);
+    } // fn
+
+    #[tokio::test]
+    async fn corrupt_state_file_is_an_error() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("rate_limits.json");
+        std::fs::write(&path, "{ \"geocoding\": ").unwrap();
+        let result = FileRateStore::new(&path).update("geocoding", &mut |_value| 1).await;
+        assert!(result.is_err());
+    } // fn
+
+    #[tokio::test]
+    async fn update_waits_for_the_lock() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("rate_limits.json");
+
+        // Another process holds the lock:
+        let lock = File::create(directory.path().join("rate_limits.json.lock")).unwrap();
+        lock.lock_exclusive().unwrap();
+
+        let store = FileRateStore::new(&path);
+        let update = tokio::spawn(async move { increment(&store, "geocoding").await });
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(!update.is_finished());
+        assert!(!path.exists());
+
+        lock.unlock().unwrap();
+        assert_eq!(update.await.unwrap(), None);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), r#"{"geocoding":1}"#);
+    } // fn
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn concurrent_updates_are_serialized() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("rate_limits.json");
+
+        // Two handles on the same path, as if in two processes. Without the
+        // lock, concurrent read-modify-writes would lose increments:
+        let first = FileRateStore::new(&path);
+        let second = FileRateStore::new(&path);
+        let tasks: Vec<_> = (0..20)
+            .map(|index| {
+                let store = if index % 2 == 0 { first.clone() } else { second.clone() };
+                tokio::spawn(async move { increment(&store, "geocoding").await })
+            }) // map
+            .collect();
+        for task in tasks {
+            task.await.unwrap();
+        } // for
+
+        assert_eq!(increment(&first, "geocoding").await, Some(20));
+    } // fn
+
+} // mod
\ No newline at end of file
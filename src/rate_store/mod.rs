@@ -0,0 +1,77 @@
+//! Contains the `RateStore` trait and its implementations. By default, the
+//! `GoogleMapsClient` keeps its rate limiter state in memory, so limits reset
+//! whenever the program restarts and are not shared between processes. A rate
+//! store persists the rate limiter state so that a limit such as
+//! `with_rate(Api::Geocoding, ...)` holds across restarts and across every
+//! process that shares the store.
+//!
+//! The rate limiter is a _generic cell rate algorithm_ (GCRA). For each API it
+//! only needs to remember one number: the _theoretical arrival time_ of the
+//! next request, stored as microseconds since the UNIX epoch. A store must be
+//! able to atomically read, update and write this number.
+//!
+//! * `FileRateStore` ‧ Stores the rate limiter state in a JSON file that is
+//!   guarded by an operating system advisory lock. It is suitable for several
+//!   processes on one host.
+//!
+//! Any other storage (for example, Redis or a SQL database) may be used by
+//! implementing the `RateStore` trait.
+
+pub mod error;
+pub mod file_rate_store;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::rate_store::{
+    error::Error,
+    file_rate_store::FileRateStore,
+}; // crate::rate_store
+
+// -----------------------------------------------------------------------------
+
+use futures::future::BoxFuture;
+
+// -----------------------------------------------------------------------------
+//
+/// Implement this trait to share the client's rate limiter state through a
+/// storage backend of your choice.
+///
+/// ## Example:
+///
+/// ```rust
+/// use futures::future::BoxFuture;
+/// use google_maps::rate_store::{Error, RateStore};
+///
+/// #[derive(Debug)]
+/// struct RedisRateStore { /* ... */ }
+///
+/// impl RateStore for RedisRateStore {
+///     fn update<'a>(
+///         &'a self,
+///         key: &'a str,
+///         update: &'a mut (dyn FnMut(Option<u64>) -> u64 + Send),
+///     ) -> BoxFuture<'a, Result<(), Error>> {
+///         Box::pin(async move {
+///             // WATCH key, GET key, compute `update(value)`, then MULTI / SET
+///             // key / EXEC, repeating if the transaction is aborted.
+///             Ok(())
+///         })
+///     }
+/// }
+/// ```
+
+pub trait RateStore: std::fmt::Debug + Send + Sync {
+
+    /// Atomically reads the value that is stored under the key (or `None` if
+    /// there is no value), passes it to `update`, and stores the value that
+    /// `update` returns. No other process may change the value in between.
+    ///
+    /// If the store uses optimistic concurrency, `update` may be called more
+    /// than once. Only the value from the final call should be stored.
+    fn update<'a>(
+        &'a self,
+        key: &'a str,
+        update: &'a mut (dyn FnMut(Option<u64>) -> u64 + Send),
+    ) -> BoxFuture<'a, Result<(), Error>>;
+
+} // trait
\ No newline at end of file
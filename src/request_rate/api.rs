@@ -9,6 +9,11 @@ use serde::{Deserialize, Serialize};
 /// Client can be set to have different request rates for `Directions` and
 /// `Elevation` requests. This `enum` is used to select which Google Maps API
 /// you would like to configure.
+///
+/// Some variants are groups of end-points. For example, settings for
+/// `Api::Places` apply to every Places API end-point, such as
+/// `Api::PlaceDetails` and `Api::TextSearch`, in addition to each end-point's
+/// own settings.
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub enum Api {
     /// This variant is used to select settings that are observed for _all_
@@ -18,13 +23,62 @@ pub enum Api {
     DistanceMatrix,
     Elevation,
     Geocoding,
+    Geolocation,
     TimeZone,
+    /// This variant is used to select settings that are observed for all
+    /// Places API end-points, in addition to the per-end-point settings.
     Places,
+    FindPlace,
+    NearbySearch,
+    PlaceAutocomplete,
+    PlaceDetails,
+    PlacePhoto,
+    QueryAutocomplete,
+    TextSearch,
+    /// This variant is used to select settings that are observed for all
+    /// Roads API end-points, in addition to the per-end-point settings.
     Roads,
+    NearestRoads,
+    SnapToRoads,
 } // enum
 
 // -----------------------------------------------------------------------------
 
+impl Api {
+
+    /// Returns the group that the API belongs to, if any. For example,
+    /// `Api::PlaceDetails` belongs to the `Api::Places` group.
+    pub fn group(&self) -> Option<Api> {
+        match self {
+            Api::FindPlace
+            | Api::NearbySearch
+            | Api::PlaceAutocomplete
+            | Api::PlaceDetails
+            | Api::PlacePhoto
+            | Api::QueryAutocomplete
+            | Api::TextSearch => Some(Api::Places),
+            Api::NearestRoads
+            | Api::SnapToRoads => Some(Api::Roads),
+            _ => None,
+        } // match
+    } // fn
+
+    /// Returns the APIs whose settings apply to requests for this API, from
+    /// the most general to the most precise: `Api::All`, the API's group (if
+    /// it has one), and the API itself.
+    pub fn scopes(&self) -> Vec<Api> {
+        let mut scopes = vec![Api::All];
+        scopes.extend(self.group());
+        if *self != Api::All {
+            scopes.push(self.clone());
+        } // if
+        scopes
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
 impl std::convert::From<&Api> for String {
     /// Converts an `Api` enum to a `String` that contains an API name.
     fn from(api: &Api) -> String {
@@ -34,9 +88,19 @@ impl std::convert::From<&Api> for String {
             Api::DistanceMatrix => String::from("Distance Matrix"),
             Api::Elevation => String::from("Elevation"),
             Api::Geocoding => String::from("Geocoding"),
+            Api::Geolocation => String::from("Geolocation"),
             Api::TimeZone => String::from("Time Zone"),
             Api::Places => String::from("Places"),
+            Api::FindPlace => String::from("Find Place"),
+            Api::NearbySearch => String::from("Nearby Search"),
+            Api::PlaceAutocomplete => String::from("Place Autocomplete"),
+            Api::PlaceDetails => String::from("Place Details"),
+            Api::PlacePhoto => String::from("Place Photo"),
+            Api::QueryAutocomplete => String::from("Query Autocomplete"),
+            Api::TextSearch => String::from("Text Search"),
             Api::Roads => String::from("Roads"),
+            Api::NearestRoads => String::from("Nearest Roads"),
+            Api::SnapToRoads => String::from("Snap to Roads"),
         } // match
     } // fn
 } // impl
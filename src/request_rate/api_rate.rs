@@ -1,27 +1,45 @@
 //! Contains the `ApiRate` struct and its associated traits. `ApiRate` contains
-//! the user's specified request rate and the system calculated effective
-//! current request rate.
+//! the user's specified request rate and the state of its rate limiter.
 
 use crate::request_rate::target_rate::TargetRate; // use
-use stream_throttle::ThrottlePool;
 use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex};
 
-/// Contains the user's specified request rate and the effective current request
-/// rate.
+/// Contains the user's specified request rate and the state of its rate
+/// limiter. Each `ApiRate` is a separate bucket: an API may have one bucket
+/// for a per-second rate and another for a per-day rate, for example.
 #[derive(Clone)]
 pub struct ApiRate {
     pub target_rate: TargetRate,
-    pub throttle_pool: Option<ThrottlePool>,
+    /// The _theoretical arrival time_ of the next request, in microseconds
+    /// since the UNIX epoch, or `None` if no request has been made yet. It is
+    /// shared between clones.
+    pub(crate) theoretical_arrival_time: Arc<Mutex<Option<u64>>>,
 } // struct
 
+impl ApiRate {
+    /// Initializes a rate limiter bucket for the specified target rate.
+    pub fn new(target_rate: TargetRate) -> Self {
+        ApiRate {
+            target_rate,
+            theoretical_arrival_time: Arc::new(Mutex::new(None)),
+        } // struct
+    } // fn
+
+    /// Returns the key that this bucket's state is kept under in a rate store.
+    /// For example, `Geocoding:requests:1000ms` or
+    /// `DistanceMatrix:units:86400000ms`.
+    pub(crate) fn store_key(&self, api: &impl fmt::Debug) -> String {
+        let unit = if self.target_rate.weighted { "units" } else { "requests" };
+        format!("{api:?}:{unit}:{}ms", self.target_rate.duration.as_millis())
+    } // fn
+} // impl
+
 impl std::default::Default for ApiRate {
     /// Returns a reasonable default values for the `ApiRate` type.
     fn default() -> Self {
-        ApiRate {
-            target_rate: TargetRate::default(),
-            throttle_pool: None,
-        } // struct
+        ApiRate::new(TargetRate::default())
     } // fn
 } // impl
 
@@ -29,14 +47,13 @@ impl fmt::Debug for ApiRate {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("ApiRate")
             .field("target_rate", &self.target_rate.to_string())
-            //.field("current_rate", &self.current_rate)
             .finish()
     }
 }
 
 impl PartialEq for ApiRate {
     fn eq(&self, other: &Self) -> bool {
-        self.target_rate == other.target_rate // && self.current_rate == other.current_rate
+        self.target_rate == other.target_rate
     }
 }
 
@@ -45,8 +62,6 @@ impl Eq for ApiRate {}
 
 impl Hash for ApiRate {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        // self.current_rate.hash(state);
         self.target_rate.hash(state);
     }
 }
-
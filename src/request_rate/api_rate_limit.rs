@@ -1,6 +1,9 @@
 use crate::request_rate::{
     api_rate::ApiRate,
+    reserve::reserve,
 }; // use crate::request_rate
+use std::sync::PoisonError;
+use std::time::Duration;
 
 impl ApiRate {
 
@@ -8,84 +11,23 @@ impl ApiRate {
     ///
     /// ## Description
     ///
-    /// This method does the actual rate limiting for an API.
-    /// If the current rate exceeds the targeted rate,
-    /// this method will put the thread to sleep until it is ready for the next
-    /// request.
+    /// This method does the actual rate limiting for an API, using the
+    /// in-memory rate limiter state. It reserves the tokens for a request and
+    /// returns how long the caller must wait before sending it.
     ///
-
-    pub async fn limit(&self) {
-        match &self.throttle_pool {
-            Some(wait_pool) => wait_pool.queue().await,
-            None => ()
-        }
-        /*
-        match self.current_rate.first_request {
-            // If this is the first request to the API, initialize the
-            // timer.
-            None => {
-                // For some reason this trace! macro can cause a stack
-                // overflow, so it has been commented out for now:
-                /* trace!("Rate limiting is enabled for the `{}` API. First request.", api.to_string()); */
-                self.current_rate.first_request = Some(Instant::now());
-                self.current_rate.request_count = 1;
-
-                //let throttle_rate = ThrottleRate::new(self.target_rate.requests as usize,  self.target_rate.duration);
-                //self.throttle_pool = Some(ThrottlePool::new(throttle_rate));
-            } // case
-
-            // If this is not the first request - calculate the elapsed,
-            // time & current rate, compare against the target rate, and
-            // sleep if necessary:
-            Some(_first_request) => {
-                // Output logging information:
-                // For some reason these trace! macros can cause a
-                // stack overflow, so they have been commented out for
-                // now:
-                /* trace!(
-                    "{} requests to the `{}` API this session. This API's session began {} ago.",
-                    rate.current_rate.request_count,
-                    api.to_string(),
-                    duration_to_string(&first_request.elapsed())
-                ); */
-                /* trace!(
-                    "Current rate: {}. Target rate: {}.",
-                    rate.current_rate, rate.target_rate
-                ); */
-
-                // Calculate the current rate and target rate:
-                //let target_rate = rate.target_rate.requests as f64
-                //    / rate.target_rate.duration.as_secs_f64();
-                //let current_rate = rate.current_rate.request_count as f64
-                //    / first_request.elapsed().as_secs_f64();
-
-                // If the current rate exceeds the targeted rate, put
-                // the thread to sleep:
-                /*
-                let difference = current_rate - target_rate;
-                if difference > 0.0 {
-                    let sleep_duration = std::time::Duration::from_secs(
-                        ((1.0 / target_rate) + difference).round() as u64,
-                    );
-                    info!(
-                        "Thread is sleeping for {}.",
-                        duration_to_string(&sleep_duration)
-                    );
-                    std::thread::sleep(sleep_duration);
-                } // if
-
-                 */
-                // wait for throttle
-                match &self.throttle_pool {
-                    Some(wait_pool) => wait_pool.queue().await,
-                    None => ()
-                }
-
-                // Increment the request counter:
-                self.current_rate.request_count += 1;
-            } // case
-        } // match
-        */
+    /// ## Arguments:
+    ///
+    /// * `tokens` ‧ How many tokens the request consumes from this bucket.
+    ///
+    /// * `now` ‧ The current time, in microseconds since the UNIX epoch.
+
+    pub(crate) fn limit(&self, tokens: u32, now: u64) -> Duration {
+        let mut theoretical_arrival_time = self.theoretical_arrival_time
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let (next, wait) = reserve(*theoretical_arrival_time, now, &self.target_rate, tokens);
+        *theoretical_arrival_time = Some(next);
+        wait
     } // fn
 
-} // impl
\ No newline at end of file
+} // impl
@@ -2,9 +2,9 @@ use crate::request_rate::{
     api::Api,
     duration_to_string::duration_to_string,
     RequestRate,
+    reserve::reserve,
 }; // use crate::request_rate
-use futures::future;
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 impl RequestRate {
 
@@ -12,32 +12,67 @@ impl RequestRate {
     ///
     /// ## Description
     ///
-    /// This method performs rate limiting, using the throttler under rate_map
-    /// specified by the list of apis, which was calculated using targeted requests/duration
-    /// rates during initialization. If the current rate exceeds any of the targeted rate,
-    /// this method will put the thread to sleep until it is ready for the next
-    /// request.
+    /// This method performs rate limiting, using the rate limiter buckets
+    /// under rate_map specified by the list of apis. A request's tokens are
+    /// reserved from every bucket. If the current rate exceeds any of the
+    /// targeted rates, this method will put the thread to sleep until it is
+    /// ready for the next request.
+    ///
+    /// If a rate store has been set, the rate limiter state is kept in the
+    /// store instead, so that it is shared with other processes and survives
+    /// restarts. If the store fails, the error is logged and the in-memory
+    /// rate limiter state is used instead.
     ///
     /// ## Arguments:
     ///
     /// * `apis` ‧ The APIs for which to observe the request rate limit.
-    pub async fn limit_apis(&self, apis: Vec<&Api>) {
-        let mut limit_futures = Vec::new();
-        for (key, val) in self.rate_map.iter() {
-            if apis.contains(&key) {
-                limit_futures.push(val.limit());
+    ///
+    /// * `weight` ‧ How many units the request consumes from weighted rate
+    ///   limits. For example, the number of elements in a Distance Matrix API
+    ///   request. Unweighted rate limits always consume one request.
+    ///
+    /// Returns how long the request waited under the rate limiter.
+    pub async fn limit_apis(&self, apis: &[Api], weight: u32) -> Duration {
+        let mut wait = Duration::ZERO;
+        for api in apis {
+            for api_rate in self.rate_map.get(api).into_iter().flatten() {
+                let tokens = if api_rate.target_rate.weighted { weight } else { 1 };
+                let api_wait = match &self.store {
+                    Some(store) => {
+                        let mut api_wait = Duration::ZERO;
+                        let mut update = |theoretical_arrival_time: Option<u64>| {
+                            let (theoretical_arrival_time, reserved_wait) =
+                                reserve(theoretical_arrival_time, now(), &api_rate.target_rate, tokens);
+                            api_wait = reserved_wait;
+                            theoretical_arrival_time
+                        }; // update
+                        match store.update(&api_rate.store_key(api), &mut update).await {
+                            Ok(()) => api_wait,
+                            Err(error) => {
+                                tracing::warn!("Rate store failed for the {api} API, using in-memory rate limiter: {error}");
+                                api_rate.limit(tokens, now())
+                            } // Err
+                        } // match
+                    } // Some
+                    None => api_rate.limit(tokens, now()),
+                }; // match
+                wait = wait.max(api_wait);
+            } // for
+        } // for
+        if !wait.is_zero() {
+            if wait.as_millis() > 10 {
+                tracing::debug!("Waiting for {} under rate limiter.", duration_to_string(&wait))
             }
+            tokio::time::sleep(wait).await;
         }
-        let start = SystemTime::now();
-        future::join_all(limit_futures).await;
-        let wait_time = SystemTime::now().duration_since(start);
-        match wait_time {
-            Ok(duration) => {
-                if duration.as_millis() > 10 {
-                    tracing::debug!("Waited for {} under rate limiter.", duration_to_string(&duration))
-                }
-            },
-            _ => tracing::warn!("Clock went backwards!")
-        }
+        wait
     }
-} // impl
\ No newline at end of file
+} // impl
+
+/// Returns the current time, in microseconds since the UNIX epoch.
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_micros() as u64
+}
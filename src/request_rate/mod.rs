@@ -3,44 +3,88 @@
 //! this module to set request rates. Use the `GoogleMapsClient` methods
 //! instead.**
 
+#[cfg(feature = "enable-reqwest")]
 mod api_rate;
-mod duration_to_string;
+#[cfg(feature = "enable-reqwest")]
+pub(crate) mod duration_to_string;
+#[cfg(feature = "enable-reqwest")]
 mod duration_unit;
+#[cfg(feature = "enable-reqwest")]
 mod limit;
+#[cfg(feature = "enable-reqwest")]
 mod rate_to_string;
+#[cfg(feature = "enable-reqwest")]
+mod reserve;
+#[cfg(feature = "enable-reqwest")]
 mod target_rate;
+#[cfg(feature = "enable-reqwest")]
 mod with_rate;
 pub mod api;
+#[cfg(feature = "enable-reqwest")]
 pub mod api_rate_limit;
+#[cfg(feature = "enable-reqwest")]
 mod current_rate;
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "enable-reqwest")]
+use crate::rate_store::RateStore;
+#[cfg(feature = "enable-reqwest")]
 use crate::request_rate::api_rate::ApiRate;
+#[cfg(feature = "enable-reqwest")]
 use crate::request_rate::api::Api;
-use std::collections::HashMap;
+#[cfg(feature = "enable-reqwest")]
+use std::{collections::HashMap, sync::Arc};
 
 // -----------------------------------------------------------------------------
 //
 /// Contains the request rates for the Google Maps Platform and the individual
 /// Google Maps APIs.
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg(feature = "enable-reqwest")]
+#[derive(Clone, Debug)]
 pub struct RequestRate {
     /// Used to specify the request rate for _all_ APIs in addition to the
     /// per-API request rates. The `Api::All` request rate will be observed
     /// first, then the per-API request rate such as `Api::Directions` will be
     /// observed afterward.
-    pub rate_map: HashMap<Api, ApiRate>,
+    ///
+    /// Each API may have several rate limiter buckets, for example a
+    /// per-second rate and a per-day rate. Every bucket is observed.
+    pub rate_map: HashMap<Api, Vec<ApiRate>>,
+    /// Optional store that the rate limiter state is kept in, so that rate
+    /// limits are shared between processes and survive restarts. When `None`,
+    /// the rate limiter state is kept in memory.
+    pub store: Option<Arc<dyn RateStore>>,
 } // struct
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "enable-reqwest")]
 impl std::default::Default for RequestRate {
     /// Returns default values (empty) for the `RequestRate` struct.
     fn default() -> Self {
         RequestRate {
             rate_map: HashMap::new(),
+            store: None,
         } // struct
     } // fn
-} // impl
\ No newline at end of file
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl PartialEq for RequestRate {
+    /// Rate limits are equal if they have the same target rates and share the
+    /// same rate store (or neither has one).
+    fn eq(&self, other: &Self) -> bool {
+        self.rate_map == other.rate_map && match (&self.store, &other.store) {
+            (Some(store), Some(other_store)) => Arc::ptr_eq(store, other_store),
+            (None, None) => true,
+            _ => false,
+        } // match
+    } // fn
+} // impl
+
+#[cfg(feature = "enable-reqwest")]
+impl Eq for RequestRate {}
\ No newline at end of file
@@ -0,0 +1,104 @@
+use crate::request_rate::target_rate::TargetRate;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+//
+/// This function is not for public consumption. It is for internal use only.
+///
+/// ## Description
+///
+/// Reserves tokens using the _generic cell rate algorithm_ (GCRA). Each token
+/// is spaced `duration / requests` apart, with bursts of up to `requests`
+/// tokens allowed after an idle period. A request that consumes more tokens
+/// than the bucket holds is allowed once the bucket has had time to refill.
+///
+/// Returns the updated _theoretical arrival time_, which should be stored, and
+/// how long the caller must wait before sending its request. Times are
+/// microseconds since the UNIX epoch, so that they may be shared between
+/// processes.
+///
+/// ## Arguments:
+///
+/// * `theoretical_arrival_time` ‧ The stored theoretical arrival time, or
+///   `None` if no request has been made yet.
+///
+/// * `now` ‧ The current time.
+///
+/// * `target_rate` ‧ The rate to observe. It must not be zero.
+///
+/// * `tokens` ‧ How many tokens the request consumes.
+
+pub(crate) fn reserve(
+    theoretical_arrival_time: Option<u64>,
+    now: u64,
+    target_rate: &TargetRate,
+    tokens: u32,
+) -> (u64, Duration) {
+
+    let duration = target_rate.duration.as_micros();
+    let increment = duration * u128::from(tokens) / u128::from(target_rate.requests.max(1));
+    let increment = u64::try_from(increment).unwrap_or(u64::MAX);
+
+    // A theoretical arrival time in the past means the API has been idle:
+    let theoretical_arrival_time = theoretical_arrival_time
+        .unwrap_or(now)
+        .max(now)
+        .saturating_add(increment);
+
+    let allowed_at = theoretical_arrival_time.saturating_sub(duration as u64);
+    let wait = Duration::from_micros(allowed_at.saturating_sub(now));
+
+    (theoretical_arrival_time, wait)
+
+} // fn
+
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    /// Ten requests per second, or one request every 100 ms.
+    fn ten_per_second() -> TargetRate {
+        TargetRate { requests: 10, duration: Duration::from_secs(1), weighted: false }
+    } // fn
+
+    #[test]
+    fn first_request_is_allowed_immediately() {
+        let (theoretical_arrival_time, wait) = reserve(None, 5_000_000, &ten_per_second(), 1);
+        assert_eq!(theoretical_arrival_time, 5_100_000);
+        assert_eq!(wait, Duration::ZERO);
+    } // fn
+
+    #[test]
+    fn burst_of_requests_is_allowed_then_spaced() {
+        let target_rate = ten_per_second();
+        let now = 5_000_000;
+        let mut theoretical_arrival_time = None;
+        for _request in 0..10 {
+            let (tat, wait) = reserve(theoretical_arrival_time, now, &target_rate, 1);
+            assert_eq!(wait, Duration::ZERO);
+            theoretical_arrival_time = Some(tat);
+        } // for
+        let (tat, wait) = reserve(theoretical_arrival_time, now, &target_rate, 1);
+        assert_eq!(tat, 6_100_000);
+        assert_eq!(wait, Duration::from_millis(100));
+    } // fn
+
+    #[test]
+    fn idle_period_refills_the_bucket() {
+        let (_tat, wait) = reserve(Some(1_000_000), 60_000_000, &ten_per_second(), 1);
+        assert_eq!(wait, Duration::ZERO);
+    } // fn
+
+    #[test]
+    fn weighted_request_consumes_its_tokens() {
+        let target_rate = TargetRate { requests: 100, duration: Duration::from_secs(1), weighted: true };
+        let (tat, wait) = reserve(None, 0, &target_rate, 150);
+        assert_eq!(tat, 1_500_000);
+        assert_eq!(wait, Duration::from_millis(500));
+    } // fn
+
+} // mod
\ No newline at end of file
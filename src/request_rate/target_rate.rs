@@ -6,27 +6,37 @@ use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
 /// Contains the user's specified request rate in the form of _requests_ per
-/// _duration_.
+/// _duration_, or for weighted rates, _units_ per _duration_.
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct TargetRate {
-    /// Limit the maximum number of requests.
-    pub requests: u16,
+    /// Limit the maximum number of requests. For weighted rates, this is the
+    /// maximum number of units, such as Distance Matrix API elements.
+    pub requests: u32,
     /// Limit the maximum number of requests per user-specified _duration_. For
     /// example this field may define _requests_ per _second_, per _minute_,
     /// per _day_, and so on. This is done by using the `std::time::Duration`
     /// methods.
     pub duration: Duration,
+    /// Whether each request consumes its weight (for example, the number of
+    /// elements in a Distance Matrix API request) rather than one request.
+    #[serde(default)]
+    pub weighted: bool,
 } // struct
 
 impl std::convert::From<&TargetRate> for String {
     /// Converts a `TargetRate` enum to a `String` that contains a
     /// human-friendly & readable rate.
     fn from(target_rate: &TargetRate) -> String {
+        let (singular, plural) = if target_rate.weighted {
+            ("unit", "units")
+        } else {
+            ("request", "requests")
+        }; // if
         rate_to_string(
             &(target_rate.requests as u64),
             &target_rate.duration,
-            "request",
-            "requests",
+            singular,
+            plural,
         )
     } // fn
 } // impl
@@ -37,6 +47,7 @@ impl std::default::Default for TargetRate {
         TargetRate {
             requests: 0,  // disabled
             duration: Duration::from_secs(1),
+            weighted: false,
         } // struct
     } // fn
 } // impl
@@ -47,4 +58,4 @@ impl std::fmt::Display for TargetRate {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "{}", String::from(self))
     } // fn
-} // impl
\ No newline at end of file
+} // impl
@@ -5,7 +5,6 @@ use crate::request_rate::{
     target_rate::TargetRate,
 }; // use crate::request_rate
 use std::time::Duration;
-use stream_throttle::{ThrottleRate, ThrottlePool};
 
 // =============================================================================
 
@@ -16,16 +15,22 @@ impl RequestRate {
     /// Specifies the request rate for the selected API. _Do not use this method
     /// to set request rate limits, use `ClientSettings.with_rate()` instead_.
     ///
+    /// Each duration is a separate bucket. Setting a rate for a duration that
+    /// already has one replaces it, while rates for other durations are kept.
+    ///
     /// ## Arguments:
     ///
     /// * `api` ‧ Which Google Maps API are you setting the rate limit for? For
-    /// example, `Api::Directions`, `Api::DistanceMatrix`, `Api::Elevation`,
-    /// `Api::Geocoding`, `Api::TimeZone`, and so on. The `Api::All` rate limit
-    /// is applied to all Google Maps API requests _in addition_ to the per-API
-    /// rate limits.
+    ///   example, `Api::Directions`, `Api::DistanceMatrix`, `Api::Elevation`,
+    ///   `Api::Geocoding`, `Api::PlaceDetails`, `Api::TimeZone`, and so on. The
+    ///   `Api::All` rate limit is applied to all Google Maps API requests _in
+    ///   addition_ to the per-API rate limits. Likewise, group rate limits such
+    ///   as `Api::Places` and `Api::Roads` are applied to every end-point in
+    ///   the group, such as `Api::TextSearch` or `Api::SnapToRoads`.
     ///
     /// * `requests` ‧ The number of requests the client library is attempting
-    /// to target. For example, _2 requests_ per 1 hour.
+    ///   to target. For example, _2 requests_ per 1 hour. `0` removes the rate
+    ///   limit for the duration.
     ///
     /// * `duration` ‧ The duration for the targeted request rate. For example,
     /// 1 request _per 1 minute_. This can be defined using the
@@ -58,39 +63,69 @@ impl RequestRate {
         requests: u16,
         duration: Duration
     ) -> &mut RequestRate {
+        self.with_target_rate(api, TargetRate {
+            requests: u32::from(requests),
+            duration,
+            weighted: false,
+        }) // TargetRate
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Specifies a weighted rate for the selected API. _Do not use this method
+    /// to set rate limits, use `ClientSettings.with_weighted_rate()`
+    /// instead_.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `api` ‧ Which Google Maps API are you setting the rate limit for?
+    ///
+    /// * `units` ‧ The number of units the client library is attempting to
+    ///   target. For example, _1,000 elements_ per 1 minute. `0` removes the
+    ///   rate limit for the duration.
+    ///
+    /// * `duration` ‧ The duration for the targeted rate.
+
+    pub fn with_weighted_rate(
+        &mut self,
+        api: Api,
+        units: u32,
+        duration: Duration
+    ) -> &mut RequestRate {
+        self.with_target_rate(api, TargetRate {
+            requests: units,
+            duration,
+            weighted: true,
+        }) // TargetRate
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the target rate for the selected API, replacing any bucket with the
+    /// same duration and weighting. A target rate of zero removes the bucket.
+
+    fn with_target_rate(
+        &mut self,
+        api: Api,
+        target_rate: TargetRate,
+    ) -> &mut RequestRate {
+
+        // Select the rate limiter buckets for the API specified by the caller:
+        let api_rates = self.rate_map.entry(api).or_default();
 
-        // Select `RequestRate` field for the API specified by the caller.
-        let api_ref = self.rate_map.get_mut(&api); // api
-        let throttle_pool = match requests {
-            0 => None,
-            _ => {
-                let throttle_rate = ThrottleRate::new(requests as usize, duration);
-                Some(ThrottlePool::new(throttle_rate))
-            }
-        };
+        // Remove the bucket being replaced, if there is one:
+        api_rates.retain(|api_rate|
+            api_rate.target_rate.duration != target_rate.duration
+                || api_rate.target_rate.weighted != target_rate.weighted
+        ); // retain
 
-        // Has the ApiRate been set already?
-        match api_ref {
-            // If not, initialize the structure:
-            None => {
-                self.rate_map.insert(api.clone(), ApiRate {
-                    target_rate: TargetRate { requests, duration },
-                    throttle_pool,
-                });
-            }
-            // If it has, set the new target request rate but preserve the
-            // current effective request rate:
-            Some(api_rate) => {
-                *api_rate = ApiRate {
-                    // Set new target request rate:
-                    target_rate: TargetRate { requests, duration },
-                    throttle_pool,
-                };
-            } // ApiRate
-        } // match
+        // Add the new bucket, unless the rate limit is being removed:
+        if target_rate.requests > 0 {
+            api_rates.push(ApiRate::new(target_rate));
+        } // if
 
         self
 
     } // fn
 
-} // impl
\ No newline at end of file
+} // impl
@@ -0,0 +1,162 @@
+use crate::retry_policy::RetryPolicy;
+
+// =============================================================================
+
+impl RetryPolicy {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns `true` if the HTTP status code is considered transient by this
+    /// policy, meaning the request is eligible for retries.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `status` ‧ The HTTP status code returned by the server. For example,
+    ///   `503`.
+
+    pub fn is_transient_http_status(&self, status: u16) -> bool {
+        self.transient_http_statuses.contains(&status)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns `true` if the Google Maps Platform response status is
+    /// considered transient by this policy, meaning the request is eligible for
+    /// retries.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `status` ‧ The response status returned by Google's servers. For
+    ///   example, `UNKNOWN_ERROR`.
+
+    pub fn is_transient_status(&self, status: &str) -> bool {
+        self.transient_statuses
+            .iter()
+            .any(|transient_status| transient_status == status)
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use crate::retry_policy::RetryPolicy;
+
+    #[test]
+    fn default_transient_http_statuses() {
+        let policy = RetryPolicy::default();
+        for status in [429, 500, 502, 503, 504] {
+            assert!(policy.is_transient_http_status(status), "{status} should be transient");
+        } // for
+        for status in [200, 400, 401, 403, 404, 501] {
+            assert!(!policy.is_transient_http_status(status), "{status} should be permanent");
+        } // for
+    } // fn
+
+    #[test]
+    fn default_transient_statuses() {
+        let policy = RetryPolicy::default();
+        // Quota-exceeded statuses are retried, as well as Google's
+        // "Unknown Error":
+        for status in ["OVER_QUERY_LIMIT", "RESOURCE_EXHAUSTED", "userRateLimitExceeded", "UNKNOWN_ERROR"] {
+            assert!(policy.is_transient_status(status), "{status} should be transient");
+        } // for
+        for status in ["OK", "ZERO_RESULTS", "INVALID_REQUEST", "REQUEST_DENIED", "NOT_FOUND"] {
+            assert!(!policy.is_transient_status(status), "{status} should be permanent");
+        } // for
+    } // fn
+
+    #[cfg(feature = "geocoding")]
+    mod pipeline {
+
+        use crate::client::GoogleMapsClient;
+        use crate::retry_policy::RetryPolicy;
+        use crate::transport::ScriptedTransport;
+
+        const OK: &str = r#"{ "results": [], "status": "OK" }"#;
+
+        fn client(transport: &ScriptedTransport, retry_policy: RetryPolicy) -> GoogleMapsClient {
+            GoogleMapsClient::new("TEST_KEY")
+                .with_transport(transport.clone())
+                .with_retry_policy(retry_policy)
+                .build()
+        } // fn
+
+        #[tokio::test(start_paused = true)]
+        async fn permanent_http_statuses_are_not_retried() {
+            let transport = ScriptedTransport::responses([(404, ""), (200, OK)]);
+            let client = client(&transport, RetryPolicy::default());
+
+            assert!(client.geocoding().with_address("Paris").execute().await.is_err());
+
+            assert_eq!(transport.requests().len(), 1);
+        } // fn
+
+        #[tokio::test(start_paused = true)]
+        async fn transient_http_statuses_may_be_configured() {
+            let transport = ScriptedTransport::responses([(418, ""), (200, OK), (503, "")]);
+            let client = client(&transport, RetryPolicy {
+                transient_http_statuses: vec![418],
+                ..RetryPolicy::default()
+            }); // client
+
+            client.geocoding().with_address("Paris").execute().await.unwrap();
+            assert_eq!(transport.requests().len(), 2);
+
+            // 503 is no longer in the list, so it isn't retried:
+            assert!(client.geocoding().with_address("Paris").execute().await.is_err());
+            assert_eq!(transport.requests().len(), 3);
+        } // fn
+
+        #[tokio::test(start_paused = true)]
+        async fn over_query_limit_is_retried() {
+            let transport = ScriptedTransport::responses([
+                (200, r#"{ "results": [], "status": "OVER_QUERY_LIMIT" }"#),
+                (200, r#"{ "results": [], "status": "OVER_QUERY_LIMIT" }"#),
+                (200, OK),
+            ]);
+            let client = client(&transport, RetryPolicy::default());
+
+            client.geocoding().with_address("Paris").execute().await.unwrap();
+
+            assert_eq!(transport.requests().len(), 3);
+        } // fn
+
+        #[tokio::test(start_paused = true)]
+        async fn permanent_statuses_are_not_retried() {
+            let transport = ScriptedTransport::responses([
+                (200, r#"{ "results": [], "status": "REQUEST_DENIED" }"#),
+                (200, OK),
+            ]);
+            let client = client(&transport, RetryPolicy::default());
+
+            assert!(client.geocoding().with_address("Paris").execute().await.is_err());
+
+            assert_eq!(transport.requests().len(), 1);
+        } // fn
+
+        #[tokio::test(start_paused = true)]
+        async fn transient_statuses_may_be_configured() {
+            let transport = ScriptedTransport::responses([
+                (200, r#"{ "results": [], "status": "INVALID_REQUEST" }"#),
+                (200, OK),
+                (200, r#"{ "results": [], "status": "UNKNOWN_ERROR" }"#),
+            ]);
+            let client = client(&transport, RetryPolicy {
+                transient_statuses: vec![String::from("INVALID_REQUEST")],
+                ..RetryPolicy::default()
+            }); // client
+
+            client.geocoding().with_address("Paris").execute().await.unwrap();
+            assert_eq!(transport.requests().len(), 2);
+
+            // `UNKNOWN_ERROR` is no longer in the list, so it isn't retried:
+            assert!(client.geocoding().with_address("Paris").execute().await.is_err());
+            assert_eq!(transport.requests().len(), 3);
+        } // fn
+
+    } // mod
+
+} // mod
\ No newline at end of file
@@ -0,0 +1,121 @@
+//! Contains the `RetryPolicy` struct and its associated traits. It is used to
+//! specify how failed requests to the Google Maps Platform are retried. **Do
+//! not use this module directly to set the retry policy. Use the
+//! `GoogleMapsClient` methods instead.**
+
+mod is_transient;
+mod retry_backoff;
+
+// -----------------------------------------------------------------------------
+
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+//
+/// Specifies how requests that fail with a transient error are retried. A
+/// transient error is one that may succeed if the request is tried again, such
+/// as an HTTP `503 Service Unavailable` response or a Google Maps `UNKNOWN_ERROR`
+/// status.
+///
+/// Retries are spaced out using exponential backoff: the first retry waits for
+/// `initial_interval`, and each subsequent retry waits `multiplier` times
+/// longer than the previous one, up to `max_interval`. Each wait is randomized
+/// by `randomization_factor` so that many clients do not retry in lock-step.
+///
+/// If the server responds with a `Retry-After` header (which is common for
+/// `429 Too Many Requests` and `503 Service Unavailable` responses), the delay
+/// requested by the server is used instead of the exponential backoff delay.
+///
+/// How to use this structure in a builder pattern:
+///
+/// ```rust
+/// let google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+///     .with_retry_policy(RetryPolicy {
+///         max_retries: Some(3),
+///         max_elapsed_time: Some(Duration::from_secs(10)),
+///         ..RetryPolicy::default()
+///     })
+///     .build();
+/// ```
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+
+    /// The maximum number of times a request will be retried after the initial
+    /// attempt. `None` means there is no limit on the number of retries, other
+    /// than `max_elapsed_time`. `Some(0)` disables retries.
+    pub max_retries: Option<u32>,
+
+    /// The maximum amount of time that may be spent retrying a request,
+    /// measured from the first attempt. `None` means there is no limit on the
+    /// elapsed time, other than `max_retries`.
+    pub max_elapsed_time: Option<Duration>,
+
+    /// The delay before the first retry.
+    pub initial_interval: Duration,
+
+    /// The maximum delay between two retries. Once the delay reaches this
+    /// value it stops increasing.
+    pub max_interval: Duration,
+
+    /// The value that the delay is multiplied by after each retry.
+    pub multiplier: f64,
+
+    /// The jitter to apply to each delay. A randomization factor of `0.5`
+    /// results in a random delay ranging between 50% below and 50% above the
+    /// calculated delay. `0.0` disables jitter.
+    pub randomization_factor: f64,
+
+    /// The [HTTP status codes](https://developer.mozilla.org/en-US/docs/Web/HTTP/Status)
+    /// that are considered transient and eligible for retries. For example,
+    /// `429` and `503`.
+    pub transient_http_statuses: Vec<u16>,
+
+    /// The Google Maps Platform response statuses that are considered
+    /// transient and eligible for retries, in the form returned by Google's
+    /// servers. For example, `UNKNOWN_ERROR`.
+    pub transient_statuses: Vec<String>,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl RetryPolicy {
+
+    /// Returns a retry policy that never retries. Failed requests are returned
+    /// to the caller immediately. This is useful for latency-sensitive code.
+    pub fn disabled() -> RetryPolicy {
+        RetryPolicy {
+            max_retries: Some(0),
+            ..RetryPolicy::default()
+        } // RetryPolicy
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for RetryPolicy {
+    /// Returns a reasonable default retry policy. Requests are retried for up
+    /// to 15 minutes, starting with a half second delay that increases by 50%
+    /// with every retry, up to one minute. Quota errors (`OVER_QUERY_LIMIT`,
+    /// `RESOURCE_EXHAUSTED` and the Geolocation API's `userRateLimitExceeded`)
+    /// are retried since short quota spikes usually clear up on their own.
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: None,
+            max_elapsed_time: Some(Duration::from_secs(15 * 60)),
+            initial_interval: Duration::from_millis(500),
+            max_interval: Duration::from_secs(60),
+            multiplier: 1.5,
+            randomization_factor: 0.5,
+            transient_http_statuses: vec![429, 500, 502, 503, 504],
+            transient_statuses: vec![
+                String::from("OVER_QUERY_LIMIT"),
+                String::from("RESOURCE_EXHAUSTED"),
+                String::from("UNKNOWN_ERROR"),
+                String::from("userRateLimitExceeded"),
+            ],
+        } // RetryPolicy
+    } // fn
+} // impl
\ No newline at end of file
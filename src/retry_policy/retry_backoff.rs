@@ -0,0 +1,302 @@
+//! Contains the `RetryBackoff` struct. It adapts a `RetryPolicy` to the
+//! `backoff` crate that performs the actual retries.
+
+use crate::request_rate::duration_to_string::duration_to_string;
+use crate::retry_policy::RetryPolicy;
+use backoff::backoff::Backoff;
+use backoff::ExponentialBackoff;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+// -----------------------------------------------------------------------------
+//
+/// This struct is not for public consumption. It is for internal use only.
+///
+/// An exponential backoff that also observes the maximum retry count of the
+/// `RetryPolicy` and any delay requested by the server through the
+/// `Retry-After` header, neither of which the `backoff` crate supports on its
+/// own.
+
+#[derive(Debug)]
+pub(crate) struct RetryBackoff {
+    /// The exponential backoff that calculates the delay between retries.
+    exponential_backoff: ExponentialBackoff,
+    /// The maximum number of retries. `None` means unlimited.
+    max_retries: Option<u32>,
+    /// The number of retries that have been performed so far.
+    retries: u32,
+    /// The delay requested by the server for the next retry, if any.
+    server_delay: ServerDelay,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// This struct is not for public consumption. It is for internal use only.
+///
+/// A handle that is used by the request to pass the delay requested by the
+/// server (i.e. the `Retry-After` header) to the `RetryBackoff`.
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ServerDelay(Arc<Mutex<Option<Duration>>>);
+
+impl ServerDelay {
+
+    /// Sets the delay requested by the server for the next retry. `None`
+    /// clears any previously requested delay.
+    pub(crate) fn set(&self, delay: Option<Duration>) {
+        if let Ok(mut server_delay) = self.0.lock() { *server_delay = delay }
+    } // fn
+
+    /// Returns and clears the delay requested by the server.
+    fn take(&self) -> Option<Duration> {
+        self.0.lock().ok().and_then(|mut server_delay| server_delay.take())
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl RetryPolicy {
+
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// Returns a new backoff for a single request, which is used by the
+    /// `backoff` crate to schedule retries according to this policy.
+    pub(crate) fn backoff(&self) -> RetryBackoff {
+        RetryBackoff {
+            exponential_backoff: ExponentialBackoff {
+                current_interval: self.initial_interval,
+                initial_interval: self.initial_interval,
+                randomization_factor: self.randomization_factor,
+                multiplier: self.multiplier,
+                max_interval: self.max_interval,
+                max_elapsed_time: self.max_elapsed_time,
+                start_time: Instant::now(),
+                ..ExponentialBackoff::default()
+            }, // ExponentialBackoff
+            max_retries: self.max_retries,
+            retries: 0,
+            server_delay: ServerDelay::default(),
+        } // RetryBackoff
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl RetryBackoff {
+
+    /// Returns a handle that may be used to pass the delay requested by the
+    /// server to this backoff.
+    pub(crate) fn server_delay(&self) -> ServerDelay {
+        self.server_delay.clone()
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Backoff for RetryBackoff {
+
+    /// Resets the backoff to its initial state.
+    fn reset(&mut self) {
+        self.exponential_backoff.reset();
+        self.retries = 0;
+        self.server_delay.set(None);
+    } // fn
+
+    /// Returns the delay before the next retry, or `None` if the request should
+    /// not be retried again.
+    fn next_backoff(&mut self) -> Option<Duration> {
+
+        let server_delay = self.server_delay.take();
+
+        if let Some(max_retries) = self.max_retries {
+            if self.retries >= max_retries {
+                tracing::warn!("Giving up after {} retries: the retry limit has been reached.", self.retries);
+                return None
+            } // if
+        } // if
+
+        // The exponential backoff returns `None` once the maximum elapsed time
+        // has been exceeded:
+        let backoff_delay = match self.exponential_backoff.next_backoff() {
+            Some(backoff_delay) => backoff_delay,
+            None => {
+                tracing::warn!("Giving up after {} retries: the maximum elapsed time has been reached.", self.retries);
+                return None
+            }, // None
+        }; // match
+
+        self.retries += 1;
+
+        match server_delay {
+            // The server requested a specific delay. Honour it, unless waiting
+            // that long would exceed the maximum elapsed time:
+            Some(server_delay) => {
+                let elapsed = self.exponential_backoff.get_elapsed_time();
+                if let Some(max_elapsed_time) = self.exponential_backoff.max_elapsed_time {
+                    if elapsed + server_delay > max_elapsed_time {
+                        tracing::warn!(
+                            "Giving up after {} retries: the server requested a delay of {}, which exceeds the maximum elapsed time.",
+                            self.retries - 1,
+                            duration_to_string(&server_delay),
+                        );
+                        return None
+                    } // if
+                } // if
+                tracing::info!(
+                    "Retry #{} in {}, as requested by the server's `Retry-After` header.",
+                    self.retries,
+                    duration_to_string(&server_delay),
+                );
+                Some(server_delay)
+            }, // Some
+            // Otherwise, use the exponential backoff delay:
+            None => {
+                tracing::info!(
+                    "Retry #{} in {}, using exponential backoff.",
+                    self.retries,
+                    duration_to_string(&backoff_delay),
+                );
+                Some(backoff_delay)
+            }, // None
+        } // match
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "geocoding"))]
+mod tests {
+
+    use crate::client::GoogleMapsClient;
+    use crate::retry_policy::RetryPolicy;
+    use crate::transport::{HttpResponse, ScriptedTransport};
+    use std::time::Duration;
+    use tokio::time::Instant;
+
+    const OK: &str = r#"{ "results": [], "status": "OK" }"#;
+
+    /// A retry policy without jitter, so that the backoff intervals are
+    /// predictable: 500 ms, then 750 ms, then 1,125 ms, and so on.
+    fn policy() -> RetryPolicy {
+        RetryPolicy { randomization_factor: 0.0, ..RetryPolicy::default() }
+    } // fn
+
+    fn client(transport: &ScriptedTransport, retry_policy: RetryPolicy) -> GoogleMapsClient {
+        GoogleMapsClient::new("TEST_KEY")
+            .with_transport(transport.clone())
+            .with_retry_policy(retry_policy)
+            .build()
+    } // fn
+
+    #[tokio::test(start_paused = true)]
+    async fn transient_errors_are_retried_with_exponential_backoff() {
+        let transport = ScriptedTransport::responses([(503, ""), (503, ""), (200, OK)]);
+        let client = client(&transport, policy());
+
+        let start = Instant::now();
+        client.geocoding().with_address("Paris").execute().await.unwrap();
+
+        assert_eq!(transport.requests().len(), 3);
+        assert_eq!(start.elapsed(), Duration::from_millis(500 + 750));
+    } // fn
+
+    #[tokio::test(start_paused = true)]
+    async fn max_retries_limits_the_attempts() {
+        let transport = ScriptedTransport::responses([(503, ""); 5]);
+        let client = client(&transport, RetryPolicy { max_retries: Some(2), ..policy() });
+
+        assert!(client.geocoding().with_address("Paris").execute().await.is_err());
+
+        // The first attempt, and two retries:
+        assert_eq!(transport.requests().len(), 3);
+    } // fn
+
+    #[tokio::test(start_paused = true)]
+    async fn disabled_policy_makes_a_single_attempt() {
+        let transport = ScriptedTransport::responses([(503, ""), (200, OK)]);
+        let client = client(&transport, RetryPolicy::disabled());
+
+        let start = Instant::now();
+        assert!(client.geocoding().with_address("Paris").execute().await.is_err());
+
+        assert_eq!(transport.requests().len(), 1);
+        assert_eq!(start.elapsed(), Duration::ZERO);
+    } // fn
+
+    #[tokio::test(start_paused = true)]
+    async fn max_elapsed_time_limits_the_retries() {
+        let transport = ScriptedTransport::responses([(503, ""); 5]);
+        let client = client(&transport, RetryPolicy {
+            max_elapsed_time: Some(Duration::ZERO),
+            ..policy()
+        }); // client
+
+        assert!(client.geocoding().with_address("Paris").execute().await.is_err());
+
+        assert_eq!(transport.requests().len(), 1);
+    } // fn
+
+    /// Returns a response with a `Retry-After` header.
+    fn retry_after(status: u16, retry_after: &str) -> HttpResponse {
+        let mut response = HttpResponse::new(status, "");
+        response.headers.push((String::from("Retry-After"), String::from(retry_after)));
+        response
+    } // fn
+
+    #[tokio::test(start_paused = true)]
+    async fn retry_after_overrides_the_backoff_interval() {
+        let transport = ScriptedTransport::new([
+            Ok(retry_after(429, "30")),
+            Ok(retry_after(503, "2")),
+            Ok(HttpResponse::new(200, OK)),
+        ]); // ScriptedTransport
+        let client = client(&transport, policy());
+
+        let start = Instant::now();
+        client.geocoding().with_address("Paris").execute().await.unwrap();
+
+        assert_eq!(transport.requests().len(), 3);
+        assert_eq!(start.elapsed(), Duration::from_secs(30 + 2));
+    } // fn
+
+    #[tokio::test(start_paused = true)]
+    async fn retry_after_only_applies_to_its_own_retry() {
+        let transport = ScriptedTransport::new([
+            Ok(retry_after(429, "10")),
+            Ok(HttpResponse::new(503, "")),
+            Ok(HttpResponse::new(200, OK)),
+        ]); // ScriptedTransport
+        let client = client(&transport, policy());
+
+        let start = Instant::now();
+        client.geocoding().with_address("Paris").execute().await.unwrap();
+
+        // The second retry falls back to the exponential backoff interval:
+        assert_eq!(start.elapsed(), Duration::from_millis(10_000 + 750));
+    } // fn
+
+    #[tokio::test(start_paused = true)]
+    async fn retry_after_beyond_the_max_elapsed_time_gives_up() {
+        let transport = ScriptedTransport::new([
+            Ok(retry_after(429, "120")),
+            Ok(HttpResponse::new(200, OK)),
+        ]); // ScriptedTransport
+        let client = client(&transport, RetryPolicy {
+            max_elapsed_time: Some(Duration::from_secs(60)),
+            ..policy()
+        }); // client
+
+        let start = Instant::now();
+        assert!(client.geocoding().with_address("Paris").execute().await.is_err());
+
+        // The client gives up rather than waiting for longer than allowed:
+        assert_eq!(transport.requests().len(), 1);
+        assert_eq!(start.elapsed(), Duration::ZERO);
+    } // fn
+
+} // mod
\ No newline at end of file
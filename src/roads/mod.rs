@@ -72,10 +72,12 @@ pub use crate::roads::{
 
 pub use crate::roads::snap_to_roads::{
     response::Response as SnapToRoadsResponse,
+    request::description::Description as SnapToRoadsRequestDescription,
     request::Request as SnapToRoadsRequest,
 }; // crate::roads::snap_to_roads
 
 pub use crate::roads::nearest_roads::{
     response::Response as NearestRoadsResponse,
+    request::description::Description as NearestRoadsRequestDescription,
     request::Request as NearestRoadsRequest,
 }; // crate::roads::nearest_roads
\ No newline at end of file
@@ -14,7 +14,7 @@ impl<'a> Request<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> &'a mut Request {
+    pub fn build(&mut self) -> &mut Self {
 
         // This section builds the "required parameters" portion of the query
         // string:
@@ -32,8 +32,7 @@ impl<'a> Request<'a> {
 
         // Build "required parameters" portion of the query string:
         let query = format!(
-            "key={key}&points={points}",
-            key=self.client.key,
+            "points={points}",
         );
 
         // Set query string in Request struct.
@@ -23,7 +23,7 @@ impl<'a> NearestRoadsRequest<'a> {
     /// This method accepts no arguments.
 
     pub async fn execute(
-        &'a mut self
+        &mut self
     ) -> Result<NearestRoadsResponse, GoogleMapsError> {
 
         self.build().get().await
@@ -6,16 +6,24 @@
 // -----------------------------------------------------------------------------
 
 mod build;
+mod describe;
 #[cfg(feature = "enable-reqwest")]
 mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
 #[cfg(feature = "enable-reqwest")]
 mod get;
+#[cfg(feature = "enable-reqwest")]
+mod into_future;
 mod new;
 mod query_url;
+#[cfg(feature = "enable-reqwest")]
+mod with_timeout;
+pub mod description;
 
 // -----------------------------------------------------------------------------
 
-use crate::client::GoogleMapsClient;
+use crate::client::ClientRef;
 use crate::types::LatLng;
 
 // -----------------------------------------------------------------------------
@@ -32,7 +40,7 @@ pub struct Request<'a> {
 
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
-    client: &'a GoogleMapsClient,
+    client: ClientRef<'a>,
 
     /// The points to be snapped. The points parameter accepts a list of
     /// latitude/longitude pairs. Latitude and longitude values should be
@@ -41,6 +49,12 @@ pub struct Request<'a> {
     /// `points=60.170880,24.942795|60.170879,24.942796|60.170877,24.942796`.
     points: Vec<LatLng>,
 
+    /// The deadline for the request, including rate limiter waits and
+    /// retries. It overrides the client's default timeout. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "enable-reqwest")]
+    timeout: Option<std::time::Duration>,
+
     // Internal use only:
     // ------------------
 
@@ -1,4 +1,4 @@
-use crate::client::GoogleMapsClient;
+use crate::client::ClientRef;
 use crate::types::LatLng;
 use crate::roads::nearest_roads::request::Request;
 
@@ -27,15 +27,17 @@ impl<'a> Request<'a> {
 
 
     pub fn new(
-        client: &GoogleMapsClient,
+        client: impl Into<ClientRef<'a>>,
         points: Vec<LatLng>,
-    ) -> Request {
+    ) -> Self {
 
         // Instantiate struct and return it to caller:
         Request {
             // Required parameters:
-            client,
+            client: client.into(),
             points,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
             // Internal use only:
             query: None,
         } // struct
@@ -1,4 +1,5 @@
 use crate::roads::nearest_roads::{SERVICE_URL, request::Request};
+use crate::request_rate::api::Api;
 
 // =============================================================================
 
@@ -15,12 +16,20 @@ impl<'a> Request<'a> {
     /// records or logging. It could also be used for passing to your HTTP
     /// client of choice and executing the HTTP GET request yourself.
     ///
+    /// The API key (or client ID and signature) is appended to the URL. The
+    /// API key and signature are replaced with `REDACTED` unless the client
+    /// has opted-in to revealing secrets using
+    /// `GoogleMapsClient::with_reveal_secrets`. If a base URL override has
+    /// been set using `GoogleMapsClient::with_base_url`, it is used in place
+    /// of Google's.
+    ///
     /// ## Arguments:
     ///
     /// This method accepts no arguments.
 
-    pub fn query_url(&'a mut self) -> String {
+    pub fn query_url(&mut self) -> String {
 
+        let client = self.client.clone();
         let query_string = match &self.query {
             // If query string has already been built, return it:
             Some(query_string) => query_string,
@@ -28,7 +37,8 @@ impl<'a> Request<'a> {
             None => self.build().query.as_ref().unwrap(),
         }; // match
 
-        format!("{SERVICE_URL}/?{query_string}")
+        let service_url = client.service_url(&Api::NearestRoads, SERVICE_URL);
+        client.redact_url(&client.authenticated_url(&format!("{service_url}?{query_string}")))
 
     } // fn
 
@@ -46,4 +46,23 @@ impl std::str::FromStr for Response {
     fn from_str(s: &str) -> Result<Self, serde_json::error::Error> {
         serde_json::from_str(s)
     } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::traits::ServiceResponse for Response {
+    type Error = crate::roads::error::Error;
+    /// Returns the response if the Google Maps Nearest Roads API did not return an
+    /// `ErrorResponse`. Otherwise, returns the status code and the
+    /// corresponding error.
+    fn into_result(self) -> Result<Self, (String, Self::Error)> {
+        match self.error {
+            None => Ok(self),
+            Some(error) => Err((
+                String::from(&error.status),
+                crate::roads::error::Error::GoogleMapsService(error.status, Some(error.message)),
+            )),
+        } // match
+    } // fn
 } // impl
\ No newline at end of file
@@ -23,7 +23,7 @@ impl<'a> SnapToRoadsRequest<'a> {
     /// This method accepts no arguments.
 
     pub async fn execute(
-        &'a mut self
+        &mut self
     ) -> Result<SnapToRoadsResponse, GoogleMapsError> {
 
         self.build().get().await
@@ -0,0 +1,37 @@
+use crate::error::Error as GoogleMapsError;
+use crate::roads::snap_to_roads::{
+    request::Request as SnapToRoadsRequest,
+    response::Response as SnapToRoadsResponse,
+}; // crate::roads::snap_to_roads
+
+// =============================================================================
+
+impl<'a> SnapToRoadsRequest<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built, blocking the current thread until the
+    /// response has been received.
+    ///
+    /// ## Description:
+    ///
+    /// This is the synchronous version of `execute()`. It is useful for
+    /// command-line tools and scripts that do not otherwise need an `async`
+    /// runtime. The same rate limiting and retry settings are observed.
+    ///
+    /// This method must not be called from within an `async` context. Use
+    /// `execute()` instead.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub fn execute_blocking(
+        &mut self
+    ) -> Result<SnapToRoadsResponse, GoogleMapsError> {
+
+        crate::blocking::block_on(self.execute())
+
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -6,17 +6,25 @@
 // -----------------------------------------------------------------------------
 
 mod build;
+mod describe;
 #[cfg(feature = "enable-reqwest")]
 mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
 #[cfg(feature = "enable-reqwest")]
 mod get;
+#[cfg(feature = "enable-reqwest")]
+mod into_future;
 mod new;
 mod query_url;
 mod with_interpolation;
+#[cfg(feature = "enable-reqwest")]
+mod with_timeout;
+pub mod description;
 
 // -----------------------------------------------------------------------------
 
-use crate::client::GoogleMapsClient;
+use crate::client::ClientRef;
 use crate::types::LatLng;
 
 // -----------------------------------------------------------------------------
@@ -33,7 +41,7 @@ pub struct Request<'a> {
 
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
-    client: &'a GoogleMapsClient,
+    client: ClientRef<'a>,
 
     /// The path to be snapped. The path parameter accepts a list of
     /// latitude/longitude pairs. Latitude and longitude values should be
@@ -53,6 +61,12 @@ pub struct Request<'a> {
     /// `false`.
     interpolate: Option<bool>,
 
+    /// The deadline for the request, including rate limiter waits and
+    /// retries. It overrides the client's default timeout. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "enable-reqwest")]
+    timeout: Option<std::time::Duration>,
+
     // Internal use only:
     // ------------------
 
@@ -1,4 +1,4 @@
-use crate::client::GoogleMapsClient;
+use crate::client::ClientRef;
 use crate::types::LatLng;
 use crate::roads::snap_to_roads::request::Request;
 
@@ -23,17 +23,19 @@ impl<'a> Request<'a> {
     /// points caused by GPS signal loss, or noise.
 
     pub fn new(
-        client: &GoogleMapsClient,
+        client: impl Into<ClientRef<'a>>,
         path: Vec<LatLng>,
-    ) -> Request {
+    ) -> Self {
 
         // Instantiate struct and return it to caller:
         Request {
             // Required parameters:
-            client,
+            client: client.into(),
             path,
             // Optional parameters:
             interpolate: None,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
             // Internal use only:
             query: None,
         } // struct
@@ -0,0 +1,34 @@
+use crate::roads::snap_to_roads::request::Request;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+
+    /// Sets a deadline for this Snap To Roads request. If the snapped path has
+    /// not been returned in time, `Error::Timeout` is returned instead. The
+    /// deadline includes waiting on the client's rate limits and any retries.
+    ///
+    /// It overrides the client's default timeout, if one was set using
+    /// `GoogleMapsClient::with_timeout`. Interpolating a long path returns many
+    /// more points than were sent, which takes longer to return.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `timeout` ‧ The maximum time that the request may take.
+    ///
+    /// ## Example:
+    ///
+    /// * Give up on the path after 15 seconds:
+    /// ```rust
+    /// .with_timeout(std::time::Duration::from_secs(15))
+    /// ```
+
+    pub fn with_timeout(&mut self, timeout: Duration) -> &mut Self {
+        // Set timeout in Request struct.
+        self.timeout = Some(timeout);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -77,6 +77,7 @@ const OUTPUT_FORMAT: &str = "json"; // json or xml
 
 pub use crate::time_zone::{
     error::Error as TimeZoneError,
+    request::description::Description as TimeZoneRequestDescription,
     request::Request as TimeZoneRequest,
     response::{
         status::Status as TimeZoneStatus,
@@ -9,14 +9,13 @@ impl<'a> Request<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> &'a mut Request {
+    pub fn build(&mut self) -> &mut Self {
 
         // This section builds the "required parameters" portion of the query
         // string:
 
         let mut query = format!(
-            "key={}&location={}&timestamp={}",
-            self.client.key,
+            "location={}&timestamp={}",
             String::from(&self.location),
             self.timestamp.timestamp(),
         );
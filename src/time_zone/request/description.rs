@@ -0,0 +1,90 @@
+//! Contains the `Description` struct. It is a client-independent, serializable
+//! description of a _Time Zone API_ request that may be persisted (for
+//! example, in a job queue) and later bound to a client for execution.
+
+use chrono::{DateTime, Utc};
+use crate::client::ClientRef;
+use crate::time_zone::request::Request;
+use crate::types::{Language, LatLng};
+
+// -----------------------------------------------------------------------------
+//
+/// A client-independent description of a _Time Zone API_ request. Use
+/// `Request::describe()` to obtain one, store it wherever you like using
+/// `serde`, and then call `bind()` to turn it back into a `Request` that may
+/// be executed.
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Description {
+    /// The location to look up.
+    pub location: LatLng,
+    /// Specifies the desired time.
+    pub timestamp: DateTime<Utc>,
+    /// The language in which to return results.
+    pub language: Option<Language>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Request<'_>> for Description {
+    /// Describes a _Time Zone API_ request, leaving its client behind.
+    fn from(request: &Request<'_>) -> Self {
+        Description {
+            location: request.location.clone(),
+            timestamp: request.timestamp,
+            language: request.language.clone(),
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Description {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Binds this description to a client, producing a `Request` that is ready
+    /// to be executed.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct. This may
+    ///   be a reference to a `GoogleMapsClient` or an `Arc<GoogleMapsClient>`.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let description: TimeZoneRequestDescription = serde_json::from_str(&job)?;
+    /// let response = description.bind(&google_maps_client).execute().await?;
+    /// ```
+
+    pub fn bind<'a>(self, client: impl Into<ClientRef<'a>>) -> Request<'a> {
+        Request {
+            client: client.into(),
+            location: self.location,
+            timestamp: self.timestamp,
+            language: self.language,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
+            query: None,
+        } // struct
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::traits::RequestDescription for Description {
+
+    type Response = crate::time_zone::response::Response;
+
+    /// Binds this description to the client and executes the request.
+    fn execute(
+        self,
+        client: &crate::client::GoogleMapsClient,
+    ) -> futures::future::BoxFuture<'_, Result<Self::Response, crate::error::Error>> {
+        Box::pin(async move { self.bind(client).execute().await })
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -23,7 +23,7 @@ impl<'a> TimeZoneRequest<'a> {
     /// This method accepts no arguments.
 
     pub async fn execute(
-        &'a mut self
+        &mut self
     ) -> Result<TimeZoneResponse, GoogleMapsError> {
 
         self.build().get().await
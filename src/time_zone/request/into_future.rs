@@ -0,0 +1,31 @@
+use crate::error::Error as GoogleMapsError;
+use crate::time_zone::{
+    request::Request as TimeZoneRequest,
+    response::Response as TimeZoneResponse,
+}; // crate::time_zone
+use futures::future::BoxFuture;
+
+// =============================================================================
+
+impl std::future::IntoFuture for TimeZoneRequest<'static> {
+
+    type Output = Result<TimeZoneResponse, GoogleMapsError>;
+
+    type IntoFuture = BoxFuture<'static, Self::Output>;
+
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built, consuming the request. The returned
+    /// future is `Send` and `'static`, so it may be spawned onto another task
+    /// or stored for later. Requests created from an `Arc<GoogleMapsClient>`
+    /// are `'static`. See `ClientRef` for more information.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    fn into_future(mut self) -> Self::IntoFuture {
+        Box::pin(async move { self.execute().await })
+    } // fn
+
+} // impl
\ No newline at end of file
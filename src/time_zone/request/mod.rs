@@ -4,16 +4,24 @@
 //! for building your Google Maps Platform request.
 
 mod build;
+mod describe;
 #[cfg(feature = "enable-reqwest")]
 mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
 #[cfg(feature = "enable-reqwest")]
 mod get;
+#[cfg(feature = "enable-reqwest")]
+mod into_future;
 mod new;
 mod query_url;
 mod with_language;
+#[cfg(feature = "enable-reqwest")]
+mod with_timeout;
+pub mod description;
 
 use chrono::{DateTime, Utc};
-use crate::{client::GoogleMapsClient, types::Language, types::LatLng};
+use crate::{client::ClientRef, types::Language, types::LatLng};
 
 // -----------------------------------------------------------------------------
 //
@@ -29,7 +37,7 @@ pub struct Request<'a> {
 
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
-    client: &'a GoogleMapsClient,
+    client: ClientRef<'a>,
 
     /// The location to look up.
     location: LatLng,
@@ -51,6 +59,12 @@ pub struct Request<'a> {
     /// exhaustive. Defaults to `Language::English`.
     language: Option<Language>,
 
+    /// The deadline for the request, including rate limiter waits and
+    /// retries. It overrides the client's default timeout. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "enable-reqwest")]
+    timeout: Option<std::time::Duration>,
+
     // Internal use only:
     // ------------------
 
@@ -1,5 +1,5 @@
 use chrono::{DateTime, Utc};
-use crate::client::GoogleMapsClient;
+use crate::client::ClientRef;
 use crate::types::LatLng;
 use crate::time_zone::request::Request;
 
@@ -35,19 +35,21 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn new(
-        client: &GoogleMapsClient,
+        client: impl Into<ClientRef<'a>>,
         location: LatLng,
         timestamp: DateTime<Utc>,
-    ) -> Request {
+    ) -> Self {
 
         // Instantiate struct and return it to caller:
         Request {
             // Required parameters:
-            client,
+            client: client.into(),
             location,
             timestamp,
             // Optional parameters:
             language: None,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
             // Internal use only:
             query: None,
         } // struct
@@ -72,7 +74,7 @@ impl<'a> Request<'a> {
 
     #[cfg(feature = "geo")]
     pub fn try_new_coordinate<'b>(
-        client: &'a GoogleMapsClient,
+        client: impl Into<ClientRef<'a>>,
         coordinate: &'b geo_types::Coord,
         timestamp: DateTime<Utc>,
     ) -> Result<Request<'a>, crate::error::Error> {
@@ -80,11 +82,13 @@ impl<'a> Request<'a> {
         // Instantiate struct and return it to caller:
         Ok(Request {
             // Required parameters:
-            client,
+            client: client.into(),
             location: LatLng::try_from(coordinate)?,
             timestamp,
             // Optional parameters:
             language: None,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
             // Internal use only:
             query: None,
         }) // struct
@@ -109,7 +113,7 @@ impl<'a> Request<'a> {
 
     #[cfg(feature = "geo")]
     pub fn try_new_point<'b>(
-        client: &'a GoogleMapsClient,
+        client: impl Into<ClientRef<'a>>,
         point: &'b geo_types::Point,
         timestamp: DateTime<Utc>,
     ) -> Result<Request<'a>, crate::error::Error> {
@@ -117,11 +121,13 @@ impl<'a> Request<'a> {
         // Instantiate struct and return it to caller:
         Ok(Request {
             // Required parameters:
-            client,
+            client: client.into(),
             location: LatLng::try_from(point)?,
             timestamp,
             // Optional parameters:
             language: None,
+            #[cfg(feature = "enable-reqwest")]
+            timeout: None,
             // Internal use only:
             query: None,
         }) // struct
@@ -0,0 +1,65 @@
+//! Contains the `EndPoint` trait. It describes how a request is sent to a
+//! Google Maps Platform API end-point.
+
+use crate::billing::Charge;
+use crate::request_rate::api::Api;
+use crate::traits::ServiceResponse;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+//
+/// Describes a Google Maps Platform API end-point to the client's shared
+/// request pipeline. It is implemented by each request type, such as the
+/// Directions API `Request`.
+
+pub trait EndPoint {
+
+    /// The type that the server's response is deserialized into.
+    type Response: ServiceResponse;
+
+    /// The Google service URL of the end-point, without the output format or
+    /// query string. For example,
+    /// `https://maps.googleapis.com/maps/api/directions`.
+    fn service_url() -> &'static str;
+
+    /// The output format that is appended to the service URL, if any. For
+    /// example, `json`.
+    fn output_format() -> Option<&'static str>;
+
+    /// The human-readable name of the API, used in log messages. For example,
+    /// `Directions`.
+    fn title() -> &'static str;
+
+    /// The API that the end-point belongs to. It is used to select the rate
+    /// limits and base URL override for the request.
+    fn api() -> Api;
+
+    /// The URL-encoded query string that was built by the request builder, or
+    /// `None` if the query string has not been built yet.
+    fn query_string(&self) -> Option<&str>;
+
+    /// The JSON body of the request, for end-points that are sent as HTTP
+    /// `POST` requests. Most end-points are sent as `GET` requests and have no
+    /// body.
+    fn request_body(&self) -> Option<&str> { None }
+
+    /// The request's deadline, if one was set with its `with_timeout` method.
+    /// When `None`, the client's default timeout is used.
+    fn timeout(&self) -> Option<Duration>;
+
+    /// What the request will be billed for by Google: its billing SKU and how
+    /// many units of it. This is used to estimate the cost of the request.
+    fn charge(&self) -> Charge;
+
+    /// How many units the request consumes from weighted rate limits. For
+    /// example, the number of elements in a Distance Matrix API request. Most
+    /// end-points weigh one unit per request.
+    fn weight(&self) -> u32 { 1 }
+
+    /// Whether the response may be stored in, and answered from, the client's
+    /// response cache. Requests with a body are not cached since the URL
+    /// doesn't identify them. End-points that don't return JSON, such as the
+    /// Place Photo API, may also opt out.
+    fn cacheable(&self) -> bool { self.request_body().is_none() }
+
+} // trait
\ No newline at end of file
@@ -0,0 +1,18 @@
+//! Contains the traits that are shared by the Google Maps Platform API
+//! services. These traits describe each service to the client's shared request
+//! pipeline (see `GoogleMapsClient::get_request`), so that rate limiting,
+//! retries, logging and error handling behave identically for every service.
+
+pub mod end_point;
+pub mod request_description;
+pub mod service_error;
+pub mod service_response;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::traits::{
+    end_point::EndPoint,
+    request_description::RequestDescription,
+    service_error::ServiceError,
+    service_response::ServiceResponse,
+}; // crate::traits
\ No newline at end of file
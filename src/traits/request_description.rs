@@ -0,0 +1,27 @@
+//! Contains the `RequestDescription` trait. It lets a client execute any
+//! client-independent request description, such as those passed to
+//! `GoogleMapsClient::batch`.
+
+use crate::client::GoogleMapsClient;
+use crate::error::Error as GoogleMapsError;
+use futures::future::BoxFuture;
+
+// -----------------------------------------------------------------------------
+//
+/// A client-independent, serializable description of a request, such as the
+/// Geocoding API `GeocodingRequestDescription`. It is implemented by each
+/// request type's `Description`, which is obtained using the request's
+/// `describe()` method.
+
+pub trait RequestDescription {
+
+    /// The type that a successful response is deserialized into.
+    type Response;
+
+    /// Binds the description to the `client` and executes the request.
+    fn execute(
+        self,
+        client: &GoogleMapsClient,
+    ) -> BoxFuture<'_, Result<Self::Response, GoogleMapsError>>;
+
+} // trait
\ No newline at end of file
@@ -0,0 +1,24 @@
+//! Contains the `ServiceError` trait. It allows the client's shared request
+//! pipeline to produce the error type of each service.
+
+use crate::error::Error as GoogleMapsError;
+
+// -----------------------------------------------------------------------------
+//
+/// Allows the client's shared request pipeline to produce errors that are
+/// specific to a Google Maps Platform API service. It is implemented by the
+/// error type of each service, such as the Directions API `Error`.
+
+pub trait ServiceError: std::fmt::Display + Into<GoogleMapsError> {
+
+    /// The query string must be built before the request may be sent to the
+    /// Google Maps Platform.
+    fn query_not_built() -> Self;
+
+    /// The HTTP request was unsuccessful. The HTTP status code is passed in.
+    fn http_unsuccessful(status: String) -> Self;
+
+    /// The server's response could not be deserialized.
+    fn serde_json(error: serde_json::Error) -> Self;
+
+} // trait
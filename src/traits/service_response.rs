@@ -0,0 +1,39 @@
+//! Contains the `ServiceResponse` trait. It allows the client's shared request
+//! pipeline to check the status of a response from any service.
+
+use crate::traits::ServiceError;
+use crate::transport::HttpResponse;
+use serde::de::DeserializeOwned;
+
+// -----------------------------------------------------------------------------
+//
+/// Allows the client's shared request pipeline to check the Google Maps
+/// Platform status that is contained in a response. It is implemented by the
+/// response type of each service, such as the Directions API `Response`.
+
+pub trait ServiceResponse: DeserializeOwned {
+
+    /// The error type of the service.
+    type Error: ServiceError;
+
+    /// Returns the response if Google's servers indicated success. Otherwise,
+    /// returns the status code that was returned by Google (for example,
+    /// `OVER_QUERY_LIMIT`) along with the corresponding service error. The
+    /// status code is used to decide whether the request should be retried.
+    fn into_result(self) -> Result<Self, (String, Self::Error)>;
+
+    /// Some services describe their errors in the body of an unsuccessful HTTP
+    /// response (for example, a `404 Not Found`) rather than in a successful
+    /// response. Returns the status code and the corresponding service error
+    /// that is described by such a body, if any.
+    fn error_from_body(_body: &[u8]) -> Option<(String, Self::Error)> { None }
+
+    /// Deserializes the response from a successful HTTP response. Most
+    /// services return JSON, which is parsed from the body. Services that
+    /// return other content (for example, the images of the Place Photo API)
+    /// build their response from the body and headers instead.
+    fn from_http_response(response: &HttpResponse) -> Result<Self, serde_json::Error> {
+        serde_json::from_slice(&response.body)
+    } // fn
+
+} // trait
\ No newline at end of file
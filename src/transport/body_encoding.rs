@@ -0,0 +1,31 @@
+//! Contains the `BodyEncoding` enum and its associated traits. It specifies
+//! how the body of a response is stored in a fixture.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// How the body of a recorded response is stored in a fixture.
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BodyEncoding {
+    /// The body is JSON, and is stored as JSON so that it is readable. This is
+    /// used by most Google Maps Platform APIs.
+    #[default] Json,
+    /// The body isn't JSON (for example, a Place Photo image), and is stored
+    /// as a base64 string so that its bytes are preserved exactly.
+    Base64,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl BodyEncoding {
+
+    /// Returns whether the body is stored as JSON. Fixtures only record the
+    /// encoding of bodies that aren't JSON.
+    pub fn is_json(&self) -> bool {
+        *self == BodyEncoding::Json
+    } // fn
+
+} // impl
\ No newline at end of file
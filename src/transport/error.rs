@@ -0,0 +1,127 @@
+//! HTTP transport error types and error messages.
+
+// -----------------------------------------------------------------------------
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced by an HTTP transport when no response could be
+/// obtained from the server.
+
+#[derive(Debug, Diagnostic, Error)]
+#[diagnostic(url(docsrs))]
+pub enum Error {
+
+    /// The dependency library Reqwest generated an error.
+    #[cfg(feature = "enable-reqwest")]
+    #[error("Google Maps API client in the Reqwest library: {0}")]
+    #[diagnostic(code(google_maps::transport::reqwest))]
+    Reqwest(#[from] reqwest::Error),
+
+    /// A user-supplied transport generated an error.
+    #[error("Google Maps API client in the HTTP transport: {0}")]
+    #[diagnostic(code(google_maps::transport::custom))]
+    Custom(Box<dyn std::error::Error + Send + Sync>),
+
+    /// A fixture could not be recorded or replayed. For example, no fixture
+    /// was recorded for a request that was sent to a `ReplayTransport`. This
+    /// error is never retried.
+    #[error("Google Maps API client fixture error: {0}")]
+    #[diagnostic(code(google_maps::transport::fixture))]
+    Fixture(String),
+
+} // enum Error
+
+// -----------------------------------------------------------------------------
+
+impl Error {
+
+    /// Returns whether the request that produced this error is eligible for
+    /// retries. Reqwest errors are only retried if the request timed out, the
+    /// connection failed, or the request could not be sent. Other Reqwest
+    /// errors (such as an invalid URL, a misconfigured client or a body that
+    /// could not be decoded) and fixture errors will happen again no matter
+    /// how many times the request is retried. Errors from user-supplied
+    /// transports are assumed to be network errors, and are retried.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            #[cfg(feature = "enable-reqwest")]
+            Error::Reqwest(error) => error.is_timeout() || error.is_connect() || error.is_request(),
+            Error::Custom(_) => true,
+            Error::Fixture(_) => false,
+        } // match
+    } // fn
+
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// ## Description
+    ///
+    /// Replaces any secrets (such as the API key) in the URL that is carried
+    /// by a Reqwest error with `REDACTED`. Errors produced by user-supplied
+    /// transports are opaque and are returned as-is.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    #[cfg(feature = "enable-reqwest")]
+    pub(crate) fn redacted(self) -> Self {
+        match self {
+            Error::Reqwest(mut error) => {
+                if let Some(url) = error.url_mut() {
+                    let redacted = crate::client::redact_secrets(url.as_str());
+                    if let Ok(redacted) = reqwest::Url::parse(&redacted) {
+                        *url = redacted;
+                    } // if
+                } // if
+                Error::Reqwest(error)
+            }, // Reqwest
+            error => error,
+        } // match
+    } // fn
+
+} // impl
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn custom_errors_are_transient() {
+        assert!(Error::Custom("connection reset".into()).is_transient());
+    } // fn
+
+    #[test]
+    fn fixture_errors_are_permanent() {
+        assert!(!Error::Fixture(String::from("no fixture")).is_transient());
+    } // fn
+
+    #[cfg(feature = "enable-reqwest")]
+    #[test]
+    fn reqwest_builder_errors_are_permanent() {
+        let error = reqwest::Client::new()
+            .get("not a url")
+            .build()
+            .unwrap_err();
+        assert!(error.is_builder());
+        assert!(!Error::Reqwest(error).is_transient());
+    } // fn
+
+    #[cfg(feature = "enable-reqwest")]
+    #[tokio::test]
+    async fn reqwest_connection_errors_are_transient() {
+        // Nothing listens on port 1, so the connection is refused:
+        let error = reqwest::Client::new()
+            .get("http://127.0.0.1:1/")
+            .send()
+            .await
+            .unwrap_err();
+        assert!(error.is_connect());
+        assert!(Error::Reqwest(error).is_transient());
+    } // fn
+
+} // mod
\ No newline at end of file
@@ -0,0 +1,259 @@
+//! Contains the `Fixture` struct and its associated functions. A fixture is a
+//! recorded request and the server's response to it, as written by the
+//! `RecordingTransport` and served by the `ReplayTransport`.
+
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+use crate::transport::{BodyEncoding, Error, HttpRequest, HttpResponse};
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+use std::path::{Path, PathBuf};
+
+// -----------------------------------------------------------------------------
+//
+/// A recorded request and response. Fixtures are stored as pretty-printed JSON
+/// files so that they may be reviewed and edited by hand. Secrets (such as the
+/// API key and signature) are replaced with `REDACTED` in the recorded URL, and
+/// request headers (such as `Authorization`) are not recorded.
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Fixture {
+
+    /// The HTTP method of the request. For example, `GET`.
+    pub method: String,
+
+    /// The URL of the request, with secrets replaced with `REDACTED`.
+    pub url: String,
+
+    /// The body of the request, if any. This is used by `POST` requests.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_body: Option<serde_json::Value>,
+
+    /// The HTTP status code of the response.
+    pub status: u16,
+
+    /// The headers of the response.
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+
+    /// How the body of the response is stored. Only recorded for bodies that
+    /// aren't JSON.
+    #[serde(default, skip_serializing_if = "BodyEncoding::is_json")]
+    pub encoding: BodyEncoding,
+
+    /// The body of the response. JSON responses are stored as JSON, so that
+    /// they are readable. Any other response (for example, an image) is stored
+    /// as a base64 string.
+    pub body: serde_json::Value,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Fixture {
+
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// ## Description
+    ///
+    /// Records the request and response as a fixture.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `request` ‧ The request that was sent.
+    ///
+    /// * `response` ‧ The server's response to the request.
+
+    pub(crate) fn new(request: &HttpRequest, response: &HttpResponse) -> Fixture {
+        let (encoding, body) = match serde_json::from_slice(&response.body) {
+            Ok(json) => (BodyEncoding::Json, json),
+            Err(_error) => (
+                BodyEncoding::Base64,
+                serde_json::Value::String(BASE64.encode(&response.body)),
+            ), // Err
+        }; // match
+        Fixture {
+            method: request.method.to_string(),
+            url: crate::client::redact_secrets(&request.url),
+            request_body: request.body.as_deref().map(body_to_json),
+            status: response.status,
+            headers: response.headers.clone(),
+            encoding,
+            body,
+        } // Fixture
+    } // fn
+
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// ## Description
+    ///
+    /// Converts the fixture back into the server's response. Returns an error
+    /// if a base64 body can't be decoded.
+    ///
+    /// ## Arguments:
+    ///
+    /// This method accepts no arguments.
+
+    pub(crate) fn into_response(self) -> Result<HttpResponse, Error> {
+        let body = match (self.encoding, self.body) {
+            (BodyEncoding::Json, json) => json.to_string().into_bytes(),
+            (BodyEncoding::Base64, serde_json::Value::String(base64)) => BASE64
+                .decode(base64)
+                .map_err(|error| Error::Fixture(format!(
+                    "fixture body for `{}` is not valid base64: {error}",
+                    self.url,
+                )))?, // map_err
+            (BodyEncoding::Base64, _json) => return Err(Error::Fixture(format!(
+                "fixture body for `{}` must be a base64 string",
+                self.url,
+            ))), // Base64
+        }; // match
+        Ok(HttpResponse { status: self.status, headers: self.headers, body })
+    } // fn
+
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// ## Description
+    ///
+    /// Reads a fixture from a JSON file.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `path` ‧ The path of the fixture file.
+
+    pub(crate) fn read(path: &Path) -> Result<Fixture, Error> {
+        let json = std::fs::read(path).map_err(|error| Error::Fixture(format!(
+            "fixture `{}` could not be read: {error}",
+            path.display(),
+        )))?; // map_err
+        serde_json::from_slice(&json).map_err(|error| Error::Fixture(format!(
+            "fixture `{}` could not be parsed: {error}",
+            path.display(),
+        ))) // map_err
+    } // fn
+
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// ## Description
+    ///
+    /// Writes the fixture to a JSON file, creating its directory if necessary.
+    ///
+    /// ## Arguments:
+    ///
+    /// * `path` ‧ The path of the fixture file.
+
+    pub(crate) fn write(&self, path: &Path) -> Result<(), Error> {
+        let write = || -> std::io::Result<()> {
+            if let Some(directory) = path.parent() {
+                std::fs::create_dir_all(directory)?;
+            } // if
+            let json = serde_json::to_vec_pretty(self)?;
+            std::fs::write(path, json)
+        }; // write
+        write().map_err(|error| Error::Fixture(format!(
+            "fixture `{}` could not be written: {error}",
+            path.display(),
+        ))) // map_err
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// This function is not for public consumption. It is for internal use only.
+///
+/// ## Description
+///
+/// Returns the path of the fixture file for a request. The file name is the
+/// SHA-1 digest of the request's method, redacted URL and body, so that the
+/// same request maps to the same fixture regardless of the API key that was
+/// used to record it.
+///
+/// ## Arguments:
+///
+/// * `directory` ‧ The fixture directory.
+///
+/// * `request` ‧ The request to find the fixture for.
+
+pub(crate) fn fixture_path(directory: &Path, request: &HttpRequest) -> PathBuf {
+    let mut hasher = Sha1::new();
+    hasher.update(request.method.to_string());
+    hasher.update(b" ");
+    hasher.update(crate::client::redact_secrets(&request.url));
+    if let Some(body) = &request.body {
+        hasher.update(b"\n");
+        hasher.update(body);
+    } // if
+    let digest = hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<String>();
+    directory.join(format!("{digest}.json"))
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Converts a request body into a JSON value for storage. Request bodies are
+/// only recorded so that fixtures may be reviewed. Bodies that aren't JSON are
+/// stored as a string.
+
+fn body_to_json(body: &[u8]) -> serde_json::Value {
+    serde_json::from_slice(body).unwrap_or_else(|_error|
+        serde_json::Value::String(String::from_utf8_lossy(body).to_string())
+    ) // unwrap_or_else
+} // fn
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    /// Writes a fixture as JSON and reads it back, as the recording and
+    /// replay transports do.
+    fn round_trip(response: &HttpResponse) -> (serde_json::Value, HttpResponse) {
+        let request = HttpRequest::get("https://maps.googleapis.com/maps/api/place/photo?key=SECRET");
+        let json = serde_json::to_vec_pretty(&Fixture::new(&request, response)).unwrap();
+        let fixture: Fixture = serde_json::from_slice(&json).unwrap();
+        (serde_json::from_slice(&json).unwrap(), fixture.into_response().unwrap())
+    } // fn
+
+    #[test]
+    fn binary_body_survives_a_round_trip() {
+        // A JPEG header, followed by bytes that aren't valid UTF-8:
+        let mut response = HttpResponse::new(200, vec![0xff, 0xd8, 0xff, 0xe0, 0x00, 0x10, 0x80, 0xfe, 0x00]);
+        response.headers.push((String::from("content-type"), String::from("image/jpeg")));
+
+        let (json, replayed) = round_trip(&response);
+
+        assert_eq!(json["encoding"], "base64");
+        assert_eq!(json["body"], "/9j/4AAQgP4A");
+        assert!(!json["url"].as_str().unwrap().contains("SECRET"));
+        assert_eq!(replayed, response);
+    } // fn
+
+    #[test]
+    fn json_body_is_stored_as_json() {
+        let response = HttpResponse::new(200, r#"{"results":[],"status":"ZERO_RESULTS"}"#);
+
+        let (json, replayed) = round_trip(&response);
+
+        assert!(json.get("encoding").is_none());
+        assert_eq!(json["body"]["status"], "ZERO_RESULTS");
+        assert_eq!(replayed, response);
+    } // fn
+
+    #[test]
+    fn invalid_base64_body_is_an_error() {
+        let fixture: Fixture = serde_json::from_str(r#"{
+            "method": "GET",
+            "url": "https://maps.googleapis.com/maps/api/place/photo",
+            "status": 200,
+            "encoding": "base64",
+            "body": "not base64!"
+        }"#).unwrap();
+
+        assert!(matches!(fixture.into_response(), Err(Error::Fixture(_))));
+    } // fn
+
+} // mod
\ No newline at end of file
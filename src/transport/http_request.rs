@@ -0,0 +1,118 @@
+//! Contains the `HttpRequest` struct and its associated traits. It describes
+//! an HTTP request that is to be sent by a transport.
+
+use crate::transport::method::Method;
+
+// -----------------------------------------------------------------------------
+//
+/// An HTTP request that is to be sent to the Google Maps Platform by a
+/// `Transport`.
+
+#[derive(Clone, Default, Eq, Hash, PartialEq)]
+pub struct HttpRequest {
+
+    /// The HTTP method of the request.
+    pub method: Method,
+
+    /// The full URL of the request, including the query string.
+    pub url: String,
+
+    /// Any additional headers that should be sent with the request, in the
+    /// form of `(name, value)` pairs.
+    pub headers: Vec<(String, String)>,
+
+    /// The body of the request, if any.
+    pub body: Option<Vec<u8>>,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl HttpRequest {
+
+    /// Initializes an HTTP `GET` request for the specified URL.
+    pub fn get(url: &str) -> HttpRequest {
+        HttpRequest {
+            method: Method::Get,
+            url: url.to_string(),
+            headers: Vec::new(),
+            body: None,
+        } // HttpRequest
+    } // fn
+
+    /// Initializes an HTTP `POST` request for the specified URL and body.
+    pub fn post(url: &str, body: Vec<u8>) -> HttpRequest {
+        HttpRequest {
+            method: Method::Post,
+            url: url.to_string(),
+            headers: Vec::new(),
+            body: Some(body),
+        } // HttpRequest
+    } // fn
+
+    /// Adds a header to the HTTP request.
+    pub fn with_header(mut self, name: &str, value: &str) -> HttpRequest {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for HttpRequest {
+    /// Formats the request for debugging. Any secrets in the URL (such as the
+    /// API key) and the value of the `Authorization` header are replaced with
+    /// `REDACTED`. Only the length of the body is shown, since it may hold
+    /// credentials (such as the signed JWT assertion that is exchanged for an
+    /// OAuth access token). The `url` and `body` fields may be read directly
+    /// if they are required.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let redacted_headers = self.headers
+            .iter()
+            .map(|(name, value)| if name.eq_ignore_ascii_case("authorization") {
+                (name.as_str(), crate::client::REDACTED)
+            } else {
+                (name.as_str(), value.as_str())
+            }) // map
+            .collect::<Vec<(&str, &str)>>();
+        f.debug_struct("HttpRequest")
+            .field("method", &self.method)
+            .field("url", &crate::client::redact_secrets(&self.url))
+            .field("headers", &redacted_headers)
+            .field("body", &self.body.as_ref().map(|body| format!("{} bytes", body.len())))
+            .finish()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn debug_redacts_secrets_and_the_body() {
+        let request = HttpRequest::post(
+            "https://oauth2.googleapis.com/token?key=SECRET_KEY",
+            b"grant_type=jwt-bearer&assertion=SECRET_JWT".to_vec(),
+        ) // HttpRequest
+        .with_header("Authorization", "Bearer SECRET_TOKEN")
+        .with_header("Content-Type", "application/x-www-form-urlencoded");
+
+        let debug = format!("{request:?}");
+
+        assert!(!debug.contains("SECRET"), "secret leaked: {debug}");
+        assert!(debug.contains("42 bytes"), "body length missing: {debug}");
+        assert!(debug.contains("application/x-www-form-urlencoded"));
+    } // fn
+
+    #[test]
+    fn debug_shows_requests_without_a_body() {
+        let debug = format!("{:?}", HttpRequest::get("https://maps.googleapis.com/maps/api/geocode/json"));
+
+        assert!(debug.contains("body: None"), "unexpected body: {debug}");
+    } // fn
+
+} // mod
\ No newline at end of file
@@ -0,0 +1,123 @@
+//! Contains the `HttpResponse` struct and its associated traits. It contains
+//! the response that was returned to a transport by the server.
+
+use std::time::{Duration, SystemTime};
+
+// -----------------------------------------------------------------------------
+//
+/// An HTTP response that was returned by the Google Maps Platform to a
+/// `Transport`.
+
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct HttpResponse {
+
+    /// The [HTTP status code](https://developer.mozilla.org/en-US/docs/Web/HTTP/Status)
+    /// of the response. For example, `200`.
+    pub status: u16,
+
+    /// The response headers, in the form of `(name, value)` pairs.
+    pub headers: Vec<(String, String)>,
+
+    /// The body of the response.
+    pub body: Vec<u8>,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl HttpResponse {
+
+    /// Initializes an HTTP response with the specified status code and body,
+    /// and no headers. This is convenient for test doubles.
+    pub fn new(status: u16, body: impl Into<Vec<u8>>) -> HttpResponse {
+        HttpResponse {
+            status,
+            headers: Vec::new(),
+            body: body.into(),
+        } // HttpResponse
+    } // fn
+
+    /// Returns `true` if the status code is in the `200-299` range.
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    } // fn
+
+    /// Returns `true` if the status code is in the `500-599` range.
+    pub fn is_server_error(&self) -> bool {
+        (500..600).contains(&self.status)
+    } // fn
+
+    /// Returns the value of the first header with the specified name. Header
+    /// names are compared case-insensitively.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header_name, _value)| header_name.eq_ignore_ascii_case(name))
+            .map(|(_name, value)| value.as_str())
+    } // fn
+
+    /// Returns the delay requested by the server in the
+    /// [Retry-After](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Retry-After)
+    /// header, if any. Both the delay in seconds form (i.e. `120`) and the HTTP
+    /// date form (i.e. `Wed, 21 Oct 2015 07:28:00 GMT`) are supported. A date
+    /// in the past results in a delay of zero.
+    pub fn retry_after(&self) -> Option<Duration> {
+        let retry_after = self.header("Retry-After")?.trim();
+        match retry_after.parse::<u64>() {
+            Ok(seconds) => Some(Duration::from_secs(seconds)),
+            Err(_error) => httpdate::parse_http_date(retry_after)
+                .ok()
+                .map(|date| date
+                    .duration_since(SystemTime::now())
+                    .unwrap_or(Duration::ZERO)),
+        } // match
+    } // fn
+
+} // impl
+
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    /// Returns a `429 Too Many Requests` response with a `Retry-After` header.
+    fn too_many_requests(retry_after: &str) -> HttpResponse {
+        HttpResponse {
+            status: 429,
+            headers: vec![(String::from("retry-after"), String::from(retry_after))],
+            body: Vec::new(),
+        } // HttpResponse
+    } // fn
+
+    #[test]
+    fn retry_after_accepts_seconds() {
+        assert_eq!(too_many_requests("120").retry_after(), Some(Duration::from_secs(120)));
+        assert_eq!(too_many_requests(" 0 ").retry_after(), Some(Duration::ZERO));
+    } // fn
+
+    #[test]
+    fn retry_after_accepts_a_future_http_date() {
+        let date = httpdate::fmt_http_date(SystemTime::now() + Duration::from_secs(600));
+        let retry_after = too_many_requests(&date).retry_after().unwrap();
+        assert!(retry_after > Duration::from_secs(590), "{retry_after:?}");
+        assert!(retry_after <= Duration::from_secs(600), "{retry_after:?}");
+    } // fn
+
+    #[test]
+    fn retry_after_treats_a_past_http_date_as_zero() {
+        assert_eq!(
+            too_many_requests("Wed, 21 Oct 2015 07:28:00 GMT").retry_after(),
+            Some(Duration::ZERO),
+        );
+    } // fn
+
+    #[test]
+    fn retry_after_ignores_missing_and_invalid_headers() {
+        assert_eq!(HttpResponse::new(429, Vec::new()).retry_after(), None);
+        assert_eq!(too_many_requests("soon").retry_after(), None);
+    } // fn
+
+} // mod
\ No newline at end of file
@@ -0,0 +1,37 @@
+//! Contains the `Method` enum and its associated traits. It specifies the HTTP
+//! method that a transport should use for a request.
+
+// -----------------------------------------------------------------------------
+//
+/// The HTTP method for a request to the Google Maps Platform.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Method {
+    /// HTTP `GET` request. This is used by most Google Maps Platform APIs.
+    #[default] Get,
+    /// HTTP `POST` request. The request has a body.
+    Post,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Method> for String {
+    /// Converts a `Method` enum to a `String` that contains an HTTP method
+    /// name.
+    fn from(method: &Method) -> String {
+        match method {
+            Method::Get => String::from("GET"),
+            Method::Post => String::from("POST"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Method {
+    /// Formats a `Method` enum into a string that is presentable to the end
+    /// user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", String::from(self))
+    } // fn
+} // impl
@@ -0,0 +1,90 @@
+//! Contains the `Transport` trait and its associated types. A transport is
+//! responsible for sending an HTTP request to the Google Maps Platform and
+//! returning the server's response.
+//!
+//! By default, the `GoogleMapsClient` uses the `ReqwestTransport`, which is
+//! backed by the [reqwest](https://crates.io/crates/reqwest) crate. Any other
+//! HTTP stack (for example, `hyper`, `ureq`, an in-house instrumented client or
+//! a test double that returns canned JSON) may be used instead by implementing
+//! the `Transport` trait and passing it to `GoogleMapsClient::with_transport`.
+//!
+//! The `Transport` trait and its request and response types are available
+//! without the `enable-reqwest` feature, so that a transport may be written
+//! without depending on `reqwest`. Only the `ReqwestTransport` requires it.
+
+pub mod body_encoding;
+pub mod error;
+pub mod fixture;
+pub mod http_request;
+pub mod http_response;
+pub mod method;
+pub mod recording_transport;
+pub mod replay_transport;
+#[cfg(feature = "enable-reqwest")]
+pub mod reqwest_transport;
+#[cfg(test)]
+pub(crate) mod scripted_transport;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::transport::{
+    body_encoding::BodyEncoding,
+    error::Error,
+    fixture::Fixture,
+    http_request::HttpRequest,
+    http_response::HttpResponse,
+    method::Method,
+    recording_transport::RecordingTransport,
+    replay_transport::ReplayTransport,
+}; // crate::transport
+
+#[cfg(feature = "enable-reqwest")]
+pub use crate::transport::reqwest_transport::ReqwestTransport;
+
+#[cfg(test)]
+pub(crate) use crate::transport::scripted_transport::ScriptedTransport;
+
+// -----------------------------------------------------------------------------
+
+use futures::future::BoxFuture;
+
+// -----------------------------------------------------------------------------
+//
+/// Implement this trait to have the Google Maps client send its HTTP requests
+/// through an HTTP stack of your choice.
+///
+/// The transport is only responsible for sending a single HTTP request and
+/// returning the response. Rate limiting, retries, deserialization and status
+/// handling are performed by the Google Maps client.
+///
+/// ## Example:
+///
+/// ```rust
+/// use futures::future::BoxFuture;
+/// use google_maps::transport::{Error, HttpRequest, HttpResponse, Transport};
+///
+/// #[derive(Debug)]
+/// struct CannedTransport;
+///
+/// impl Transport for CannedTransport {
+///     fn execute(&self, _request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, Error>> {
+///         Box::pin(async move {
+///             Ok(HttpResponse::new(200, r#"{ "results": [], "status": "ZERO_RESULTS" }"#))
+///         })
+///     }
+/// }
+///
+/// let google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+///     .with_transport(CannedTransport)
+///     .build();
+/// ```
+
+pub trait Transport: std::fmt::Debug + Send + Sync {
+
+    /// Sends the HTTP request and returns the server's response. A response
+    /// with an unsuccessful HTTP status code (for example, `500 Internal Server
+    /// Error`) should be returned as an `Ok` response. An `Err` should only be
+    /// returned if no response could be obtained from the server.
+    fn execute(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, Error>>;
+
+} // trait
\ No newline at end of file
@@ -0,0 +1,65 @@
+//! Contains the `RecordingTransport` struct and its associated traits. It
+//! records every request and response to a fixture directory.
+
+use crate::transport::{
+    Error,
+    fixture::{Fixture, fixture_path},
+    HttpRequest,
+    HttpResponse,
+    Transport,
+}; // crate::transport
+use futures::future::BoxFuture;
+use std::{path::PathBuf, sync::Arc};
+
+// -----------------------------------------------------------------------------
+//
+/// A transport that sends requests through another transport, and writes
+/// each request and the server's response to a fixture directory. The
+/// fixtures may later be served by a `ReplayTransport`, so that tests do not
+/// need to reach Google.
+///
+/// Secrets (such as the API key and signature) are replaced with `REDACTED`
+/// in the recorded URLs, and request headers (such as `Authorization`) are not
+/// recorded. An existing fixture for the same request is overwritten.
+
+#[derive(Clone, Debug)]
+pub struct RecordingTransport {
+
+    /// The transport that requests are sent through.
+    pub inner: Arc<dyn Transport>,
+
+    /// The directory that fixtures are written to.
+    pub directory: PathBuf,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl RecordingTransport {
+
+    /// Initializes a transport that sends requests through `inner` and
+    /// records them to the `directory`.
+    pub fn new(inner: Arc<dyn Transport>, directory: impl Into<PathBuf>) -> RecordingTransport {
+        RecordingTransport { inner, directory: directory.into() }
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Transport for RecordingTransport {
+
+    /// Sends the HTTP request through the inner transport and records the
+    /// response. Requests that fail without a response are not recorded.
+    fn execute(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, Error>> {
+        Box::pin(async move {
+            let path = fixture_path(&self.directory, &request);
+            let fixture_request = request.clone();
+            let response = self.inner.execute(request).await?;
+            Fixture::new(&fixture_request, &response).write(&path)?;
+            tracing::debug!("Recorded fixture `{}`", path.display());
+            Ok(response)
+        }) // Box::pin
+    } // fn
+
+} // impl
\ No newline at end of file
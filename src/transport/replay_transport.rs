@@ -0,0 +1,64 @@
+//! Contains the `ReplayTransport` struct and its associated traits. It serves
+//! responses from a fixture directory instead of going to the network.
+
+use crate::transport::{
+    Error,
+    fixture::{Fixture, fixture_path},
+    HttpRequest,
+    HttpResponse,
+    Transport,
+}; // crate::transport
+use futures::future::BoxFuture;
+use std::path::PathBuf;
+
+// -----------------------------------------------------------------------------
+//
+/// A transport that serves the responses that were recorded by a
+/// `RecordingTransport`, and never reaches the network. This allows tests to
+/// be deterministic and to run without an API key.
+///
+/// Requests are matched on their method, URL and body, with secrets (such as
+/// the API key) ignored. A request that has no recorded fixture fails with a
+/// `transport::Error::Fixture` error, which is never retried.
+
+#[derive(Clone, Debug)]
+pub struct ReplayTransport {
+
+    /// The directory that fixtures are read from.
+    pub directory: PathBuf,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl ReplayTransport {
+
+    /// Initializes a transport that serves fixtures from the `directory`.
+    pub fn new(directory: impl Into<PathBuf>) -> ReplayTransport {
+        ReplayTransport { directory: directory.into() }
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Transport for ReplayTransport {
+
+    /// Returns the recorded response for the HTTP request.
+    fn execute(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, Error>> {
+        Box::pin(async move {
+            let path = fixture_path(&self.directory, &request);
+            if !path.exists() {
+                return Err(Error::Fixture(format!(
+                    "no fixture was recorded for `{method} {url}`, expected `{path}`",
+                    method = request.method,
+                    url = crate::client::redact_secrets(&request.url),
+                    path = path.display(),
+                ))) // Err
+            } // if
+            tracing::debug!("Replaying fixture `{}`", path.display());
+            Fixture::read(&path)?.into_response()
+        }) // Box::pin
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,101 @@
+//! Contains the `ReqwestTransport` struct and its associated traits. This is
+//! the default transport used by the `GoogleMapsClient`.
+
+use crate::transport::{
+    Error,
+    HttpRequest,
+    HttpResponse,
+    Method,
+    Transport,
+}; // crate::transport
+use futures::future::BoxFuture;
+
+// -----------------------------------------------------------------------------
+//
+/// A transport that sends HTTP requests using the
+/// [reqwest](https://crates.io/crates/reqwest) crate. This is the default
+/// transport of the `GoogleMapsClient`.
+
+#[derive(Clone, Debug)]
+pub struct ReqwestTransport {
+    /// The reqwest client that is used to send requests.
+    pub reqwest_client: reqwest::Client,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl ReqwestTransport {
+
+    /// Initializes a transport using the default reqwest client settings for
+    /// this crate.
+    pub fn new() -> ReqwestTransport {
+        let reqwest_client = reqwest::Client::builder()
+            .user_agent(format!("google-maps-rust/{version}", version=env!("CARGO_PKG_VERSION")))
+            .build()
+            .unwrap();
+        ReqwestTransport { reqwest_client }
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for ReqwestTransport {
+    /// Returns a transport using the default reqwest client settings for this
+    /// crate.
+    fn default() -> Self {
+        ReqwestTransport::new()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<reqwest::Client> for ReqwestTransport {
+    /// Wraps a user configured reqwest client in a transport.
+    fn from(reqwest_client: reqwest::Client) -> Self {
+        ReqwestTransport { reqwest_client }
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Transport for ReqwestTransport {
+
+    /// Sends the HTTP request using reqwest and collects the response.
+    fn execute(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, Error>> {
+        Box::pin(async move {
+
+            let mut builder = match request.method {
+                Method::Get => self.reqwest_client.get(&request.url),
+                Method::Post => self.reqwest_client.post(&request.url),
+            }; // match
+
+            for (name, value) in &request.headers {
+                builder = builder.header(name.as_str(), value.as_str());
+            } // for
+
+            if let Some(body) = request.body {
+                builder = builder.body(body);
+            } // if
+
+            let response = self.reqwest_client.execute(builder.build()?).await?;
+
+            let status = response.status().as_u16();
+
+            let headers = response
+                .headers()
+                .iter()
+                .filter_map(|(name, value)| value
+                    .to_str()
+                    .ok()
+                    .map(|value| (name.to_string(), value.to_string())))
+                .collect();
+
+            let body = response.bytes().await?.to_vec();
+
+            Ok(HttpResponse { status, headers, body })
+
+        }) // Box::pin
+    } // fn
+
+} // impl
@@ -0,0 +1,74 @@
+//! Contains the `ScriptedTransport` struct and its associated traits. It is a
+//! test double that answers requests from a script, so that the client's
+//! request pipeline may be tested without a network.
+
+use crate::transport::{Error, HttpRequest, HttpResponse, Transport};
+use futures::future::BoxFuture;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+// -----------------------------------------------------------------------------
+//
+/// A transport that returns scripted results, in order, and remembers the
+/// requests that it was sent. Once the script has run out, requests never
+/// complete, which allows timeouts to be tested.
+///
+/// Clones share the same script and requests, so a clone may be passed to
+/// the client while the test keeps the original to inspect it.
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ScriptedTransport {
+
+    /// The results that remain to be returned, in order.
+    script: Arc<Mutex<VecDeque<Result<HttpResponse, Error>>>>,
+
+    /// The requests that have been sent through the transport, in order.
+    requests: Arc<Mutex<Vec<HttpRequest>>>,
+
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl ScriptedTransport {
+
+    /// Initializes a transport that returns the results in `script`, in order.
+    pub(crate) fn new(
+        script: impl IntoIterator<Item = Result<HttpResponse, Error>>,
+    ) -> ScriptedTransport {
+        ScriptedTransport {
+            script: Arc::new(Mutex::new(script.into_iter().collect())),
+            requests: Arc::default(),
+        } // ScriptedTransport
+    } // fn
+
+    /// Initializes a transport that returns the responses in `responses`, in
+    /// order. Each response is a status code and a body.
+    pub(crate) fn responses<'a>(
+        responses: impl IntoIterator<Item = (u16, &'a str)>,
+    ) -> ScriptedTransport {
+        ScriptedTransport::new(responses
+            .into_iter()
+            .map(|(status, body)| Ok(HttpResponse::new(status, body))))
+    } // fn
+
+    /// Returns the requests that have been sent through the transport.
+    pub(crate) fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.lock().unwrap().clone()
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Transport for ScriptedTransport {
+    fn execute(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, Error>> {
+        self.requests.lock().unwrap().push(request);
+        let result = self.script.lock().unwrap().pop_front();
+        Box::pin(async move {
+            match result {
+                Some(result) => result,
+                None => std::future::pending().await,
+            } // match
+        }) // Box::pin
+    } // fn
+} // impl
\ No newline at end of file
@@ -0,0 +1,27 @@
+//! URL signing error types and error messages.
+
+// -----------------------------------------------------------------------------
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced when signing a URL.
+
+#[derive(Clone, Debug, Diagnostic, Eq, Error, Hash, Ord, PartialEq, PartialOrd)]
+#[diagnostic(url(docsrs))]
+pub enum Error {
+
+    /// The URL signing secret could not be decoded. Signing secrets are
+    /// URL-safe base64 encoded, and are provided in the Google Cloud Console.
+    #[error("URL signing secret is not valid URL-safe base64: {0}")]
+    #[diagnostic(code(google_maps::url_signing::invalid_signing_secret))]
+    InvalidSigningSecret(String),
+
+    /// The URL does not contain a path that may be signed.
+    #[error("`{0}` is not a URL that may be signed")]
+    #[diagnostic(code(google_maps::url_signing::invalid_url))]
+    InvalidUrl(String),
+
+} // enum Error
\ No newline at end of file
@@ -0,0 +1,39 @@
+//! Contains the `sign_url` function and its associated types. Digital
+//! signatures are required by the Google Maps Platform for requests that are
+//! authenticated using a _client ID_ (for example, `client=gme-...`) and are
+//! optionally used to harden requests that are authenticated with an API key.
+//!
+//! A request is signed by computing the HMAC-SHA1 of the URL's path and query
+//! string using your URL signing secret, and appending the URL-safe base64
+//! encoded result to the URL as the `signature` parameter.
+//!
+//! Once a client ID or signing secret has been set using
+//! `GoogleMapsClient::with_client_id` or
+//! `GoogleMapsClient::with_signing_secret`, every request is signed
+//! automatically. The `sign_url` function may be used to sign URLs for
+//! services that are not implemented by this crate, such as the Maps Static
+//! API or Street View Static API.
+//!
+//! Learn more about [digital
+//! signatures](https://developers.google.com/maps/documentation/maps-static/digital-signature).
+
+pub mod error;
+pub mod sign_url;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::url_signing::{
+    error::Error,
+    sign_url::sign_url,
+}; // crate::url_signing
+
+pub(crate) use crate::url_signing::sign_url::{
+    decode_signing_secret,
+    encode_signing_secret,
+}; // crate::url_signing::sign_url
+
+#[cfg(request_urls)]
+pub(crate) use crate::url_signing::sign_url::{
+    path_and_query,
+    signature,
+}; // crate::url_signing::sign_url
\ No newline at end of file
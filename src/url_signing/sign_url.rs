@@ -0,0 +1,201 @@
+use base64::{
+    alphabet::URL_SAFE,
+    Engine,
+    engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig},
+}; // use base64
+use crate::url_signing::Error;
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+
+// -----------------------------------------------------------------------------
+
+/// URL-safe base64 engine that is used to decode signing secrets and encode
+/// signatures. Google's signing secrets are padded, but unpadded secrets are
+/// accepted too.
+const URL_SAFE_BASE64: GeneralPurpose = GeneralPurpose::new(
+    &URL_SAFE,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+); // GeneralPurpose
+
+// -----------------------------------------------------------------------------
+//
+/// Signs a URL using a URL signing secret, and returns the URL with the
+/// `signature` parameter appended to it.
+///
+/// The signature is computed over the URL's path and query string only. The
+/// URL must therefore already contain every parameter that will be sent,
+/// including the `key` or `client` parameter.
+///
+/// ## Arguments:
+///
+/// * `url` ‧ The full URL to sign, including the scheme, host, path and query
+///   string.
+///
+/// * `signing_secret` ‧ Your URL signing secret, as provided by the Google
+///   Cloud Console. It is URL-safe base64 encoded.
+///
+/// ## Examples:
+///
+/// * Signs the example URL from Google's documentation:
+/// ```rust
+/// use google_maps::url_signing::sign_url;
+///
+/// let signed_url = sign_url(
+///     "https://maps.googleapis.com/maps/api/geocode/json?address=New+York&client=clientID",
+///     "vNIXE0xscrmjlyV-12Nj_BvUPaw=",
+/// ).unwrap();
+///
+/// assert_eq!(
+///     signed_url,
+///     "https://maps.googleapis.com/maps/api/geocode/json?address=New+York&client=clientID&signature=chaRF2hTJKOScPr-RQCEhZbSzIE=",
+/// );
+/// ```
+
+pub fn sign_url(url: &str, signing_secret: &str) -> Result<String, Error> {
+
+    let signing_secret = decode_signing_secret(signing_secret)?;
+
+    let path_and_query = match path_and_query(url) {
+        Some(path_and_query) => path_and_query,
+        None => return Err(Error::InvalidUrl(crate::client::redact_secrets(url))),
+    }; // match
+
+    let separator = if path_and_query.contains('?') { '&' } else { '?' };
+    let signature = signature(path_and_query, &signing_secret);
+
+    Ok(format!("{url}{separator}signature={signature}"))
+
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// This function is not for public consumption. It is for internal use only.
+///
+/// ## Description
+///
+/// Decodes a URL-safe base64 signing secret into the bytes that are used as
+/// the HMAC-SHA1 key.
+///
+/// ## Arguments:
+///
+/// * `signing_secret` ‧ The URL signing secret, as provided by the Google
+///   Cloud Console.
+
+pub(crate) fn decode_signing_secret(signing_secret: &str) -> Result<Vec<u8>, Error> {
+    URL_SAFE_BASE64
+        .decode(signing_secret.trim())
+        .map_err(|error| Error::InvalidSigningSecret(error.to_string()))
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// This function is not for public consumption. It is for internal use only.
+///
+/// ## Description
+///
+/// Encodes decoded signing secret bytes back into their URL-safe base64 form.
+///
+/// ## Arguments:
+///
+/// * `signing_secret` ‧ The decoded URL signing secret.
+
+pub(crate) fn encode_signing_secret(signing_secret: &[u8]) -> String {
+    URL_SAFE_BASE64.encode(signing_secret)
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// This function is not for public consumption. It is for internal use only.
+///
+/// ## Description
+///
+/// Returns the path and query string (i.e. `/maps/api/geocode/json?...`) of a
+/// URL, without its scheme and host (i.e. `https://maps.googleapis.com`).
+/// Returns `None` if the URL has no path.
+///
+/// ## Arguments:
+///
+/// * `url` ‧ The full URL, including the scheme, host, path and query string.
+
+pub(crate) fn path_and_query(url: &str) -> Option<&str> {
+    let authority_start = url.find("://").map_or(0, |index| index + 3);
+    url[authority_start..]
+        .find('/')
+        .map(|index| &url[authority_start + index..])
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// This function is not for public consumption. It is for internal use only.
+///
+/// ## Description
+///
+/// Returns the URL-safe base64 encoded HMAC-SHA1 signature of a URL's path and
+/// query string.
+///
+/// ## Arguments:
+///
+/// * `path_and_query` ‧ The path and query string of the URL to sign. For
+///   example, `/maps/api/geocode/json?address=New+York&client=clientID`.
+///
+/// * `signing_secret` ‧ The decoded URL signing secret.
+
+pub(crate) fn signature(path_and_query: &str, signing_secret: &[u8]) -> String {
+    // HMAC accepts keys of any length, so this cannot fail:
+    let mut mac = Hmac::<Sha1>::new_from_slice(signing_secret)
+        .expect("HMAC can take a key of any size");
+    mac.update(path_and_query.as_bytes());
+    URL_SAFE_BASE64.encode(mac.finalize().into_bytes())
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    /// The URL, signing secret and signature from Google's [Digital Signature
+    /// Guide](https://developers.google.com/maps/documentation/maps-static/digital-signature).
+    const URL: &str = "https://maps.googleapis.com/maps/api/geocode/json?address=New+York&client=clientID";
+    const SIGNING_SECRET: &str = "vNIXE0xscrmjlyV-12Nj_BvUPaw=";
+    const SIGNATURE: &str = "chaRF2hTJKOScPr-RQCEhZbSzIE=";
+
+    #[test]
+    fn signature_matches_google_test_vector() {
+        let signing_secret = decode_signing_secret(SIGNING_SECRET).unwrap();
+        let path_and_query = path_and_query(URL).unwrap();
+        assert_eq!(path_and_query, "/maps/api/geocode/json?address=New+York&client=clientID");
+        assert_eq!(signature(path_and_query, &signing_secret), SIGNATURE);
+    } // fn
+
+    #[test]
+    fn sign_url_matches_google_test_vector() {
+        assert_eq!(
+            sign_url(URL, SIGNING_SECRET).unwrap(),
+            format!("{URL}&signature={SIGNATURE}"),
+        );
+    } // fn
+
+    #[test]
+    fn sign_url_rejects_invalid_signing_secret() {
+        assert!(matches!(
+            sign_url(URL, "not base64!"),
+            Err(Error::InvalidSigningSecret(_)),
+        ));
+    } // fn
+
+    #[cfg(request_urls)]
+    #[test]
+    fn authenticated_url_matches_google_test_vector() {
+        let client = crate::client::GoogleMapsClient::new("")
+            .with_client_id("clientID", SIGNING_SECRET)
+            .unwrap()
+            .build();
+        assert_eq!(
+            client.authenticated_url("https://maps.googleapis.com/maps/api/geocode/json?address=New+York"),
+            format!("{URL}&signature={SIGNATURE}"),
+        );
+    } // fn
+
+} // mod
\ No newline at end of file
@@ -0,0 +1,44 @@
+//! Helpers that are shared by the regression suites. Each suite replays the
+//! fixtures that were recorded for its service, so the suites are
+//! deterministic and run without an API key or network access.
+//!
+//! To re-record a service's fixtures against the Google Maps Platform, set the
+//! `GOOGLE_MAPS_API_KEY` environment variable and run its suite. For example:
+//!
+//! ```bash
+//! GOOGLE_MAPS_API_KEY=... cargo test --test geocoding
+//! ```
+//!
+//! Secrets are redacted from recorded fixtures, so they may be committed.
+
+use google_maps::prelude::*;
+use std::path::PathBuf;
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the fixture directory of a service, for example
+/// `tests/fixtures/geocoding`.
+
+pub fn fixture_directory(service: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(service)
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns a client that replays the fixtures of a service. If the
+/// `GOOGLE_MAPS_API_KEY` environment variable is set, requests are sent to
+/// Google and the fixtures are recorded instead.
+
+pub fn client(service: &str) -> GoogleMapsClient {
+    match std::env::var("GOOGLE_MAPS_API_KEY") {
+        Ok(key) => GoogleMapsClient::new(&key)
+            .with_recording(fixture_directory(service))
+            .build(),
+        Err(_error) => GoogleMapsClient::new("TEST_KEY")
+            .with_replay(fixture_directory(service))
+            .build(),
+    } // match
+} // fn
\ No newline at end of file
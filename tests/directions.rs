@@ -0,0 +1,61 @@
+//! Regression suite for the Directions API. The responses are replayed from the
+//! fixtures in `tests/fixtures/directions`.
+
+#![cfg(all(feature = "directions", feature = "enable-reqwest"))]
+
+mod common;
+
+use google_maps::prelude::*;
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn driving_directions_return_a_route() {
+    let client = common::client("directions");
+
+    let response = client
+        .directions(
+            Location::Address(String::from("Toronto, ON")),
+            Location::Address(String::from("Montreal, QC")),
+        ) // directions
+        .with_travel_mode(TravelMode::Driving)
+        .execute()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, DirectionsStatus::Ok);
+    assert_eq!(response.routes.len(), 1);
+    let route = &response.routes[0];
+    assert_eq!(route.summary, "ON-401 E");
+    assert_eq!(route.legs.len(), 1);
+    let leg = &route.legs[0];
+    assert_eq!(leg.start_address, "Toronto, ON, Canada");
+    assert_eq!(leg.end_address, "Montreal, QC, Canada");
+    assert_eq!(leg.distance.value, 541_531);
+    assert_eq!(leg.duration.value.num_seconds(), 19_594);
+    assert_eq!(leg.steps.len(), 2);
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn not_found_is_returned_as_a_service_error() {
+    let client = common::client("directions");
+
+    let error = client
+        .directions(
+            Location::Address(String::from("zzzz no such place zzzz")),
+            Location::Address(String::from("Montreal, QC")),
+        ) // directions
+        .execute()
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(
+            error,
+            GoogleMapsError::Directions(DirectionsError::GoogleMapsService(DirectionsStatus::NotFound, _)),
+        ),
+        "unexpected error: {error:?}",
+    );
+} // fn
\ No newline at end of file
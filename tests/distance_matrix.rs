@@ -0,0 +1,42 @@
+//! Regression suite for the Distance Matrix API. The responses are replayed
+//! from the fixtures in `tests/fixtures/distance_matrix`.
+
+#![cfg(all(feature = "distance_matrix", feature = "enable-reqwest"))]
+
+mod common;
+
+use google_maps::distance_matrix::response::element_status::ElementStatus;
+use google_maps::prelude::*;
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn distance_matrix_returns_an_element_per_pair() {
+    let client = common::client("distance_matrix");
+
+    let response = client
+        .distance_matrix(
+            vec![Waypoint::Address(String::from("Vancouver, BC"))],
+            vec![
+                Waypoint::Address(String::from("San Francisco, CA")),
+                Waypoint::Address(String::from("Victoria, BC")),
+            ], // vec!
+        ) // distance_matrix
+        .execute()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, DistanceMatrixStatus::Ok);
+    assert_eq!(response.origin_addresses, vec!["Vancouver, BC, Canada"]);
+    assert_eq!(
+        response.destination_addresses,
+        vec!["San Francisco, CA, USA", "Victoria, BC, Canada"],
+    );
+    assert_eq!(response.rows.len(), 1);
+    let elements = &response.rows[0].elements;
+    assert_eq!(elements.len(), 2);
+    assert_eq!(elements[0].status, ElementStatus::Ok);
+    assert_eq!(elements[0].distance.as_ref().unwrap().value, 1_528_129);
+    assert_eq!(elements[1].status, ElementStatus::ZeroResults);
+    assert_eq!(elements[1].distance, None);
+} // fn
\ No newline at end of file
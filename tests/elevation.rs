@@ -0,0 +1,28 @@
+//! Regression suite for the Elevation API. The responses are replayed from the
+//! fixtures in `tests/fixtures/elevation`.
+
+#![cfg(all(feature = "elevation", feature = "enable-reqwest"))]
+
+mod common;
+
+use google_maps::prelude::*;
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn positional_request_returns_the_elevation() {
+    let client = common::client("elevation");
+
+    let response = client
+        .elevation()
+        .for_positional_request(LatLng::try_from_f64(39.7391536, -104.9847034).unwrap())
+        .execute()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, ElevationStatus::Ok);
+    let results = response.results.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].elevation, 1608.637939453125);
+    assert_eq!(results[0].resolution, Some(4.771975994110107));
+} // fn
\ No newline at end of file
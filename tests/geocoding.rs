@@ -0,0 +1,149 @@
+//! Regression suite for the Geocoding API. The responses are replayed from the
+//! fixtures in `tests/fixtures/geocoding`.
+
+#![cfg(all(feature = "geocoding", feature = "enable-reqwest"))]
+
+mod common;
+
+use google_maps::prelude::*;
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn forward_geocoding_returns_the_address_location() {
+    let client = common::client("geocoding");
+
+    let response = client
+        .geocoding()
+        .with_address("1600 Amphitheatre Parkway, Mountain View, CA")
+        .execute()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, GeocodingStatus::Ok);
+    assert_eq!(response.results.len(), 1);
+    let result = &response.results[0];
+    assert_eq!(result.formatted_address, "1600 Amphitheatre Pkwy, Mountain View, CA 94043, USA");
+    assert_eq!(result.place_id, "ChIJ2eUgeAK6j4ARbn5u_wAGqWA");
+    assert_eq!(
+        result.geometry.location,
+        LatLng::try_from_f64(37.4224764, -122.0842499).unwrap(),
+    );
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn reverse_geocoding_returns_the_address() {
+    let client = common::client("geocoding");
+
+    let response = client
+        .reverse_geocoding(LatLng::try_from_f64(40.714224, -73.961452).unwrap())
+        .execute()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, GeocodingStatus::Ok);
+    assert_eq!(
+        response.results[0].formatted_address,
+        "277 Bedford Ave, Brooklyn, NY 11211, USA",
+    );
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn zero_results_is_returned_as_a_service_error() {
+    let client = common::client("geocoding");
+
+    let error = client
+        .geocoding()
+        .with_address("zzzz no such place zzzz")
+        .execute()
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(
+            error,
+            GoogleMapsError::Geocoding(GeocodingError::GoogleMapsService(GeocodingStatus::ZeroResults, _)),
+        ),
+        "unexpected error: {error:?}",
+    );
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn missing_fixture_is_a_transport_error() {
+    let client = GoogleMapsClient::new("TEST_KEY")
+        .with_replay(common::fixture_directory("geocoding"))
+        .build();
+
+    let error = client
+        .geocoding()
+        .with_address("An address that was never recorded")
+        .execute()
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(error, GoogleMapsError::Transport(_)),
+        "unexpected error: {error:?}",
+    );
+} // fn
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn batch_returns_results_in_input_order() {
+    let client = common::client("geocoding");
+    let addresses = [
+        "1600 Amphitheatre Parkway, Mountain View, CA",
+        "zzzz no such place zzzz",
+        "1600 Amphitheatre Parkway, Mountain View, CA",
+    ]; // addresses
+
+    let descriptions = addresses
+        .iter()
+        .map(|address| client.geocoding().with_address(address).describe());
+    let results = client.batch(descriptions, 2).await;
+
+    // The failed request doesn't abort the others, and its error is returned
+    // in its position:
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().unwrap().results[0].place_id, "ChIJ2eUgeAK6j4ARbn5u_wAGqWA");
+    assert!(
+        matches!(
+            results[1],
+            Err(GoogleMapsError::Geocoding(GeocodingError::GoogleMapsService(GeocodingStatus::ZeroResults, _))),
+        ),
+        "unexpected result: {:?}",
+        results[1],
+    );
+    assert_eq!(results[2].as_ref().unwrap().results[0].place_id, "ChIJ2eUgeAK6j4ARbn5u_wAGqWA");
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn batch_stream_returns_results_in_input_order() {
+    use futures::StreamExt;
+    let client = common::client("geocoding");
+    let addresses = [
+        "zzzz no such place zzzz",
+        "1600 Amphitheatre Parkway, Mountain View, CA",
+        "zzzz no such place zzzz",
+    ]; // addresses
+
+    let descriptions: Vec<GeocodingRequestDescription> = addresses
+        .iter()
+        .map(|address| client.geocoding().with_address(address).describe())
+        .collect();
+    let results: Vec<_> = client
+        .batch_stream(futures::stream::iter(descriptions), 3)
+        .map(|result| result.is_ok())
+        .collect()
+        .await;
+
+    assert_eq!(results, vec![false, true, false]);
+} // fn
\ No newline at end of file
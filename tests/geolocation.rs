@@ -0,0 +1,93 @@
+//! Regression suite for the Geolocation API. The responses are replayed from
+//! the fixtures in `tests/fixtures/geolocation`.
+
+#![cfg(all(feature = "geolocation", feature = "enable-reqwest"))]
+
+mod common;
+
+use google_maps::prelude::*;
+
+// -----------------------------------------------------------------------------
+
+fn wifi_access_points() -> Vec<WiFiAccessPoint> {
+    vec![
+        WiFiAccessPoint {
+            mac_address: String::from("3c:37:86:5d:75:d4"),
+            signal_strength: Some(-35),
+            ..Default::default()
+        }, // WiFiAccessPoint
+        WiFiAccessPoint {
+            mac_address: String::from("94:b4:0f:fd:c1:40"),
+            signal_strength: Some(-43),
+            ..Default::default()
+        }, // WiFiAccessPoint
+    ] // vec!
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn wifi_access_points_return_a_location() {
+    let client = common::client("geolocation");
+
+    let response = client
+        .geolocation()
+        .with_consider_ip(false)
+        .with_wifi_access_points(&wifi_access_points())
+        .execute()
+        .await
+        .unwrap();
+
+    assert_eq!(response.location, LatLng::try_from_f64(37.4241173, -122.0915717).unwrap());
+    assert_eq!(response.accuracy, 20.0);
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn not_found_is_returned_as_a_service_error() {
+    let client = common::client("geolocation");
+
+    let error = client
+        .geolocation()
+        .with_consider_ip(false)
+        .execute()
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(
+            error,
+            GoogleMapsError::Geolocation(GeolocationError::GoogleMapsService(GeolocationReason::NotFound, _)),
+        ),
+        "unexpected error: {error:?}",
+    );
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn reason_serializes_as_its_reason_code() {
+    let json = serde_json::to_string(&GeolocationReason::UserRateLimitExceeded).unwrap();
+    assert_eq!(json, r#""userRateLimitExceeded""#);
+    let reason: GeolocationReason = serde_json::from_str(&json).unwrap();
+    assert_eq!(reason, GeolocationReason::UserRateLimitExceeded);
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn query_url_is_signed_and_redacted() {
+    let client = GoogleMapsClient::new("")
+        .with_client_id("clientID", "vNIXE0xscrmjlyV-12Nj_BvUPaw=")
+        .unwrap()
+        .build();
+
+    let query_url = client.geolocation().query_url();
+
+    assert!(
+        query_url.starts_with("https://www.googleapis.com/geolocation/v1/geolocate?client=clientID&signature="),
+        "{query_url}",
+    );
+    assert!(query_url.ends_with("&signature=REDACTED"), "{query_url}");
+} // fn
\ No newline at end of file
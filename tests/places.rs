@@ -0,0 +1,305 @@
+//! Regression suite for the Places API. The responses are replayed from the
+//! fixtures in `tests/fixtures/places`.
+
+#![cfg(all(feature = "places", feature = "enable-reqwest"))]
+
+mod common;
+
+use google_maps::prelude::*;
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn text_search_returns_places() {
+    let client = common::client("places");
+
+    let response = client
+        .text_search(String::from("pizza in new york"))
+        .execute()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, PlacesStatus::Ok);
+    assert_eq!(response.results.len(), 2);
+    assert_eq!(response.results[0].name.as_deref(), Some("Joe's Pizza"));
+    assert_eq!(response.next_page_token.as_deref(), Some("NEXT_PAGE_TOKEN"));
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn text_search_execute_all_follows_next_page_tokens() {
+    let client = common::client("places");
+
+    let places = client
+        .text_search(String::from("pizza in new york"))
+        .execute_all()
+        .await
+        .unwrap();
+
+    let names: Vec<_> = places
+        .iter()
+        .map(|place| place.name.as_deref().unwrap_or_default())
+        .collect();
+    assert_eq!(names, vec!["Joe's Pizza", "Prince Street Pizza", "Lombardi's Pizza"]);
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn text_search_stream_yields_every_page() {
+    use futures::TryStreamExt;
+    let client = common::client("places");
+
+    let streamed: Vec<Place> = client
+        .text_search(String::from("pizza in new york"))
+        .stream()
+        .try_collect()
+        .await
+        .unwrap();
+
+    let all = client
+        .text_search(String::from("pizza in new york"))
+        .execute_all()
+        .await
+        .unwrap();
+
+    assert_eq!(streamed.len(), 3);
+    assert_eq!(streamed, all);
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn text_search_execute_all_restores_the_page_token() {
+    let client = common::client("places")
+        .with_cache(google_maps::cache::MemoryCache::new(100), std::time::Duration::from_secs(3_600))
+        .build();
+
+    let mut request = client.text_search(String::from("pizza in new york"));
+    let first = request.execute_all().await.unwrap();
+    assert!(!request.query_url().contains("pagetoken"));
+
+    // Pagination must start from the first page again, and not from a page
+    // remembered by the request or the cache:
+    let second = request.execute_all().await.unwrap();
+    assert_eq!(first.len(), 3);
+    assert_eq!(second.len(), first.len());
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn find_place_returns_candidates() {
+    let client = common::client("places");
+
+    let response = client
+        .find_place(String::from("Museum of Contemporary Art Australia"), InputType::TextQuery)
+        .execute()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, PlacesStatus::Ok);
+    assert_eq!(response.candidates.len(), 1);
+    assert_eq!(
+        response.candidates[0].place_id.as_deref(),
+        Some("ChIJ68aBlEKuEmsRHUA9oME5Zh0"),
+    );
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn place_details_returns_the_place() {
+    let client = common::client("places");
+
+    let response = client
+        .place_details(String::from("ChIJN1t_tDeuEmsRUsoyG83frY4"))
+        .execute()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, PlacesStatus::Ok);
+    assert_eq!(response.result.name.as_deref(), Some("Google Workplace 6"));
+    assert_eq!(
+        response.result.formatted_address.as_deref(),
+        Some("48 Pirrama Rd, Pyrmont NSW 2009, Australia"),
+    );
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn invalid_request_is_returned_as_a_service_error() {
+    let client = common::client("places");
+
+    let error = client
+        .find_place(String::new(), InputType::TextQuery)
+        .execute()
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(
+            error,
+            GoogleMapsError::Places(PlacesError::GoogleMapsService(PlacesStatus::InvalidRequest, _)),
+        ),
+        "unexpected error: {error:?}",
+    );
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn nearby_search_ranked_by_distance_has_no_radius() {
+    let client = common::client("places");
+    let location = LatLng::try_from_f64(-33.8670522, 151.1957362).unwrap();
+
+    let query_url = client
+        .nearby_search_ranked_by_distance(location)
+        .with_type(PlaceType::Pharmacy)
+        .validate()
+        .unwrap()
+        .query_url();
+
+    assert!(!query_url.contains("radius="), "{query_url}");
+    assert!(query_url.contains("rankby=distance"), "{query_url}");
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn nearby_search_sends_one_type() {
+    let client = common::client("places");
+    let location = LatLng::try_from_f64(-33.8670522, 151.1957362).unwrap();
+
+    let query_url = client
+        .nearby_search(location, 1_500)
+        .with_type(PlaceType::Pharmacy)
+        .with_type(PlaceType::Hospital)
+        .query_url();
+
+    assert!(query_url.contains("&type=hospital"), "{query_url}");
+    assert!(!query_url.contains("pharmacy"), "{query_url}");
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn nearby_search_ranked_by_distance_refuses_a_radius() {
+    let client = common::client("places");
+    let location = LatLng::try_from_f64(-33.8670522, 151.1957362).unwrap();
+
+    let error = client
+        .nearby_search(location, 1_500)
+        .with_keyword("pharmacy")
+        .with_rank_by(RankBy::Distance)
+        .execute()
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(error, GoogleMapsError::Places(PlacesError::RadiusWithRankByDistance)),
+        "unexpected error: {error:?}",
+    );
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn nearby_search_ranked_by_distance_requires_a_keyword_or_type() {
+    let client = common::client("places");
+    let location = LatLng::try_from_f64(-33.8670522, 151.1957362).unwrap();
+
+    let error = client
+        .nearby_search_ranked_by_distance(location)
+        .execute()
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(error, GoogleMapsError::Places(PlacesError::KeywordOrTypeRequired)),
+        "unexpected error: {error:?}",
+    );
+} // fn
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "autocomplete")]
+#[tokio::test]
+async fn place_autocomplete_returns_predictions() {
+    let client = common::client("places");
+
+    let response = client
+        .place_autocomplete(String::from("Amoeba"))
+        .with_location_and_radius(LatLng::try_from_f64(37.76999, -122.44696).unwrap(), 500)
+        .with_type(AutocompleteType::Establishment)
+        .execute()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, AutocompleteStatus::Ok);
+    let descriptions: Vec<_> = response.predictions
+        .iter()
+        .map(|prediction| prediction.description.as_str())
+        .collect();
+    assert_eq!(descriptions, vec![
+        "Amoeba Music, Haight Street, San Francisco, CA, USA",
+        "Amoeba Music, Telegraph Avenue, Berkeley, CA, USA",
+    ]);
+    let prediction = &response.predictions[0];
+    assert_eq!(prediction.place_id.as_deref(), Some("ChIJnTB8ZkmHhYAR7xvNq6x2uMc"));
+    assert_eq!(prediction.structured_formatting.main_text, "Amoeba Music");
+    assert_eq!(prediction.matched_substrings, vec![MatchedSubstring { length: 6, offset: 0 }]);
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "autocomplete")]
+#[tokio::test]
+async fn query_autocomplete_returns_query_predictions() {
+    let client = common::client("places");
+
+    let response = client
+        .query_autocomplete(String::from("pizza near par"))
+        .execute()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, AutocompleteStatus::Ok);
+    let descriptions: Vec<_> = response.predictions
+        .iter()
+        .map(|prediction| prediction.description.as_str())
+        .collect();
+    assert_eq!(descriptions, vec![
+        "pizza near Paris, France",
+        "pizza near Parramatta NSW, Australia",
+    ]);
+    // Query predictions for categorical searches don't identify a place:
+    assert_eq!(response.predictions[0].place_id, None);
+    assert_eq!(
+        response.predictions[0].terms.iter().map(|term| term.value.as_str()).collect::<Vec<_>>(),
+        vec!["pizza", "near", "Paris", "France"],
+    );
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn place_photo_returns_the_image_bytes() {
+    let client = common::client("places");
+
+    let response = client
+        .place_photo(String::from("AUc7tXW5m3CqxNDvDBRo1hNMDLNyHFNGYwUQGRFK5d8"))
+        .with_max_width(400)
+        .execute()
+        .await
+        .unwrap();
+
+    assert_eq!(response.content_type, "image/jpeg");
+    assert_eq!(response.bytes.len(), 24);
+    // The image is returned byte-for-byte, from its JPEG start-of-image marker
+    // to its end-of-image marker:
+    assert!(response.bytes.starts_with(&[0xff, 0xd8, 0xff, 0xe0]));
+    assert!(response.bytes.ends_with(&[0xff, 0xd9]));
+    assert!(String::from_utf8(response.bytes).is_err());
+} // fn
\ No newline at end of file
@@ -0,0 +1,89 @@
+//! Regression suite for the Roads API. The responses are replayed from the
+//! fixtures in `tests/fixtures/roads`.
+
+#![cfg(all(feature = "roads", feature = "enable-reqwest"))]
+
+mod common;
+
+use google_maps::prelude::*;
+
+// -----------------------------------------------------------------------------
+
+/// GPS points collected along a route in Canberra, Australia.
+fn path() -> Vec<LatLng> {
+    vec![
+        LatLng::try_from_f64(-35.27801, 149.12958).unwrap(),
+        LatLng::try_from_f64(-35.28032, 149.12907).unwrap(),
+        LatLng::try_from_f64(-35.28099, 149.12929).unwrap(),
+    ] // vec!
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn snap_to_roads_returns_the_snapped_points() {
+    let client = common::client("roads");
+
+    let response = client
+        .snap_to_roads(path())
+        .execute()
+        .await
+        .unwrap();
+
+    let snapped_points = response.snapped_points.unwrap();
+    assert_eq!(snapped_points.len(), 3);
+    assert_eq!(
+        snapped_points.iter().map(|point| point.origin_index).collect::<Vec<_>>(),
+        vec![Some(0), Some(1), Some(2)],
+    );
+    assert_eq!(snapped_points[0].place_id.as_deref(), Some("ChIJjXkMCDsVF2sR-T0fPQ8n6bI"));
+    assert_eq!(
+        snapped_points[0].location,
+        LatLng::try_from_f64(-35.2784167, 149.1294692).unwrap(),
+    );
+    assert_eq!(response.warning_message, None);
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn snap_to_roads_interpolates_the_path() {
+    let client = common::client("roads");
+
+    let response = client
+        .snap_to_roads(path())
+        .with_interpolation(true)
+        .execute()
+        .await
+        .unwrap();
+
+    let snapped_points = response.snapped_points.unwrap();
+    assert_eq!(snapped_points.len(), 5);
+    // Interpolated points don't correspond to any of the original points:
+    assert_eq!(
+        snapped_points.iter().map(|point| point.origin_index).collect::<Vec<_>>(),
+        vec![Some(0), None, Some(1), None, Some(2)],
+    );
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn nearest_roads_returns_the_closest_road_segments() {
+    let client = common::client("roads");
+
+    let response = NearestRoadsRequest::new(&client, vec![
+        LatLng::try_from_f64(60.170880, 24.942795).unwrap(),
+        LatLng::try_from_f64(60.170879, 24.942796).unwrap(),
+    ]) // NearestRoadsRequest
+    .execute()
+    .await
+    .unwrap();
+
+    let snapped_points = response.snapped_points.unwrap();
+    assert_eq!(snapped_points.len(), 2);
+    assert!(snapped_points
+        .iter()
+        .all(|point| point.place_id.as_deref() == Some("ChIJNX9BrM0LkkYRIM-cQg265e8")));
+    assert_eq!(snapped_points[1].origin_index, Some(1));
+} // fn
\ No newline at end of file
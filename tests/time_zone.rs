@@ -0,0 +1,77 @@
+//! Regression suite for the Time Zone API. The responses are replayed from the
+//! fixtures in `tests/fixtures/time_zone`.
+
+#![cfg(all(feature = "time_zone", feature = "enable-reqwest"))]
+
+mod common;
+
+use google_maps::prelude::*;
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn time_zone_returns_the_zone_and_offsets() {
+    let client = common::client("time_zone");
+
+    let response = client
+        .time_zone(
+            // St. Vitus Cathedral in Prague, Czechia:
+            LatLng::try_from_f64(50.090903, 14.400512).unwrap(),
+            Utc.with_ymd_and_hms(2020, 2, 23, 18, 0, 0).unwrap(),
+        ) // time_zone
+        .execute()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, TimeZoneStatus::Ok);
+    assert_eq!(response.time_zone_id, Some(Tz::Europe__Prague));
+    assert_eq!(response.time_zone_name.as_deref(), Some("Central European Standard Time"));
+    assert_eq!(response.raw_offset, Some(3_600));
+    assert_eq!(response.dst_offset, Some(0));
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn time_zone_name_is_localized() {
+    let client = common::client("time_zone");
+
+    let response = client
+        .time_zone(
+            LatLng::try_from_f64(50.090903, 14.400512).unwrap(),
+            Utc.with_ymd_and_hms(2020, 7, 14, 12, 0, 0).unwrap(),
+        ) // time_zone
+        .with_language(Language::French)
+        .execute()
+        .await
+        .unwrap();
+
+    assert_eq!(response.time_zone_id, Some(Tz::Europe__Prague));
+    assert_eq!(response.time_zone_name.as_deref(), Some("heure d’été d’Europe centrale"));
+    assert_eq!(response.dst_offset, Some(3_600));
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn zero_results_is_returned_as_a_service_error() {
+    let client = common::client("time_zone");
+
+    let error = client
+        .time_zone(
+            // Point Nemo, in the South Pacific Ocean:
+            LatLng::try_from_f64(-48.876667, -123.393333).unwrap(),
+            Utc.with_ymd_and_hms(2020, 2, 23, 18, 0, 0).unwrap(),
+        ) // time_zone
+        .execute()
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(
+            error,
+            GoogleMapsError::TimeZone(TimeZoneError::GoogleMapsService(TimeZoneStatus::ZeroResults, _)),
+        ),
+        "unexpected error: {error:?}",
+    );
+} // fn
\ No newline at end of file